@@ -8,6 +8,17 @@ mod m20250103_000001_rename_refresh_token_table;
 mod m20260118_000001_create_projects_table;
 mod m20260118_000002_refactor_memos_table;
 mod m20260118_000003_create_essays_table;
+mod m20261018_000001_add_essay_translations;
+mod m20261018_000002_create_project_digests_table;
+mod m20261018_000003_add_memo_needs_indexing;
+mod m20261018_000004_add_memo_content_hash;
+mod m20261018_000005_create_ai_usage_table;
+mod m20261018_000006_add_ai_usage_feature;
+mod m20261018_000007_create_memo_index_jobs;
+mod m20261018_000008_create_vector_reindex_runs;
+mod m20261018_000009_create_memo_vectors;
+mod m20261018_000010_create_project_vector_deletions;
+mod m20261018_000011_add_list_pagination_indexes;
 
 pub struct Migrator;
 
@@ -23,6 +34,17 @@ impl MigratorTrait for Migrator {
             Box::new(m20260118_000001_create_projects_table::Migration),
             Box::new(m20260118_000002_refactor_memos_table::Migration),
             Box::new(m20260118_000003_create_essays_table::Migration),
+            Box::new(m20261018_000001_add_essay_translations::Migration),
+            Box::new(m20261018_000002_create_project_digests_table::Migration),
+            Box::new(m20261018_000003_add_memo_needs_indexing::Migration),
+            Box::new(m20261018_000004_add_memo_content_hash::Migration),
+            Box::new(m20261018_000005_create_ai_usage_table::Migration),
+            Box::new(m20261018_000006_add_ai_usage_feature::Migration),
+            Box::new(m20261018_000007_create_memo_index_jobs::Migration),
+            Box::new(m20261018_000008_create_vector_reindex_runs::Migration),
+            Box::new(m20261018_000009_create_memo_vectors::Migration),
+            Box::new(m20261018_000010_create_project_vector_deletions::Migration),
+            Box::new(m20261018_000011_add_list_pagination_indexes::Migration),
         ]
     }
 }
@@ -0,0 +1,101 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 1. 번역 관련 컬럼 추가
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Essays::Table)
+                    .add_column(ColumnDef::new(Essays::SourceEssayId).integer().null())
+                    .add_column(ColumnDef::new(Essays::Language).string_len(16).null())
+                    .add_column(
+                        ColumnDef::new(Essays::IsTranslationStale)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 2. 원본 에세이 FK 추가 (원본 삭제 시 링크만 해제)
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Essays::Table)
+                    .add_foreign_key(
+                        &TableForeignKey::new()
+                            .name("fk_essays_source_essay_id")
+                            .from_tbl(Essays::Table)
+                            .from_col(Essays::SourceEssayId)
+                            .to_tbl(Essays::Table)
+                            .to_col(Essays::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .to_owned(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 3. 원본 + 언어 조합은 하나의 번역본만 허용
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-essays-source_essay_id-language")
+                    .table(Essays::Table)
+                    .col(Essays::SourceEssayId)
+                    .col(Essays::Language)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-essays-source_essay_id-language")
+                    .table(Essays::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Essays::Table)
+                    .drop_foreign_key(Alias::new("fk_essays_source_essay_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Essays::Table)
+                    .drop_column(Essays::SourceEssayId)
+                    .drop_column(Essays::Language)
+                    .drop_column(Essays::IsTranslationStale)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Essays {
+    Table,
+    Id,
+    SourceEssayId,
+    Language,
+    IsTranslationStale,
+}
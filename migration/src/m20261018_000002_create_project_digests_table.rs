@@ -0,0 +1,125 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProjectDigests::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProjectDigests::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectDigests::ProjectId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ProjectDigests::Summary).text().not_null())
+                    .col(
+                        ColumnDef::new(ProjectDigests::MemoCount)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectDigests::PeriodStart)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectDigests::PeriodEnd)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectDigests::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_project_digests_project_id")
+                            .from(ProjectDigests::Table, ProjectDigests::ProjectId)
+                            .to(Projects::Table, Projects::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-project_digests-project_id-period_end")
+                    .table(ProjectDigests::Table)
+                    .col(ProjectDigests::ProjectId)
+                    .col(ProjectDigests::PeriodEnd)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 기간별 메모 조회용 인덱스
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-memos-project_id-created_at")
+                    .table(Memos::Table)
+                    .col(Memos::ProjectId)
+                    .col(Memos::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-memos-project_id-created_at")
+                    .table(Memos::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ProjectDigests::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProjectDigests {
+    Table,
+    Id,
+    ProjectId,
+    Summary,
+    MemoCount,
+    PeriodStart,
+    PeriodEnd,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Projects {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Memos {
+    Table,
+    ProjectId,
+    CreatedAt,
+}
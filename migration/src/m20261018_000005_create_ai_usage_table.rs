@@ -0,0 +1,120 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AiUsage::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AiUsage::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AiUsage::UserId).integer().not_null())
+                    .col(ColumnDef::new(AiUsage::ProjectId).integer().null())
+                    .col(ColumnDef::new(AiUsage::Operation).string_len(32).not_null())
+                    .col(ColumnDef::new(AiUsage::Model).string_len(128).not_null())
+                    .col(
+                        ColumnDef::new(AiUsage::PromptTokens)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(AiUsage::CandidateTokens)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(AiUsage::TotalTokens)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(AiUsage::ItemCount)
+                            .integer()
+                            .not_null()
+                            .default(1),
+                    )
+                    .col(
+                        ColumnDef::new(AiUsage::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_ai_usage_user_id")
+                            .from(AiUsage::Table, AiUsage::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    // 프로젝트를 지워도 사용자 사용량 기록은 남긴다
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_ai_usage_project_id")
+                            .from(AiUsage::Table, AiUsage::ProjectId)
+                            .to(Projects::Table, Projects::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-ai_usage-user_id-created_at")
+                    .table(AiUsage::Table)
+                    .col(AiUsage::UserId)
+                    .col(AiUsage::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AiUsage::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AiUsage {
+    Table,
+    Id,
+    UserId,
+    ProjectId,
+    Operation,
+    Model,
+    PromptTokens,
+    CandidateTokens,
+    TotalTokens,
+    ItemCount,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Projects {
+    Table,
+    Id,
+}
@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 사용량을 일으킨 기능 (assist, memo_indexing 등). 기능별 할당량 계산에 사용
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AiUsage::Table)
+                    .add_column(
+                        ColumnDef::new(AiUsage::Feature)
+                            .string_len(32)
+                            .not_null()
+                            .default("unknown"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-ai_usage-user_id-feature-created_at")
+                    .table(AiUsage::Table)
+                    .col(AiUsage::UserId)
+                    .col(AiUsage::Feature)
+                    .col(AiUsage::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-ai_usage-user_id-feature-created_at")
+                    .table(AiUsage::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AiUsage::Table)
+                    .drop_column(AiUsage::Feature)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AiUsage {
+    Table,
+    UserId,
+    Feature,
+    CreatedAt,
+}
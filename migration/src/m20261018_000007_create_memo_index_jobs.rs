@@ -0,0 +1,193 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 1. 메모별 벡터 인덱싱 상태 (pending, indexed, failed)
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Memos::Table)
+                    .add_column(
+                        ColumnDef::new(Memos::IndexStatus)
+                            .string_len(16)
+                            .not_null()
+                            .default("indexed"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 2. 메모 저장과 같은 트랜잭션에 기록되는 인덱싱 작업 (outbox)
+        manager
+            .create_table(
+                Table::create()
+                    .table(MemoIndexJobs::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MemoIndexJobs::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MemoIndexJobs::MemoId)
+                            .integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MemoIndexJobs::Status)
+                            .string_len(16)
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(
+                        ColumnDef::new(MemoIndexJobs::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(MemoIndexJobs::LastError).text().null())
+                    .col(
+                        ColumnDef::new(MemoIndexJobs::RunAfter)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(MemoIndexJobs::LockedUntil)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(MemoIndexJobs::EnqueuedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_memo_index_jobs_memo_id")
+                            .from(MemoIndexJobs::Table, MemoIndexJobs::MemoId)
+                            .to(Memos::Table, Memos::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-memo_index_jobs-status-run_after")
+                    .table(MemoIndexJobs::Table)
+                    .col(MemoIndexJobs::Status)
+                    .col(MemoIndexJobs::RunAfter)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 3. 인덱싱 대기 중이던 메모를 작업으로 옮기고 기존 플래그 제거
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            "UPDATE memos SET index_status = 'pending' WHERE needs_indexing = TRUE",
+        )
+        .await?;
+        db.execute_unprepared(
+            "INSERT INTO memo_index_jobs (memo_id) \
+             SELECT id FROM memos WHERE index_status = 'pending'",
+        )
+        .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-memos-needs_indexing")
+                    .table(Memos::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Memos::Table)
+                    .drop_column(Memos::NeedsIndexing)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Memos::Table)
+                    .add_column(
+                        ColumnDef::new(Memos::NeedsIndexing)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE memos SET needs_indexing = TRUE WHERE index_status <> 'indexed'",
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-memos-needs_indexing")
+                    .table(Memos::Table)
+                    .col(Memos::NeedsIndexing)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(MemoIndexJobs::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Memos::Table)
+                    .drop_column(Memos::IndexStatus)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Memos {
+    Table,
+    Id,
+    NeedsIndexing,
+    IndexStatus,
+}
+
+#[derive(DeriveIden)]
+enum MemoIndexJobs {
+    Table,
+    Id,
+    MemoId,
+    Status,
+    Attempts,
+    LastError,
+    RunAfter,
+    LockedUntil,
+    EnqueuedAt,
+}
@@ -0,0 +1,104 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(VectorReindexRuns::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VectorReindexRuns::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(VectorReindexRuns::CollectionName)
+                            .string_len(128)
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(VectorReindexRuns::ModelId)
+                            .string_len(128)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VectorReindexRuns::Dimension)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VectorReindexRuns::Status)
+                            .string_len(16)
+                            .not_null()
+                            .default("running"),
+                    )
+                    .col(
+                        ColumnDef::new(VectorReindexRuns::LastMemoId)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(VectorReindexRuns::Processed)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(VectorReindexRuns::Total)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(VectorReindexRuns::StartedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(VectorReindexRuns::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(VectorReindexRuns::CompletedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(VectorReindexRuns::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum VectorReindexRuns {
+    Table,
+    Id,
+    CollectionName,
+    ModelId,
+    Dimension,
+    Status,
+    LastMemoId,
+    Processed,
+    Total,
+    StartedAt,
+    UpdatedAt,
+    CompletedAt,
+}
@@ -0,0 +1,157 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, DbBackend, Statement};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // pgvector 백엔드용 테이블. Qdrant만 사용하는 배포에는 확장이 없을 수 있으므로 그때는 건너뛰고,
+        // pgvector 백엔드를 처음 사용할 때 `PgVectorRepository::new`가 같은 테이블을 만든다
+        let db = manager.get_connection();
+        let available = db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                "SELECT 1 FROM pg_available_extensions WHERE name = 'vector'",
+            ))
+            .await?
+            .is_some();
+        if !available {
+            return Ok(());
+        }
+
+        db.execute_unprepared("CREATE EXTENSION IF NOT EXISTS vector")
+            .await?;
+
+        // 1. Qdrant 컬렉션에 해당하는 벡터 모음. 검색과 쓰기는 활성 컬렉션 하나만 사용한다
+        manager
+            .create_table(
+                Table::create()
+                    .table(VectorCollections::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VectorCollections::Name)
+                            .string_len(128)
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(VectorCollections::ModelId)
+                            .string_len(128)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VectorCollections::Dimension)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VectorCollections::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(VectorCollections::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        db.execute_unprepared(
+            "CREATE UNIQUE INDEX IF NOT EXISTS \"idx-vector_collections-active\" \
+             ON vector_collections (is_active) WHERE is_active",
+        )
+        .await?;
+
+        // 2. 메모 벡터. 차원은 컬렉션마다 다르므로 열에는 두지 않고,
+        //    컬렉션별 HNSW 인덱스를 만들 때 차원을 지정한다
+        manager
+            .create_table(
+                Table::create()
+                    .table(MemoVectors::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MemoVectors::Collection)
+                            .string_len(128)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MemoVectors::MemoId).integer().not_null())
+                    .col(ColumnDef::new(MemoVectors::ProjectId).integer().not_null())
+                    .col(
+                        ColumnDef::new(MemoVectors::Embedding)
+                            .custom(Alias::new("vector"))
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(MemoVectors::Collection)
+                            .col(MemoVectors::MemoId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_memo_vectors_collection")
+                            .from(MemoVectors::Table, MemoVectors::Collection)
+                            .to(VectorCollections::Table, VectorCollections::Name)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-memo_vectors-collection-project_id")
+                    .table(MemoVectors::Table)
+                    .col(MemoVectors::Collection)
+                    .col(MemoVectors::ProjectId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(MemoVectors::Table)
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(VectorCollections::Table)
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum VectorCollections {
+    Table,
+    Name,
+    ModelId,
+    Dimension,
+    IsActive,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum MemoVectors {
+    Table,
+    Collection,
+    MemoId,
+    ProjectId,
+    Embedding,
+}
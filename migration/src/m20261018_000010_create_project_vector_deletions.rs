@@ -0,0 +1,87 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 삭제된 프로젝트의 벡터 삭제 작업 (outbox). 프로젝트 삭제와 같은 트랜잭션에 기록되므로
+        // 프로젝트 행이 없어도 남아 있어야 해서 외래 키를 두지 않는다
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProjectVectorDeletions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProjectVectorDeletions::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectVectorDeletions::ProjectId)
+                            .integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectVectorDeletions::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectVectorDeletions::LastError)
+                            .text()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectVectorDeletions::RunAfter)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(ProjectVectorDeletions::EnqueuedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-project_vector_deletions-run_after")
+                    .table(ProjectVectorDeletions::Table)
+                    .col(ProjectVectorDeletions::RunAfter)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(ProjectVectorDeletions::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProjectVectorDeletions {
+    Table,
+    Id,
+    ProjectId,
+    Attempts,
+    LastError,
+    RunAfter,
+    EnqueuedAt,
+}
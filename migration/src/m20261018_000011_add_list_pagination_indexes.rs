@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 목록 조회의 정렬 순서와 같은 인덱스. 커서 다음 항목을 정렬 없이 인덱스 역방향 스캔으로 읽는다
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-memos-project_id-list_order")
+                    .table(Memos::Table)
+                    .col(Memos::ProjectId)
+                    .col(Memos::IsPinned)
+                    .col(Memos::UpdatedAt)
+                    .col(Memos::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-essays-project_id-list_order")
+                    .table(Essays::Table)
+                    .col(Essays::ProjectId)
+                    .col(Essays::IsPinned)
+                    .col(Essays::UpdatedAt)
+                    .col(Essays::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-projects-user_id-list_order")
+                    .table(Projects::Table)
+                    .col(Projects::UserId)
+                    .col(Projects::CreatedAt)
+                    .col(Projects::Id)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-projects-user_id-list_order")
+                    .table(Projects::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-essays-project_id-list_order")
+                    .table(Essays::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-memos-project_id-list_order")
+                    .table(Memos::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Memos {
+    Table,
+    Id,
+    ProjectId,
+    IsPinned,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Essays {
+    Table,
+    Id,
+    ProjectId,
+    IsPinned,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Projects {
+    Table,
+    Id,
+    UserId,
+    CreatedAt,
+}
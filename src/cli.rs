@@ -0,0 +1,218 @@
+//! 서버와 같은 설정으로 실행하는 운영용 명령.
+//!
+//! - `reconcile-index [project_id]`: 메모 테이블과 벡터 DB 대조
+//! - `requeue-failed`: 인덱싱을 포기한(dead) 메모를 다시 인덱싱 대기열에 등록
+//! - `reindex`: 현재 임베딩 모델로 새 컬렉션을 만들어 재인덱싱하고 검색 별칭 전환 (중단되면 다시 실행해 이어서 처리)
+//! - `reindex-status`: 검색 별칭, 컬렉션, 재인덱싱 기록 출력
+//! - `switch-collection <name>`: 검색 별칭을 지정한 컬렉션으로 옮김 (롤백)
+
+use anyhow::{bail, Result};
+use sea_orm::DatabaseConnection;
+use std::{env::var, sync::Arc};
+
+use crate::{
+    clients::{self, Embedder},
+    db,
+    repositories::{VectorBackend, VectorSpec, VectorStore, VectorStoreConfig},
+    services::{MemoService, ReconcileConfig, ReindexService},
+    utils::env::env_or,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    ReconcileIndex { project_id: Option<i32> },
+    RequeueFailed,
+    Reindex,
+    ReindexStatus,
+    SwitchCollection { collection: String },
+}
+
+impl Command {
+    /// 실행 인자(프로그램 이름 제외)를 명령으로 변환. 인자가 없으면 서버 실행
+    pub fn parse(args: &[String]) -> Result<Option<Self>> {
+        let command = match args {
+            [] => return Ok(None),
+            [name, rest @ ..] => match (name.as_str(), rest) {
+                ("reconcile-index", []) => Self::ReconcileIndex { project_id: None },
+                ("reconcile-index", [project_id]) => Self::ReconcileIndex {
+                    project_id: Some(project_id.parse()?),
+                },
+                ("requeue-failed", []) => Self::RequeueFailed,
+                ("reindex", []) => Self::Reindex,
+                ("reindex-status", []) => Self::ReindexStatus,
+                ("switch-collection", [collection]) => Self::SwitchCollection {
+                    collection: collection.clone(),
+                },
+                _ => bail!("unknown command: {}", args.join(" ")),
+            },
+        };
+
+        Ok(Some(command))
+    }
+}
+
+struct Backends {
+    db: Arc<DatabaseConnection>,
+    vectors: VectorStore,
+    embedder: Arc<dyn Embedder>,
+}
+
+/// 메모리 저장소는 서버 프로세스 안에만 있으므로 별도 프로세스로 실행하는 명령은 빈 저장소를 보게 된다
+fn check_vector_backend(config: &VectorStoreConfig) -> Result<()> {
+    if config.backend == VectorBackend::Memory {
+        bail!(
+            "VECTOR_STORE=memory keeps vectors inside the server process, so these commands \
+             cannot read or change them. Use qdrant or pgvector to run them; with the memory \
+             store the server's reconciliation job restores missing vectors when it starts"
+        );
+    }
+    Ok(())
+}
+
+async fn connect() -> Result<Backends> {
+    let vector_config = VectorStoreConfig::from_env()?;
+    check_vector_backend(&vector_config)?;
+    let database_url = var("DATABASE_URL").expect("DATABASE_URL must be set in .env file");
+
+    let ai_config = clients::AiProviderConfig::from_env()?;
+    let providers = clients::build_providers(&ai_config)?;
+    let db = Arc::new(db::create_connection(&database_url).await?);
+    // 재인덱싱은 현재 컬렉션과 다른 임베더로 실행하므로 컬렉션 형식을 검증하지 않는다
+    let spec = VectorSpec::new(
+        providers.embedder.model_id(),
+        providers.embedder.dimension(),
+    );
+    let vectors = VectorStore::connect(&vector_config, db.clone(), spec)
+        .await
+        .expect("Failed to initialize vector store");
+
+    Ok(Backends {
+        db,
+        vectors,
+        embedder: providers.embedder,
+    })
+}
+
+pub async fn run(command: Command) -> Result<()> {
+    dotenv::dotenv().ok();
+    tracing_subscriber::fmt::init();
+
+    let backends = connect().await?;
+    let memo_service = MemoService::new(
+        backends.db.clone(),
+        backends.vectors.repo.clone(),
+        backends.embedder.clone(),
+    )
+    .with_reconcile_config(ReconcileConfig::from_env()?);
+    let reindex_service =
+        ReindexService::new(backends.db, backends.vectors.collections, backends.embedder)
+            .with_batch_size(env_or("REINDEX_BATCH_SIZE", 64)?);
+
+    match command {
+        // 누락된 메모는 인덱싱 작업으로 등록되어 실행 중인 서버의 워커가 처리한다
+        Command::ReconcileIndex { project_id } => {
+            let report = match project_id {
+                Some(project_id) => memo_service.reconcile_project_index(project_id).await?,
+                None => memo_service.reconcile_index().await?,
+            };
+            println!("points scanned:   {}", report.points_scanned);
+            println!("orphans deleted:  {}", report.orphans_deleted);
+            println!("memos requeued:   {}", report.missing_requeued);
+        }
+        // 실행 중인 서버의 워커가 다시 처리한다
+        Command::RequeueFailed => {
+            let requeued = memo_service.requeue_failed_index_jobs().await?;
+            println!("memos requeued:   {}", requeued);
+        }
+        Command::Reindex => {
+            let report = reindex_service.reindex().await?;
+            println!(
+                "{} {} ({} memo(s) embedded)",
+                if report.resumed { "resumed" } else { "created" },
+                report.collection,
+                report.embedded
+            );
+
+            // 재인덱싱 중 삭제된 메모의 포인트를 새 컬렉션에서 정리
+            let report = memo_service.reconcile_index().await?;
+            println!("orphans deleted:  {}", report.orphans_deleted);
+        }
+        Command::ReindexStatus => {
+            let overview = reindex_service.overview().await?;
+            let active = overview.active_collection.unwrap_or_default();
+            println!("active collection: {}", active);
+            for collection in overview.collections {
+                let marker = if collection == active { "*" } else { " " };
+                println!("  {} {}", marker, collection);
+            }
+            for run in overview.runs {
+                println!(
+                    "{} {:?} {} ({} dims) {}/{} started {}",
+                    run.collection_name,
+                    run.status,
+                    run.model_id,
+                    run.dimension,
+                    run.processed,
+                    run.total,
+                    run.started_at
+                );
+            }
+        }
+        Command::SwitchCollection { collection } => {
+            reindex_service.switch_collection(&collection).await?;
+            println!("active collection: {}", collection);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(Command::parse(&[]).unwrap(), None);
+        assert_eq!(
+            Command::parse(&args(&["reconcile-index", "42"])).unwrap(),
+            Some(Command::ReconcileIndex {
+                project_id: Some(42)
+            })
+        );
+        assert_eq!(
+            Command::parse(&args(&["switch-collection", "memo_embeddings"])).unwrap(),
+            Some(Command::SwitchCollection {
+                collection: "memo_embeddings".to_string()
+            })
+        );
+        assert_eq!(
+            Command::parse(&args(&["requeue-failed"])).unwrap(),
+            Some(Command::RequeueFailed)
+        );
+        assert!(Command::parse(&args(&["reindex", "extra"])).is_err());
+        assert!(Command::parse(&args(&["unknown"])).is_err());
+    }
+
+    #[test]
+    fn test_memory_vector_store_is_rejected() {
+        let config = VectorStoreConfig {
+            backend: VectorBackend::Memory,
+            qdrant_url: None,
+            qdrant: Default::default(),
+            snapshot_path: None,
+        };
+        let message = check_vector_backend(&config).unwrap_err().to_string();
+        assert!(message.contains("VECTOR_STORE=memory"));
+
+        let config = VectorStoreConfig {
+            backend: VectorBackend::PgVector,
+            ..config
+        };
+        assert!(check_vector_backend(&config).is_ok());
+    }
+}
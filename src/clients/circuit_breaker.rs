@@ -0,0 +1,489 @@
+use serde::Serialize;
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::Instant;
+use tracing::{info, warn};
+use utoipa::ToSchema;
+
+use crate::clients::{ClientError, Embedder, EmbeddingPurpose, GenerationOptions, TextGenerator};
+
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// 회로를 여는 실패율 (0.0 ~ 1.0)
+    pub failure_rate_threshold: f64,
+    /// 실패율을 계산할 최근 호출 수
+    pub window_size: usize,
+    /// 실패율을 평가하기 위한 최소 호출 수
+    pub minimum_calls: usize,
+    /// 회로가 열린 뒤 half-open 프로브를 허용하기까지 대기 시간
+    pub open_duration: Duration,
+    /// half-open 상태에서 동시에 허용하는 프로브 호출 수.
+    /// 프로브가 모두 성공해야 회로가 닫힌다
+    pub half_open_probes: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_rate_threshold: 0.5,
+            window_size: 20,
+            minimum_calls: 5,
+            open_duration: Duration::from_secs(30),
+            half_open_probes: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+enum State {
+    Closed,
+    Open { until: Instant },
+    HalfOpen { in_flight: u32, succeeded: u32 },
+}
+
+struct Inner {
+    state: State,
+    /// 닫힌 상태에서의 최근 호출 결과 (true = 실패)
+    outcomes: VecDeque<bool>,
+}
+
+/// 외부 AI 프로바이더의 실패율을 추적하는 서킷 브레이커.
+/// 실패율이 임계치를 넘으면 회로를 열어 호출을 즉시 실패시키고,
+/// `open_duration` 후 half-open 프로브가 성공하면 다시 닫는다
+pub struct CircuitBreaker {
+    name: &'static str,
+    config: CircuitBreakerConfig,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    pub fn new(name: &'static str, config: CircuitBreakerConfig) -> Self {
+        Self {
+            name,
+            config,
+            inner: Mutex::new(Inner {
+                state: State::Closed,
+                outcomes: VecDeque::new(),
+            }),
+        }
+    }
+
+    pub fn state(&self) -> CircuitState {
+        match self.inner.lock().unwrap().state {
+            State::Closed => CircuitState::Closed,
+            // 대기 시간이 지났더라도 다음 호출이 프로브가 되기 전까지는 열린 상태
+            State::Open { .. } => CircuitState::Open,
+            State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    fn acquire(&self) -> Result<Permit<'_>, ClientError> {
+        let mut inner = self.inner.lock().unwrap();
+
+        let probe = match inner.state {
+            State::Closed => false,
+            State::Open { until } => {
+                if Instant::now() < until {
+                    return Err(self.open_error(until.saturating_duration_since(Instant::now())));
+                }
+                inner.state = State::HalfOpen {
+                    in_flight: 1,
+                    succeeded: 0,
+                };
+                true
+            }
+            State::HalfOpen {
+                ref mut in_flight,
+                succeeded,
+            } => {
+                if *in_flight + succeeded >= self.config.half_open_probes {
+                    return Err(self.open_error(Duration::ZERO));
+                }
+                *in_flight += 1;
+                true
+            }
+        };
+
+        Ok(Permit {
+            breaker: self,
+            probe,
+            finished: false,
+        })
+    }
+
+    fn open_error(&self, remaining: Duration) -> ClientError {
+        ClientError::CircuitOpen(format!(
+            "{} provider is failing; calls are suspended for {:?}",
+            self.name, remaining
+        ))
+    }
+
+    fn record(&self, probe: bool, failed: bool) {
+        let mut inner = self.inner.lock().unwrap();
+
+        match inner.state {
+            State::HalfOpen {
+                in_flight,
+                succeeded,
+            } if probe => {
+                if failed {
+                    warn!("{} circuit breaker re-opened after failed probe", self.name);
+                    self.open(&mut inner);
+                } else if succeeded + 1 >= self.config.half_open_probes {
+                    info!("{} circuit breaker closed", self.name);
+                    inner.state = State::Closed;
+                    inner.outcomes.clear();
+                } else {
+                    inner.state = State::HalfOpen {
+                        in_flight: in_flight.saturating_sub(1),
+                        succeeded: succeeded + 1,
+                    };
+                }
+            }
+            State::Closed if !probe => {
+                inner.outcomes.push_back(failed);
+                while inner.outcomes.len() > self.config.window_size {
+                    inner.outcomes.pop_front();
+                }
+
+                let calls = inner.outcomes.len();
+                let failures = inner.outcomes.iter().filter(|f| **f).count();
+                if calls >= self.config.minimum_calls
+                    && failures as f64 / calls as f64 >= self.config.failure_rate_threshold
+                {
+                    warn!(
+                        "{} circuit breaker opened ({}/{} recent calls failed)",
+                        self.name, failures, calls
+                    );
+                    self.open(&mut inner);
+                }
+            }
+            // 상태가 바뀌기 전에 시작된 호출의 결과는 무시
+            _ => {}
+        }
+    }
+
+    fn release_probe(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        if let State::HalfOpen {
+            ref mut in_flight, ..
+        } = inner.state
+        {
+            *in_flight = in_flight.saturating_sub(1);
+        }
+    }
+
+    fn open(&self, inner: &mut Inner) {
+        inner.state = State::Open {
+            until: Instant::now() + self.config.open_duration,
+        };
+        inner.outcomes.clear();
+    }
+
+    async fn call<R>(
+        &self,
+        call: impl std::future::Future<Output = Result<R, ClientError>>,
+    ) -> Result<R, ClientError> {
+        let mut permit = self.acquire()?;
+        let result = call.await;
+        // 요청 자체가 잘못된 4xx 등은 프로바이더 장애가 아니므로 성공으로 취급
+        permit.finish(matches!(&result, Err(e) if e.is_retryable()));
+        result
+    }
+}
+
+/// 호출 하나에 대한 통과 허가. 결과를 기록하지 않고 drop되면 (취소된 호출)
+/// half-open 프로브 슬롯만 반환한다
+struct Permit<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+    finished: bool,
+}
+
+impl Permit<'_> {
+    fn finish(&mut self, failed: bool) {
+        self.finished = true;
+        self.breaker.record(self.probe, failed);
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if !self.finished && self.probe {
+            self.breaker.release_probe();
+        }
+    }
+}
+
+/// [`Embedder`]/[`TextGenerator`] 호출을 [`CircuitBreaker`]로 보호하는 데코레이터
+pub struct WithBreaker<T: ?Sized> {
+    inner: Arc<T>,
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl<T: ?Sized> WithBreaker<T> {
+    pub fn new(inner: Arc<T>, breaker: Arc<CircuitBreaker>) -> Self {
+        Self { inner, breaker }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: Embedder + ?Sized> Embedder for WithBreaker<T> {
+    async fn embed(&self, text: &str, purpose: EmbeddingPurpose) -> Result<Vec<f32>, ClientError> {
+        self.breaker.call(self.inner.embed(text, purpose)).await
+    }
+
+    async fn embed_batch(
+        &self,
+        texts: &[&str],
+        purpose: EmbeddingPurpose,
+    ) -> Result<Vec<Vec<f32>>, ClientError> {
+        self.breaker
+            .call(self.inner.embed_batch(texts, purpose))
+            .await
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension()
+    }
+
+    fn model_id(&self) -> &str {
+        self.inner.model_id()
+    }
+
+    fn circuit_state(&self) -> Option<CircuitState> {
+        Some(self.breaker.state())
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: TextGenerator + ?Sized> TextGenerator for WithBreaker<T> {
+    async fn generate(
+        &self,
+        prompt: &str,
+        context: Vec<String>,
+        options: &GenerationOptions,
+    ) -> Result<String, ClientError> {
+        self.breaker
+            .call(self.inner.generate(prompt, context, options))
+            .await
+    }
+
+    async fn complete(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
+    ) -> Result<String, ClientError> {
+        self.breaker
+            .call(self.inner.complete(prompt, options))
+            .await
+    }
+
+    fn circuit_state(&self) -> Option<CircuitState> {
+        Some(self.breaker.state())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    /// `failing`이 true인 동안 503을 돌려주는 클라이언트
+    struct Switchable {
+        failing: AtomicBool,
+        calls: AtomicUsize,
+    }
+
+    impl Switchable {
+        fn new(failing: bool) -> Arc<Self> {
+            Arc::new(Self {
+                failing: AtomicBool::new(failing),
+                calls: AtomicUsize::new(0),
+            })
+        }
+
+        fn set_failing(&self, failing: bool) {
+            self.failing.store(failing, Ordering::SeqCst);
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Embedder for Switchable {
+        async fn embed(
+            &self,
+            _text: &str,
+            _purpose: EmbeddingPurpose,
+        ) -> Result<Vec<f32>, ClientError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.failing.load(Ordering::SeqCst) {
+                Err(ClientError::Unavailable {
+                    message: "503".to_string(),
+                    retry_after: None,
+                })
+            } else {
+                Ok(vec![1.0])
+            }
+        }
+
+        fn dimension(&self) -> usize {
+            1
+        }
+
+        fn model_id(&self) -> &str {
+            "test"
+        }
+    }
+
+    fn config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_rate_threshold: 0.5,
+            window_size: 4,
+            minimum_calls: 4,
+            open_duration: Duration::from_secs(10),
+            half_open_probes: 1,
+        }
+    }
+
+    fn guarded(inner: Arc<Switchable>) -> WithBreaker<Switchable> {
+        WithBreaker::new(inner, Arc::new(CircuitBreaker::new("test", config())))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_opens_when_failure_rate_exceeds_threshold() {
+        let inner = Switchable::new(false);
+        let client = guarded(inner.clone());
+
+        client
+            .embed("a", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        client
+            .embed("a", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        inner.set_failing(true);
+        assert!(client
+            .embed("a", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .is_err());
+        assert_eq!(client.circuit_state(), Some(CircuitState::Closed));
+
+        // 4번 중 2번 실패 → 실패율 50%로 회로가 열린다
+        assert!(client
+            .embed("a", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .is_err());
+        assert_eq!(client.circuit_state(), Some(CircuitState::Open));
+
+        // 열린 동안에는 프로바이더를 호출하지 않고 즉시 실패
+        let result = client.embed("a", EmbeddingPurpose::RetrievalDocument).await;
+        assert!(matches!(result, Err(ClientError::CircuitOpen(_))));
+        assert_eq!(inner.calls(), 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_does_not_open_below_minimum_calls() {
+        let inner = Switchable::new(true);
+        let client = guarded(inner.clone());
+
+        for _ in 0..3 {
+            assert!(client
+                .embed("a", EmbeddingPurpose::RetrievalDocument)
+                .await
+                .is_err());
+        }
+        assert_eq!(client.circuit_state(), Some(CircuitState::Closed));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_half_open_probe_success_closes_circuit() {
+        let inner = Switchable::new(true);
+        let client = guarded(inner.clone());
+
+        for _ in 0..4 {
+            let _ = client.embed("a", EmbeddingPurpose::RetrievalDocument).await;
+        }
+        assert_eq!(client.circuit_state(), Some(CircuitState::Open));
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        inner.set_failing(false);
+
+        client
+            .embed("a", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        assert_eq!(client.circuit_state(), Some(CircuitState::Closed));
+        assert_eq!(inner.calls(), 5);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_half_open_probe_failure_reopens_circuit() {
+        let inner = Switchable::new(true);
+        let client = guarded(inner.clone());
+
+        for _ in 0..4 {
+            let _ = client.embed("a", EmbeddingPurpose::RetrievalDocument).await;
+        }
+        tokio::time::advance(Duration::from_secs(10)).await;
+
+        // 프로브 한 번만 프로바이더에 전달되고, 실패하면 다시 열린다
+        assert!(matches!(
+            client.embed("a", EmbeddingPurpose::RetrievalDocument).await,
+            Err(ClientError::Unavailable { .. })
+        ));
+        assert_eq!(client.circuit_state(), Some(CircuitState::Open));
+        assert!(matches!(
+            client.embed("a", EmbeddingPurpose::RetrievalDocument).await,
+            Err(ClientError::CircuitOpen(_))
+        ));
+        assert_eq!(inner.calls(), 5);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_half_open_allows_limited_probes() {
+        let breaker = CircuitBreaker::new("test", config());
+        for _ in 0..4 {
+            breaker.acquire().unwrap().finish(true);
+        }
+        tokio::time::advance(Duration::from_secs(10)).await;
+
+        let probe = breaker.acquire().unwrap();
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(matches!(
+            breaker.acquire().err(),
+            Some(ClientError::CircuitOpen(_))
+        ));
+
+        // 취소된 프로브는 슬롯을 반환한다
+        drop(probe);
+        assert!(breaker.acquire().is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_permanent_errors_do_not_count_as_failures() {
+        let breaker = CircuitBreaker::new("test", config());
+
+        for _ in 0..10 {
+            let result: Result<(), ClientError> = breaker
+                .call(async { Err(ClientError::GeminiApi("400".to_string())) })
+                .await;
+            assert!(result.is_err());
+        }
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
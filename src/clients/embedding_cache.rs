@@ -0,0 +1,292 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    clients::{circuit_breaker::CircuitState, ClientError, Embedder, EmbeddingPurpose},
+    utils::content_hash::content_hash,
+};
+
+/// 임베딩 결과를 모델 ID + 콘텐츠 해시로 캐싱하는 프로세스 내 캐시.
+/// 용량을 넘으면 가장 오래 전에 저장된 항목부터 제거한다
+struct Cache {
+    capacity: usize,
+    entries: HashMap<String, Vec<f32>>,
+    order: VecDeque<String>,
+}
+
+impl Cache {
+    fn get(&self, key: &str) -> Option<Vec<f32>> {
+        self.entries.get(key).cloned()
+    }
+
+    fn insert(&mut self, key: String, vector: Vec<f32>) {
+        if self.capacity == 0 || self.entries.contains_key(&key) {
+            return;
+        }
+
+        while self.entries.len() >= self.capacity {
+            match self.order.pop_front() {
+                Some(oldest) => {
+                    self.entries.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+
+        self.order.push_back(key.clone());
+        self.entries.insert(key, vector);
+    }
+}
+
+/// 같은 내용을 다시 임베딩하지 않도록 결과를 캐싱하는 [`Embedder`] 데코레이터
+pub struct CachedEmbedder<T: ?Sized> {
+    inner: Arc<T>,
+    cache: Mutex<Cache>,
+}
+
+impl<T: Embedder + ?Sized> CachedEmbedder<T> {
+    pub fn new(inner: Arc<T>, capacity: usize) -> Self {
+        Self {
+            inner,
+            cache: Mutex::new(Cache {
+                capacity,
+                entries: HashMap::new(),
+                order: VecDeque::new(),
+            }),
+        }
+    }
+
+    fn key(&self, text: &str, purpose: EmbeddingPurpose) -> String {
+        format!(
+            "{}:{}:{}",
+            self.inner.model_id(),
+            purpose.as_str(),
+            content_hash(text)
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: Embedder + ?Sized> Embedder for CachedEmbedder<T> {
+    async fn embed(&self, text: &str, purpose: EmbeddingPurpose) -> Result<Vec<f32>, ClientError> {
+        let key = self.key(text, purpose);
+        if let Some(vector) = self.cache.lock().unwrap().get(&key) {
+            return Ok(vector);
+        }
+
+        let vector = self.inner.embed(text, purpose).await?;
+        self.cache.lock().unwrap().insert(key, vector.clone());
+        Ok(vector)
+    }
+
+    async fn embed_batch(
+        &self,
+        texts: &[&str],
+        purpose: EmbeddingPurpose,
+    ) -> Result<Vec<Vec<f32>>, ClientError> {
+        let keys: Vec<String> = texts.iter().map(|text| self.key(text, purpose)).collect();
+        let mut vectors: Vec<Option<Vec<f32>>> = {
+            let cache = self.cache.lock().unwrap();
+            keys.iter().map(|key| cache.get(key)).collect()
+        };
+
+        // 캐시에 없는 텍스트만 한 번의 배치로 임베딩
+        let missing: Vec<usize> = (0..texts.len()).filter(|&i| vectors[i].is_none()).collect();
+        if !missing.is_empty() {
+            let missing_texts: Vec<&str> = missing.iter().map(|&i| texts[i]).collect();
+            let embedded = self.inner.embed_batch(&missing_texts, purpose).await?;
+
+            let mut cache = self.cache.lock().unwrap();
+            for (i, vector) in missing.into_iter().zip(embedded) {
+                cache.insert(keys[i].clone(), vector.clone());
+                vectors[i] = Some(vector);
+            }
+        }
+
+        vectors
+            .into_iter()
+            .map(|vector| {
+                vector.ok_or_else(|| {
+                    ClientError::ParseError("Embedding batch returned too few vectors".to_string())
+                })
+            })
+            .collect()
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension()
+    }
+
+    fn model_id(&self) -> &str {
+        self.inner.model_id()
+    }
+
+    fn circuit_state(&self) -> Option<CircuitState> {
+        self.inner.circuit_state()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// 호출된 텍스트 수를 세는 임베더
+    struct Counting {
+        model: &'static str,
+        embedded: AtomicUsize,
+    }
+
+    impl Counting {
+        fn new(model: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                model,
+                embedded: AtomicUsize::new(0),
+            })
+        }
+
+        fn embedded(&self) -> usize {
+            self.embedded.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Embedder for Counting {
+        async fn embed(
+            &self,
+            text: &str,
+            _purpose: EmbeddingPurpose,
+        ) -> Result<Vec<f32>, ClientError> {
+            self.embedded.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![text.len() as f32])
+        }
+
+        fn dimension(&self) -> usize {
+            1
+        }
+
+        fn model_id(&self) -> &str {
+            self.model
+        }
+    }
+
+    #[tokio::test]
+    async fn test_repeated_content_is_embedded_once() {
+        let inner = Counting::new("model-a");
+        let cached = CachedEmbedder::new(inner.clone(), 10);
+
+        let first = cached
+            .embed("같은 메모", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        let second = cached
+            .embed("  같은   메모 ", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(inner.embedded(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cache_key_includes_model_id() {
+        let inner_a = Counting::new("model-a");
+        let inner_b = Counting::new("model-b");
+        let cached_a = CachedEmbedder::new(inner_a.clone(), 10);
+        let cached_b = CachedEmbedder::new(inner_b.clone(), 10);
+
+        let purpose = EmbeddingPurpose::RetrievalDocument;
+        assert_ne!(cached_a.key("메모", purpose), cached_b.key("메모", purpose));
+        assert!(cached_a.key("메모", purpose).starts_with("model-a:"));
+    }
+
+    #[tokio::test]
+    async fn test_purposes_do_not_share_entries() {
+        let inner = Counting::new("model-a");
+        let cached = CachedEmbedder::new(inner.clone(), 10);
+
+        cached
+            .embed("메모", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        cached
+            .embed("메모", EmbeddingPurpose::RetrievalQuery)
+            .await
+            .unwrap();
+
+        assert_eq!(inner.embedded(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_embeds_only_missing_texts() {
+        let inner = Counting::new("model-a");
+        let cached = CachedEmbedder::new(inner.clone(), 10);
+
+        cached
+            .embed("b", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        let vectors = cached
+            .embed_batch(&["a", "bb", "b", "a"], EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+
+        assert_eq!(vectors, vec![vec![1.0], vec![2.0], vec![1.0], vec![1.0]]);
+        // "b" 1회 + 배치의 캐시 미스 "a", "bb", "a"
+        assert_eq!(inner.embedded(), 4);
+
+        cached
+            .embed_batch(&["a", "bb"], EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        assert_eq!(inner.embedded(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_evicts_oldest_entries_beyond_capacity() {
+        let inner = Counting::new("model-a");
+        let cached = CachedEmbedder::new(inner.clone(), 2);
+
+        cached
+            .embed("one", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        cached
+            .embed("two", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        cached
+            .embed("three", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        assert_eq!(inner.embedded(), 3);
+
+        cached
+            .embed("three", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        cached
+            .embed("one", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        assert_eq!(inner.embedded(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_zero_capacity_disables_cache() {
+        let inner = Counting::new("model-a");
+        let cached = CachedEmbedder::new(inner.clone(), 0);
+
+        cached
+            .embed("메모", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        cached
+            .embed("메모", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        assert_eq!(inner.embedded(), 2);
+    }
+}
@@ -1,3 +1,4 @@
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -5,6 +6,43 @@ pub enum ClientError {
     #[error("Gemini API error: {0}")]
     GeminiApi(String),
 
+    #[error("OpenAI-compatible API error: {0}")]
+    OpenAiApi(String),
+
+    /// 429 응답. 프로바이더가 Retry-After를 보낸 경우 대기 시간을 함께 전달
+    #[error("AI provider rate limit exceeded: {message}")]
+    RateLimited {
+        message: String,
+        retry_after: Option<Duration>,
+    },
+
+    /// 5xx 응답 (일시적인 서버 장애)
+    #[error("AI provider temporarily unavailable: {message}")]
+    Unavailable {
+        message: String,
+        retry_after: Option<Duration>,
+    },
+
+    /// 서킷 브레이커가 열려 있어 프로바이더를 호출하지 않고 즉시 실패
+    #[error("AI provider circuit is open: {0}")]
+    CircuitOpen(String),
+
+    /// 프롬프트나 생성 결과가 프로바이더의 안전 필터에 차단됨.
+    /// 생성 도중 차단된 경우 그때까지 생성된 텍스트를 `partial`로 전달
+    #[error("Content blocked by AI provider: {reason}")]
+    ContentBlocked {
+        reason: String,
+        categories: Vec<String>,
+        partial: Option<String>,
+    },
+
+    /// 최대 출력 토큰에 도달해 생성이 중간에 끊김
+    #[error("AI output truncated at the output token limit")]
+    OutputTruncated { partial: String },
+
+    #[error("Request timed out: {0}")]
+    Timeout(String),
+
     #[error("Network error: {0}")]
     Network(String),
 
@@ -14,3 +52,27 @@ pub enum ClientError {
     #[error("Qdrant error: {0}")]
     Qdrant(String),
 }
+
+impl ClientError {
+    /// 같은 요청을 다시 보내면 성공할 수 있는 일시적 실패인지 여부.
+    /// 4xx 요청 오류나 응답 파싱 실패는 재시도해도 결과가 같다
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::RateLimited { .. }
+                | Self::Unavailable { .. }
+                | Self::Timeout(_)
+                | Self::Network(_)
+        )
+    }
+
+    /// 프로바이더가 지정한 재시도 대기 시간
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimited { retry_after, .. } | Self::Unavailable { retry_after, .. } => {
+                *retry_after
+            }
+            _ => None,
+        }
+    }
+}
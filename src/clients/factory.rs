@@ -0,0 +1,565 @@
+use std::{fmt, str::FromStr, sync::Arc, time::Duration};
+use thiserror::Error;
+
+use crate::clients::{
+    lexical::DEFAULT_LEXICAL_DIMENSION, CachedEmbedder, CircuitBreaker, CircuitBreakerConfig,
+    Embedder, GeminiClient, GeminiConfig, LexicalHashEmbedder, OpenAiClient, OpenAiConfig,
+    RetryPolicy, Retrying, TextGenerator, WithBreaker,
+};
+
+/// 768차원 기준 약 30MB
+const DEFAULT_EMBEDDING_CACHE_CAPACITY: usize = 10_000;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ProviderConfigError {
+    #[error("Unknown {role} provider '{value}' (expected one of: gemini, openai, lexical)")]
+    UnknownProvider { role: &'static str, value: String },
+
+    #[error("Provider '{provider}' cannot be used for {role}")]
+    UnsupportedRole {
+        role: &'static str,
+        provider: ProviderKind,
+    },
+
+    #[error("{0} must be set when a configured AI provider uses it")]
+    MissingVar(&'static str),
+
+    #[error("{key} has an invalid value '{value}': {reason}")]
+    InvalidVar {
+        key: &'static str,
+        value: String,
+        reason: &'static str,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderKind {
+    Gemini,
+    OpenAi,
+    /// 내장 어휘 해시 임베더. 임베딩 전용
+    Lexical,
+}
+
+impl fmt::Display for ProviderKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gemini => write!(f, "gemini"),
+            Self::OpenAi => write!(f, "openai"),
+            Self::Lexical => write!(f, "lexical"),
+        }
+    }
+}
+
+impl FromStr for ProviderKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "gemini" => Ok(Self::Gemini),
+            "openai" => Ok(Self::OpenAi),
+            "lexical" => Ok(Self::Lexical),
+            _ => Err(()),
+        }
+    }
+}
+
+/// 임베딩/텍스트 생성 프로바이더 설정. 두 역할은 서로 다른 프로바이더를 쓸 수 있다
+///
+/// - `EMBEDDING_PROVIDER`, `GENERATION_PROVIDER`: `gemini`(기본값) 또는 `openai`.
+///   임베딩에는 API 키가 필요 없는 내장 `lexical`도 쓸 수 있다
+/// - `GEMINI_API_KEY`: Gemini를 사용하는 역할이 있을 때 필수
+/// - `GEMINI_BASE_URL`, `GEMINI_EMBEDDING_MODEL`, `GEMINI_GENERATION_MODEL`,
+///   `GEMINI_EMBEDDING_DIMENSION`: Gemini 엔드포인트와 모델 설정
+/// - `OPENAI_BASE_URL`, `OPENAI_API_KEY`, `OPENAI_EMBEDDING_MODEL`, `OPENAI_CHAT_MODEL`,
+///   `OPENAI_EMBEDDING_DIMENSION`: OpenAI 호환 프로바이더 설정
+/// - `LEXICAL_EMBEDDING_DIMENSION`: 내장 어휘 해시 임베더의 벡터 차원
+/// - `AI_RETRY_MAX_ATTEMPTS`, `AI_RETRY_MAX_ELAPSED_SECS`: 일시적 실패 재시도 횟수와 전체 시간 상한
+/// - `AI_BREAKER_FAILURE_RATE`, `AI_BREAKER_OPEN_SECS`: 서킷 브레이커를 여는 실패율과 열린 상태 유지 시간
+/// - `EMBEDDING_CACHE_CAPACITY`: 프로세스 내 임베딩 캐시 항목 수 (0이면 비활성화)
+#[derive(Debug, Clone)]
+pub struct AiProviderConfig {
+    pub embedding: ProviderKind,
+    pub generation: ProviderKind,
+    pub gemini: GeminiConfig,
+    pub openai: OpenAiConfig,
+    pub lexical_dimension: usize,
+    pub retry: RetryPolicy,
+    pub breaker: CircuitBreakerConfig,
+    pub embedding_cache_capacity: usize,
+}
+
+impl AiProviderConfig {
+    pub fn from_env() -> Result<Self, ProviderConfigError> {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    /// 환경 변수 대신 임의의 조회 함수로 설정을 읽는다 (테스트용)
+    pub fn from_lookup(
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, ProviderConfigError> {
+        let get = |key: &str| lookup(key).filter(|v| !v.trim().is_empty());
+
+        let embedding = parse_provider(get("EMBEDDING_PROVIDER"), "embedding")?;
+        let generation = parse_provider(get("GENERATION_PROVIDER"), "generation")?;
+
+        let gemini_defaults = GeminiConfig::default();
+        let gemini_dimension = parse_var(
+            get("GEMINI_EMBEDDING_DIMENSION"),
+            "GEMINI_EMBEDDING_DIMENSION",
+            gemini_defaults.embedding_dimension,
+            |dimension: &usize| *dimension > 0,
+            "must be a positive integer",
+        )?;
+
+        let defaults = OpenAiConfig::default();
+        let openai_dimension = parse_var(
+            get("OPENAI_EMBEDDING_DIMENSION"),
+            "OPENAI_EMBEDDING_DIMENSION",
+            defaults.embedding_dimension,
+            |dimension: &usize| *dimension > 0,
+            "must be a positive integer",
+        )?;
+
+        let lexical_dimension = parse_var(
+            get("LEXICAL_EMBEDDING_DIMENSION"),
+            "LEXICAL_EMBEDDING_DIMENSION",
+            DEFAULT_LEXICAL_DIMENSION,
+            |dimension: &usize| *dimension > 0,
+            "must be a positive integer",
+        )?;
+
+        let retry_defaults = RetryPolicy::default();
+        let max_attempts = parse_var(
+            get("AI_RETRY_MAX_ATTEMPTS"),
+            "AI_RETRY_MAX_ATTEMPTS",
+            retry_defaults.max_attempts,
+            |attempts: &u32| *attempts > 0,
+            "must be a positive integer",
+        )?;
+        let max_elapsed_secs = parse_var(
+            get("AI_RETRY_MAX_ELAPSED_SECS"),
+            "AI_RETRY_MAX_ELAPSED_SECS",
+            retry_defaults.max_elapsed.as_secs(),
+            |secs: &u64| *secs > 0,
+            "must be a positive integer",
+        )?;
+
+        let breaker_defaults = CircuitBreakerConfig::default();
+        let failure_rate_threshold = parse_var(
+            get("AI_BREAKER_FAILURE_RATE"),
+            "AI_BREAKER_FAILURE_RATE",
+            breaker_defaults.failure_rate_threshold,
+            |rate: &f64| *rate > 0.0 && *rate <= 1.0,
+            "must be a number in (0, 1]",
+        )?;
+        let open_secs = parse_var(
+            get("AI_BREAKER_OPEN_SECS"),
+            "AI_BREAKER_OPEN_SECS",
+            breaker_defaults.open_duration.as_secs(),
+            |secs: &u64| *secs > 0,
+            "must be a positive integer",
+        )?;
+
+        let embedding_cache_capacity = parse_var(
+            get("EMBEDDING_CACHE_CAPACITY"),
+            "EMBEDDING_CACHE_CAPACITY",
+            DEFAULT_EMBEDDING_CACHE_CAPACITY,
+            |_: &usize| true,
+            "must be a non-negative integer",
+        )?;
+
+        let config = Self {
+            embedding,
+            generation,
+            gemini: GeminiConfig {
+                base_url: get("GEMINI_BASE_URL").unwrap_or(gemini_defaults.base_url),
+                api_key: get("GEMINI_API_KEY").unwrap_or_default(),
+                embedding_model: get("GEMINI_EMBEDDING_MODEL")
+                    .unwrap_or(gemini_defaults.embedding_model),
+                generation_model: get("GEMINI_GENERATION_MODEL")
+                    .unwrap_or(gemini_defaults.generation_model),
+                embedding_dimension: gemini_dimension,
+            },
+            openai: OpenAiConfig {
+                base_url: get("OPENAI_BASE_URL").unwrap_or(defaults.base_url),
+                api_key: get("OPENAI_API_KEY"),
+                embedding_model: get("OPENAI_EMBEDDING_MODEL").unwrap_or(defaults.embedding_model),
+                chat_model: get("OPENAI_CHAT_MODEL").unwrap_or(defaults.chat_model),
+                embedding_dimension: openai_dimension,
+            },
+            lexical_dimension,
+            retry: RetryPolicy {
+                max_attempts,
+                max_elapsed: Duration::from_secs(max_elapsed_secs),
+                ..retry_defaults
+            },
+            breaker: CircuitBreakerConfig {
+                failure_rate_threshold,
+                open_duration: Duration::from_secs(open_secs),
+                ..breaker_defaults
+            },
+            embedding_cache_capacity,
+        };
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn uses(&self, kind: ProviderKind) -> bool {
+        self.embedding == kind || self.generation == kind
+    }
+
+    fn validate(&self) -> Result<(), ProviderConfigError> {
+        if self.generation == ProviderKind::Lexical {
+            return Err(ProviderConfigError::UnsupportedRole {
+                role: "generation",
+                provider: ProviderKind::Lexical,
+            });
+        }
+
+        if self.uses(ProviderKind::Gemini) {
+            if self.gemini.api_key.is_empty() {
+                return Err(ProviderConfigError::MissingVar("GEMINI_API_KEY"));
+            }
+            validate_base_url("GEMINI_BASE_URL", &self.gemini.base_url)?;
+        }
+
+        if self.uses(ProviderKind::OpenAi) {
+            let base_url = &self.openai.base_url;
+            validate_base_url("OPENAI_BASE_URL", base_url)?;
+
+            // 공식 OpenAI API는 키 없이 호출할 수 없으므로 기동 시점에 실패
+            if base_url.contains("api.openai.com") && self.openai.api_key.is_none() {
+                return Err(ProviderConfigError::MissingVar("OPENAI_API_KEY"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_base_url(key: &'static str, base_url: &str) -> Result<(), ProviderConfigError> {
+    if base_url.starts_with("http://") || base_url.starts_with("https://") {
+        return Ok(());
+    }
+
+    Err(ProviderConfigError::InvalidVar {
+        key,
+        value: base_url.to_string(),
+        reason: "must start with http:// or https://",
+    })
+}
+
+fn parse_var<T: FromStr>(
+    value: Option<String>,
+    key: &'static str,
+    default: T,
+    is_valid: impl Fn(&T) -> bool,
+    reason: &'static str,
+) -> Result<T, ProviderConfigError> {
+    let Some(value) = value else {
+        return Ok(default);
+    };
+
+    match value.trim().parse::<T>() {
+        Ok(parsed) if is_valid(&parsed) => Ok(parsed),
+        _ => Err(ProviderConfigError::InvalidVar { key, value, reason }),
+    }
+}
+
+fn parse_provider(
+    value: Option<String>,
+    role: &'static str,
+) -> Result<ProviderKind, ProviderConfigError> {
+    match value {
+        None => Ok(ProviderKind::Gemini),
+        Some(value) => value
+            .parse()
+            .map_err(|_| ProviderConfigError::UnknownProvider { role, value }),
+    }
+}
+
+pub struct AiProviders {
+    pub embedder: Arc<dyn Embedder>,
+    pub text_generator: Arc<dyn TextGenerator>,
+}
+
+pub fn build_providers(config: &AiProviderConfig) -> Result<AiProviders, ProviderConfigError> {
+    config.validate()?;
+
+    let gemini = config
+        .uses(ProviderKind::Gemini)
+        .then(|| Arc::new(GeminiClient::with_config(config.gemini.clone())));
+    let openai = config
+        .uses(ProviderKind::OpenAi)
+        .then(|| Arc::new(OpenAiClient::new(config.openai.clone())));
+
+    // validate()가 필요한 클라이언트의 설정을 보장하므로 아래 expect는 실패하지 않는다
+    let embedder: Arc<dyn Embedder> = match config.embedding {
+        ProviderKind::Gemini => gemini.clone().expect("validated gemini config"),
+        ProviderKind::OpenAi => openai.clone().expect("validated openai config"),
+        ProviderKind::Lexical => Arc::new(LexicalHashEmbedder::new(config.lexical_dimension)),
+    };
+    let text_generator: Arc<dyn TextGenerator> = match config.generation {
+        ProviderKind::Gemini => gemini.expect("validated gemini config"),
+        ProviderKind::OpenAi => openai.expect("validated openai config"),
+        ProviderKind::Lexical => unreachable!("validate() rejects lexical generation"),
+    };
+
+    // 재시도를 모두 소진한 호출 하나를 브레이커의 실패 한 번으로 센다
+    let embedder = Arc::new(Retrying::new(embedder, config.retry.clone()));
+    let text_generator = Arc::new(Retrying::new(text_generator, config.retry.clone()));
+
+    let embedder = Arc::new(WithBreaker::new(
+        embedder,
+        Arc::new(CircuitBreaker::new("embedding", config.breaker.clone())),
+    ));
+
+    Ok(AiProviders {
+        // 캐시 적중은 프로바이더를 호출하지 않으므로 브레이커 바깥에 둔다
+        embedder: Arc::new(CachedEmbedder::new(
+            embedder,
+            config.embedding_cache_capacity,
+        )),
+        text_generator: Arc::new(WithBreaker::new(
+            text_generator,
+            Arc::new(CircuitBreaker::new("generation", config.breaker.clone())),
+        )),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::CircuitState;
+    use std::collections::HashMap;
+
+    fn config_from(vars: &[(&str, &str)]) -> Result<AiProviderConfig, ProviderConfigError> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        AiProviderConfig::from_lookup(|key| vars.get(key).cloned())
+    }
+
+    #[test]
+    fn test_defaults_to_gemini_for_both_roles() {
+        let config = config_from(&[("GEMINI_API_KEY", "key")]).unwrap();
+        assert_eq!(config.embedding, ProviderKind::Gemini);
+        assert_eq!(config.generation, ProviderKind::Gemini);
+        assert!(build_providers(&config).is_ok());
+    }
+
+    #[test]
+    fn test_gemini_requires_api_key() {
+        let result = config_from(&[]);
+        assert_eq!(
+            result.unwrap_err(),
+            ProviderConfigError::MissingVar("GEMINI_API_KEY")
+        );
+    }
+
+    #[test]
+    fn test_mixed_providers() {
+        let config = config_from(&[
+            ("EMBEDDING_PROVIDER", "gemini"),
+            ("GENERATION_PROVIDER", "OpenAI"),
+            ("GEMINI_API_KEY", "key"),
+            ("OPENAI_BASE_URL", "http://localhost:8000/v1"),
+            ("OPENAI_CHAT_MODEL", "qwen2.5-7b-instruct"),
+        ])
+        .unwrap();
+
+        assert_eq!(config.embedding, ProviderKind::Gemini);
+        assert_eq!(config.generation, ProviderKind::OpenAi);
+        assert_eq!(config.openai.chat_model, "qwen2.5-7b-instruct");
+
+        let providers = build_providers(&config).unwrap();
+        assert_eq!(providers.embedder.dimension(), 768);
+        assert_eq!(
+            providers.text_generator.circuit_state(),
+            Some(CircuitState::Closed)
+        );
+    }
+
+    #[test]
+    fn test_openai_only_does_not_need_gemini_key() {
+        let config = config_from(&[
+            ("EMBEDDING_PROVIDER", "openai"),
+            ("GENERATION_PROVIDER", "openai"),
+            ("OPENAI_BASE_URL", "http://localhost:8080/v1"),
+            ("OPENAI_EMBEDDING_DIMENSION", "384"),
+        ])
+        .unwrap();
+
+        let providers = build_providers(&config).unwrap();
+        assert_eq!(providers.embedder.dimension(), 384);
+    }
+
+    #[test]
+    fn test_gemini_endpoint_and_models_are_configurable() {
+        let config = config_from(&[
+            ("GEMINI_API_KEY", "key"),
+            ("GEMINI_BASE_URL", "http://localhost:9000/v1beta"),
+            ("GEMINI_EMBEDDING_MODEL", "gemini-embedding-001"),
+            ("GEMINI_GENERATION_MODEL", "gemini-2.5-pro"),
+            ("GEMINI_EMBEDDING_DIMENSION", "3072"),
+        ])
+        .unwrap();
+
+        assert_eq!(config.gemini.base_url, "http://localhost:9000/v1beta");
+        assert_eq!(config.gemini.generation_model, "gemini-2.5-pro");
+
+        let providers = build_providers(&config).unwrap();
+        assert_eq!(providers.embedder.dimension(), 3072);
+        assert_eq!(providers.embedder.model_id(), "gemini-embedding-001");
+
+        let result = config_from(&[("GEMINI_API_KEY", "key"), ("GEMINI_BASE_URL", "localhost")]);
+        assert!(matches!(
+            result,
+            Err(ProviderConfigError::InvalidVar {
+                key: "GEMINI_BASE_URL",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_lexical_embedding_needs_no_api_key() {
+        let config = config_from(&[
+            ("EMBEDDING_PROVIDER", "lexical"),
+            ("GENERATION_PROVIDER", "openai"),
+            ("OPENAI_BASE_URL", "http://localhost:8080/v1"),
+            ("LEXICAL_EMBEDDING_DIMENSION", "512"),
+        ])
+        .unwrap();
+
+        let providers = build_providers(&config).unwrap();
+        assert_eq!(providers.embedder.dimension(), 512);
+        assert_eq!(providers.embedder.model_id(), "lexical-hash-v1");
+
+        let result = config_from(&[
+            ("GENERATION_PROVIDER", "lexical"),
+            ("GEMINI_API_KEY", "key"),
+        ]);
+        assert_eq!(
+            result.unwrap_err(),
+            ProviderConfigError::UnsupportedRole {
+                role: "generation",
+                provider: ProviderKind::Lexical,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unknown_provider() {
+        let result = config_from(&[("EMBEDDING_PROVIDER", "cohere"), ("GEMINI_API_KEY", "key")]);
+        assert!(matches!(
+            result,
+            Err(ProviderConfigError::UnknownProvider {
+                role: "embedding",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_official_openai_requires_api_key() {
+        let result = config_from(&[("GENERATION_PROVIDER", "openai"), ("GEMINI_API_KEY", "key")]);
+        assert_eq!(
+            result.unwrap_err(),
+            ProviderConfigError::MissingVar("OPENAI_API_KEY")
+        );
+    }
+
+    #[test]
+    fn test_retry_policy_settings() {
+        let config = config_from(&[("GEMINI_API_KEY", "key")]).unwrap();
+        assert_eq!(config.retry, RetryPolicy::default());
+
+        let config = config_from(&[
+            ("GEMINI_API_KEY", "key"),
+            ("AI_RETRY_MAX_ATTEMPTS", "2"),
+            ("AI_RETRY_MAX_ELAPSED_SECS", "10"),
+        ])
+        .unwrap();
+        assert_eq!(config.retry.max_attempts, 2);
+        assert_eq!(config.retry.max_elapsed, Duration::from_secs(10));
+
+        let result = config_from(&[("GEMINI_API_KEY", "key"), ("AI_RETRY_MAX_ATTEMPTS", "0")]);
+        assert!(matches!(
+            result,
+            Err(ProviderConfigError::InvalidVar {
+                key: "AI_RETRY_MAX_ATTEMPTS",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_breaker_settings() {
+        let config = config_from(&[
+            ("GEMINI_API_KEY", "key"),
+            ("AI_BREAKER_FAILURE_RATE", "0.25"),
+            ("AI_BREAKER_OPEN_SECS", "60"),
+        ])
+        .unwrap();
+        assert_eq!(config.breaker.failure_rate_threshold, 0.25);
+        assert_eq!(config.breaker.open_duration, Duration::from_secs(60));
+
+        let result = config_from(&[
+            ("GEMINI_API_KEY", "key"),
+            ("AI_BREAKER_FAILURE_RATE", "1.5"),
+        ]);
+        assert!(matches!(
+            result,
+            Err(ProviderConfigError::InvalidVar {
+                key: "AI_BREAKER_FAILURE_RATE",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_embedding_cache_settings() {
+        let config = config_from(&[("GEMINI_API_KEY", "key")]).unwrap();
+        assert_eq!(
+            config.embedding_cache_capacity,
+            DEFAULT_EMBEDDING_CACHE_CAPACITY
+        );
+
+        let config =
+            config_from(&[("GEMINI_API_KEY", "key"), ("EMBEDDING_CACHE_CAPACITY", "0")]).unwrap();
+        assert_eq!(config.embedding_cache_capacity, 0);
+    }
+
+    #[test]
+    fn test_invalid_openai_settings() {
+        let result = config_from(&[
+            ("EMBEDDING_PROVIDER", "openai"),
+            ("GENERATION_PROVIDER", "openai"),
+            ("OPENAI_BASE_URL", "localhost:8000"),
+        ]);
+        assert!(matches!(
+            result,
+            Err(ProviderConfigError::InvalidVar {
+                key: "OPENAI_BASE_URL",
+                ..
+            })
+        ));
+
+        let result = config_from(&[
+            ("EMBEDDING_PROVIDER", "openai"),
+            ("GENERATION_PROVIDER", "openai"),
+            ("OPENAI_BASE_URL", "http://localhost:8000/v1"),
+            ("OPENAI_EMBEDDING_DIMENSION", "0"),
+        ]);
+        assert!(matches!(
+            result,
+            Err(ProviderConfigError::InvalidVar {
+                key: "OPENAI_EMBEDDING_DIMENSION",
+                ..
+            })
+        ));
+    }
+}
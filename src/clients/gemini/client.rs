@@ -1,30 +1,111 @@
-use super::traits::{Embedder, TextGenerator};
-use crate::clients::ClientError;
+use super::traits::{Embedder, EmbeddingPurpose, TextGenerator};
+use crate::clients::{
+    http,
+    prompt::assist_prompt,
+    usage::{self, TokenUsage, UsageOperation},
+    ClientError, GenerationOptions,
+};
 use serde::{Deserialize, Serialize};
 
-const EMBEDDING_API_URL: &str =
-    "https://generativelanguage.googleapis.com/v1beta/models/text-embedding-004:embedContent";
-const GENERATION_API_URL: &str =
-    "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.5-flash:generateContent";
+const DEFAULT_BASE_URL: &str = "https://generativelanguage.googleapis.com/v1beta";
+const DEFAULT_EMBEDDING_MODEL: &str = "text-embedding-004";
+const DEFAULT_GENERATION_MODEL: &str = "gemini-2.5-flash";
+const DEFAULT_EMBEDDING_DIMENSION: usize = 768;
+
+/// Gemini API 설정. 기본 URL을 바꾸면 프록시나 로컬 대역 서버를 사용할 수 있다
+#[derive(Debug, Clone)]
+pub struct GeminiConfig {
+    /// API 버전까지 포함한 기본 URL (예: https://generativelanguage.googleapis.com/v1beta)
+    pub base_url: String,
+    pub api_key: String,
+    /// `models/` 접두사 없는 모델 이름
+    pub embedding_model: String,
+    pub generation_model: String,
+    /// 임베딩 모델이 반환하는 벡터 차원
+    pub embedding_dimension: usize,
+}
+
+impl Default for GeminiConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            api_key: String::new(),
+            embedding_model: DEFAULT_EMBEDDING_MODEL.to_string(),
+            generation_model: DEFAULT_GENERATION_MODEL.to_string(),
+            embedding_dimension: DEFAULT_EMBEDDING_DIMENSION,
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct GeminiClient {
-    api_key: String,
+    config: GeminiConfig,
     client: reqwest::Client,
 }
 
 impl GeminiClient {
+    /// 기본 엔드포인트와 모델을 사용하는 클라이언트
     pub fn new(api_key: String) -> Self {
-        Self {
+        Self::with_config(GeminiConfig {
             api_key,
+            ..GeminiConfig::default()
+        })
+    }
+
+    pub fn with_config(config: GeminiConfig) -> Self {
+        let normalize = |model: &str| model.trim_start_matches("models/").to_string();
+
+        Self {
+            config: GeminiConfig {
+                base_url: config.base_url.trim_end_matches('/').to_string(),
+                embedding_model: normalize(&config.embedding_model),
+                generation_model: normalize(&config.generation_model),
+                ..config
+            },
             client: reqwest::Client::new(),
         }
     }
+
+    /// `models/{model}:{method}` 엔드포인트 호출.
+    /// API 키는 프록시 로그에 남지 않도록 쿼리 문자열 대신 헤더로 전달한다
+    async fn post<T: Serialize, R: for<'de> Deserialize<'de>>(
+        &self,
+        model: &str,
+        method: &str,
+        body: &T,
+    ) -> Result<R, ClientError> {
+        let request = self
+            .client
+            .post(format!(
+                "{}/models/{}:{}",
+                self.config.base_url, model, method
+            ))
+            .header("x-goog-api-key", &self.config.api_key)
+            .json(body);
+
+        let response = http::send(request, ClientError::GeminiApi).await?;
+
+        response
+            .json()
+            .await
+            .map_err(|e| ClientError::ParseError(format!("Failed to parse response: {}", e)))
+    }
 }
 
 #[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
 struct EmbedRequest {
     content: Content,
+    task_type: &'static str,
+}
+
+/// Gemini 임베딩 API의 `taskType` 값
+fn task_type(purpose: EmbeddingPurpose) -> &'static str {
+    match purpose {
+        EmbeddingPurpose::RetrievalQuery => "RETRIEVAL_QUERY",
+        EmbeddingPurpose::RetrievalDocument => "RETRIEVAL_DOCUMENT",
+        EmbeddingPurpose::SemanticSimilarity => "SEMANTIC_SIMILARITY",
+    }
 }
 
 #[derive(Serialize)]
@@ -47,52 +128,146 @@ struct Embedding {
     values: Vec<f32>,
 }
 
+/// batchEmbedContents 한 요청에 담을 수 있는 최대 항목 수
+const MAX_BATCH_ITEMS: usize = 100;
+
+#[derive(Serialize)]
+struct BatchEmbedRequest {
+    requests: Vec<BatchEmbedItem>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchEmbedItem {
+    model: String,
+    content: Content,
+    task_type: &'static str,
+}
+
+#[derive(Deserialize)]
+struct BatchEmbedResponse {
+    embeddings: Vec<Embedding>,
+}
+
 #[async_trait::async_trait]
 impl Embedder for GeminiClient {
-    async fn embed(&self, text: &str) -> Result<Vec<f32>, ClientError> {
+    async fn embed(&self, text: &str, purpose: EmbeddingPurpose) -> Result<Vec<f32>, ClientError> {
         let request_body = EmbedRequest {
             content: Content {
                 parts: vec![Part {
                     text: text.to_string(),
                 }],
             },
+            task_type: task_type(purpose),
         };
 
-        let response = self
-            .client
-            .post(format!("{}?key={}", EMBEDDING_API_URL, self.api_key))
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| ClientError::Network(format!("Failed to send request: {}", e)))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(ClientError::GeminiApi(format!(
-                "API request failed with status {}: {}",
-                status, error_text
-            )));
-        }
-
-        let embed_response: EmbedResponse = response.json().await.map_err(|e| {
-            ClientError::ParseError(format!("Failed to parse response: {}", e))
-        })?;
+        let embed_response: EmbedResponse = self
+            .post(&self.config.embedding_model, "embedContent", &request_body)
+            .await?;
 
+        usage::report(
+            UsageOperation::Embed,
+            &self.config.embedding_model,
+            TokenUsage::default(),
+            1,
+        );
         Ok(embed_response.embedding.values)
     }
 
+    async fn embed_batch(
+        &self,
+        texts: &[&str],
+        purpose: EmbeddingPurpose,
+    ) -> Result<Vec<Vec<f32>>, ClientError> {
+        let mut vectors = Vec::with_capacity(texts.len());
+
+        for chunk in texts.chunks(MAX_BATCH_ITEMS) {
+            let request_body = BatchEmbedRequest {
+                requests: chunk
+                    .iter()
+                    .map(|text| BatchEmbedItem {
+                        model: format!("models/{}", self.config.embedding_model),
+                        content: Content {
+                            parts: vec![Part {
+                                text: text.to_string(),
+                            }],
+                        },
+                        task_type: task_type(purpose),
+                    })
+                    .collect(),
+            };
+
+            let batch_response: BatchEmbedResponse = self
+                .post(
+                    &self.config.embedding_model,
+                    "batchEmbedContents",
+                    &request_body,
+                )
+                .await?;
+
+            if batch_response.embeddings.len() != chunk.len() {
+                return Err(ClientError::GeminiApi(format!(
+                    "Expected {} embeddings, got {}",
+                    chunk.len(),
+                    batch_response.embeddings.len()
+                )));
+            }
+
+            usage::report(
+                UsageOperation::Embed,
+                &self.config.embedding_model,
+                TokenUsage::default(),
+                chunk.len(),
+            );
+            vectors.extend(batch_response.embeddings.into_iter().map(|e| e.values));
+        }
+
+        Ok(vectors)
+    }
+
     fn dimension(&self) -> usize {
-        768
+        self.config.embedding_dimension
+    }
+
+    fn model_id(&self) -> &str {
+        &self.config.embedding_model
     }
 }
 
 #[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
 struct GenerateRequest {
     contents: Vec<ContentItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_instruction: Option<ContentItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generation_config: Option<GenerationConfig>,
+}
+
+#[derive(Serialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct GenerationConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_output_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stop_sequences: Vec<String>,
+}
+
+impl GenerationConfig {
+    /// 지정된 옵션이 하나도 없으면 None (프로바이더 기본값 사용)
+    fn from_options(options: &GenerationOptions) -> Option<Self> {
+        let config = Self {
+            temperature: options.temperature,
+            top_p: options.top_p,
+            max_output_tokens: options.max_output_tokens,
+            stop_sequences: options.stop_sequences.clone(),
+        };
+        (config != Self::default()).then_some(config)
+    }
 }
 
 #[derive(Serialize)]
@@ -101,23 +276,144 @@ struct ContentItem {
 }
 
 #[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct GenerateResponse {
+    #[serde(default)]
     candidates: Vec<Candidate>,
+    #[serde(default)]
+    prompt_feedback: Option<PromptFeedback>,
+    #[serde(default)]
+    usage_metadata: Option<UsageMetadata>,
+}
+
+/// 프롬프트 자체가 차단되면 후보 없이 blockReason만 온다
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PromptFeedback {
+    #[serde(default)]
+    block_reason: Option<String>,
+    #[serde(default)]
+    safety_ratings: Vec<SafetyRating>,
 }
 
 #[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct Candidate {
-    content: GeneratedContent,
+    #[serde(default)]
+    content: Option<GeneratedContent>,
+    #[serde(default)]
+    finish_reason: Option<String>,
+    #[serde(default)]
+    safety_ratings: Vec<SafetyRating>,
 }
 
 #[derive(Deserialize)]
 struct GeneratedContent {
+    #[serde(default)]
     parts: Vec<GeneratedPart>,
 }
 
 #[derive(Deserialize)]
 struct GeneratedPart {
-    text: String,
+    #[serde(default)]
+    text: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SafetyRating {
+    category: String,
+    #[serde(default)]
+    probability: Option<String>,
+    #[serde(default)]
+    blocked: bool,
+}
+
+/// 안전 필터에 의해 생성이 중단되었음을 뜻하는 finishReason
+const BLOCKED_FINISH_REASONS: &[&str] = &[
+    "SAFETY",
+    "RECITATION",
+    "BLOCKLIST",
+    "PROHIBITED_CONTENT",
+    "SPII",
+    "IMAGE_SAFETY",
+];
+
+/// 차단을 일으킨 것으로 보이는 카테고리 (차단 표시되었거나 위험도 MEDIUM 이상)
+fn flagged_categories(ratings: &[SafetyRating]) -> Vec<String> {
+    ratings
+        .iter()
+        .filter(|rating| {
+            rating.blocked || matches!(rating.probability.as_deref(), Some("MEDIUM" | "HIGH"))
+        })
+        .map(|rating| rating.category.clone())
+        .collect()
+}
+
+impl GenerateResponse {
+    /// 첫 번째 후보의 텍스트. 차단, 잘림, 빈 응답은 각각 구체적인 에러로 구분한다
+    fn into_text(self) -> Result<String, ClientError> {
+        if let Some(feedback) = self.prompt_feedback {
+            if let Some(reason) = feedback.block_reason {
+                return Err(ClientError::ContentBlocked {
+                    reason,
+                    categories: flagged_categories(&feedback.safety_ratings),
+                    partial: None,
+                });
+            }
+        }
+
+        let candidate = self
+            .candidates
+            .into_iter()
+            .next()
+            .ok_or_else(|| ClientError::GeminiApi("No response generated".to_string()))?;
+
+        let text: String = candidate
+            .content
+            .map(|content| content.parts)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|part| part.text)
+            .collect();
+
+        match candidate.finish_reason.as_deref() {
+            Some("MAX_TOKENS") => Err(ClientError::OutputTruncated { partial: text }),
+            Some(reason) if BLOCKED_FINISH_REASONS.contains(&reason) => {
+                Err(ClientError::ContentBlocked {
+                    reason: reason.to_string(),
+                    categories: flagged_categories(&candidate.safety_ratings),
+                    partial: Some(text).filter(|text| !text.is_empty()),
+                })
+            }
+            _ if !text.is_empty() => Ok(text),
+            None | Some("STOP") | Some("FINISH_REASON_UNSPECIFIED") => {
+                Err(ClientError::GeminiApi("No response generated".to_string()))
+            }
+            Some(reason) => Err(ClientError::GeminiApi(format!(
+                "Generation stopped without output: {}",
+                reason
+            ))),
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct UsageMetadata {
+    prompt_token_count: i32,
+    candidates_token_count: i32,
+    total_token_count: i32,
+}
+
+impl From<UsageMetadata> for TokenUsage {
+    fn from(metadata: UsageMetadata) -> Self {
+        Self {
+            prompt_tokens: metadata.prompt_token_count,
+            candidate_tokens: metadata.candidates_token_count,
+            total_tokens: metadata.total_token_count,
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -126,57 +422,50 @@ impl TextGenerator for GeminiClient {
         &self,
         prompt: &str,
         context: Vec<String>,
+        options: &GenerationOptions,
     ) -> Result<String, ClientError> {
-        let mut prompt_text = String::from("다음은 사용자가 과거에 작성한 메모들입니다:\n\n");
+        let prompt_text = assist_prompt(prompt, &context);
 
-        for (i, memo) in context.iter().enumerate() {
-            prompt_text.push_str(&format!("메모 {}:\n{}\n\n", i + 1, memo));
-        }
-
-        prompt_text.push_str(&format!(
-            "위 메모들을 참고하여, 다음 주제에 대한 글쓰기를 도와주세요:\n{}",
-            prompt
-        ));
+        self.complete(&prompt_text, options).await
+    }
 
+    async fn complete(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
+    ) -> Result<String, ClientError> {
         let request_body = GenerateRequest {
             contents: vec![ContentItem {
                 parts: vec![Part {
-                    text: prompt_text,
+                    text: prompt.to_string(),
                 }],
             }],
+            system_instruction: options.system_text().map(|text| ContentItem {
+                parts: vec![Part { text }],
+            }),
+            generation_config: GenerationConfig::from_options(options),
         };
 
-        let response = self
-            .client
-            .post(format!("{}?key={}", GENERATION_API_URL, self.api_key))
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| ClientError::Network(format!("Failed to send request: {}", e)))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(ClientError::GeminiApi(format!(
-                "API request failed with status {}: {}",
-                status, error_text
-            )));
-        }
-
-        let generate_response: GenerateResponse = response.json().await.map_err(|e| {
-            ClientError::ParseError(format!("Failed to parse response: {}", e))
-        })?;
+        let mut generate_response: GenerateResponse = self
+            .post(
+                &self.config.generation_model,
+                "generateContent",
+                &request_body,
+            )
+            .await?;
 
-        let text = generate_response
-            .candidates
-            .first()
-            .and_then(|c| c.content.parts.first())
-            .map(|p| p.text.clone())
-            .ok_or_else(|| ClientError::GeminiApi("No response generated".to_string()))?;
+        // 차단되거나 잘린 응답도 토큰은 소비하므로 결과와 관계없이 기록
+        usage::report(
+            UsageOperation::Generate,
+            &self.config.generation_model,
+            generate_response
+                .usage_metadata
+                .take()
+                .map(TokenUsage::from)
+                .unwrap_or_default(),
+            1,
+        );
 
-        Ok(text)
+        generate_response.into_text()
     }
 }
@@ -0,0 +1,219 @@
+//! Gemini 응답 픽스처로 실제 클라이언트 코드 경로를 검증하는 테스트.
+//! 현재 픽스처는 아직 녹화하지 않은, API 문서 형식에 맞춰 작성한 합성 데이터이며
+//! (`tests/fixtures/gemini/README.md`), `GEMINI_API_KEY`를 설정하고 `AI_FIXTURES_RECORD=1`로 실행해
+//! 실제 응답으로 교체해야 한다
+
+use super::*;
+use crate::clients::{
+    replay::ReplayServer, usage, ClientError, EmbeddingPurpose, GeminiConfig, GenerationOptions,
+    UsageContext, UsageFeature, UsageOperation,
+};
+
+const UPSTREAM: &str = "https://generativelanguage.googleapis.com";
+
+fn client_for(server: &ReplayServer, config: GeminiConfig) -> GeminiClient {
+    let api_key = if !ReplayServer::recording() {
+        "replay-key".to_string()
+    } else if config.api_key.is_empty() {
+        dotenv::dotenv().ok();
+        std::env::var("GEMINI_API_KEY").expect("GEMINI_API_KEY must be set to record fixtures")
+    } else {
+        config.api_key
+    };
+
+    GeminiClient::with_config(GeminiConfig {
+        base_url: format!("{}/v1beta", server.url()),
+        api_key,
+        ..config
+    })
+}
+
+#[tokio::test]
+async fn test_replay_embed_content() {
+    let server = ReplayServer::start("gemini/embed_content", UPSTREAM).await;
+    let client = client_for(&server, GeminiConfig::default());
+
+    let vector = client
+        .embed("비 오는 날의 산책", EmbeddingPurpose::RetrievalDocument)
+        .await
+        .unwrap();
+
+    assert_eq!(vector.len(), client.dimension());
+    assert!(vector.iter().all(|v| v.is_finite()));
+    server.finish();
+}
+
+#[tokio::test]
+async fn test_replay_batch_embed_contents() {
+    let server = ReplayServer::start("gemini/batch_embed_contents", UPSTREAM).await;
+    let client = client_for(&server, GeminiConfig::default());
+
+    let vectors = client
+        .embed_batch(
+            &["첫 번째 메모", "두 번째 메모", "세 번째 메모"],
+            EmbeddingPurpose::RetrievalDocument,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(vectors.len(), 3);
+    assert!(vectors.iter().all(|v| v.len() == client.dimension()));
+    assert_ne!(vectors[0], vectors[1]);
+    server.finish();
+}
+
+#[tokio::test]
+async fn test_replay_generate_content() {
+    let server = ReplayServer::start("gemini/generate_content", UPSTREAM).await;
+    let client = client_for(&server, GeminiConfig::default());
+    let context = UsageContext {
+        user_id: 1,
+        project_id: None,
+        feature: UsageFeature::Assist,
+    };
+
+    let (result, records) = usage::track(
+        context,
+        client.generate(
+            "비 오는 날에 대한 짧은 글의 첫 문장을 제안해줘",
+            vec!["창밖으로 빗소리가 들렸다".to_string()],
+            &GenerationOptions {
+                temperature: Some(0.2),
+                max_output_tokens: Some(1024),
+                ..GenerationOptions::default()
+            },
+        ),
+    )
+    .await;
+
+    assert!(!result.unwrap().trim().is_empty());
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].operation, UsageOperation::Generate);
+    assert!(records[0].tokens.total_tokens > 0);
+    server.finish();
+}
+
+#[tokio::test]
+async fn test_replay_output_truncated() {
+    let server = ReplayServer::start("gemini/max_tokens", UPSTREAM).await;
+    let client = client_for(&server, GeminiConfig::default());
+
+    let result = client
+        .complete(
+            "한국의 사계절을 각각 세 문단씩 자세히 설명해줘",
+            &GenerationOptions {
+                max_output_tokens: Some(16),
+                ..GenerationOptions::default()
+            },
+        )
+        .await;
+
+    match result {
+        Err(ClientError::OutputTruncated { partial }) => assert!(!partial.is_empty()),
+        other => panic!("expected OutputTruncated, got {:?}", other),
+    }
+    server.finish();
+}
+
+/// 프롬프트가 차단되면 후보 없이 `promptFeedback.blockReason`만 온다
+#[tokio::test]
+async fn test_replay_prompt_blocked() {
+    let server = ReplayServer::start("gemini/prompt_blocked", UPSTREAM).await;
+    let client = client_for(&server, GeminiConfig::default());
+
+    let result = client
+        .complete(
+            "옆집 사람을 협박하는 편지를 써줘",
+            &GenerationOptions::default(),
+        )
+        .await;
+
+    match result {
+        Err(ClientError::ContentBlocked {
+            reason,
+            categories,
+            partial,
+        }) => {
+            assert_eq!(reason, "SAFETY");
+            assert_eq!(
+                categories,
+                vec![
+                    "HARM_CATEGORY_HARASSMENT",
+                    "HARM_CATEGORY_DANGEROUS_CONTENT"
+                ]
+            );
+            assert_eq!(partial, None);
+        }
+        other => panic!("expected ContentBlocked, got {:?}", other),
+    }
+    server.finish();
+}
+
+/// 안전 필터 차단은 요청만으로 재현이 보장되지 않으므로,
+/// 다시 녹화했을 때 차단되지 않았다면 다른 프롬프트로 녹화해야 한다
+#[tokio::test]
+async fn test_replay_recitation_block() {
+    let server = ReplayServer::start("gemini/recitation_block", UPSTREAM).await;
+    let client = client_for(&server, GeminiConfig::default());
+
+    let result = client
+        .complete(
+            "Bohemian Rhapsody 가사 전체를 원문 그대로 적어줘",
+            &GenerationOptions::default(),
+        )
+        .await;
+
+    match result {
+        Err(ClientError::ContentBlocked { reason, .. }) => assert_eq!(reason, "RECITATION"),
+        other => panic!("expected ContentBlocked, got {:?}", other),
+    }
+    server.finish();
+}
+
+#[tokio::test]
+async fn test_replay_invalid_api_key_error() {
+    let server = ReplayServer::start("gemini/invalid_api_key", UPSTREAM).await;
+    let client = client_for(
+        &server,
+        GeminiConfig {
+            api_key: "invalid-key".to_string(),
+            ..GeminiConfig::default()
+        },
+    );
+
+    match client
+        .embed("hello", EmbeddingPurpose::RetrievalQuery)
+        .await
+    {
+        Err(ClientError::GeminiApi(message)) => {
+            assert!(message.contains("400"));
+            assert!(message.contains("API_KEY_INVALID"));
+        }
+        other => panic!("expected GeminiApi error, got {:?}", other),
+    }
+    server.finish();
+}
+
+#[tokio::test]
+async fn test_replay_unknown_model_error() {
+    let server = ReplayServer::start("gemini/unknown_model", UPSTREAM).await;
+    let client = client_for(
+        &server,
+        GeminiConfig {
+            embedding_model: "no-such-embedding-model".to_string(),
+            ..GeminiConfig::default()
+        },
+    );
+
+    match client
+        .embed("hello", EmbeddingPurpose::RetrievalQuery)
+        .await
+    {
+        Err(ClientError::GeminiApi(message)) => {
+            assert!(message.contains("404"));
+            assert!(message.contains("NOT_FOUND"));
+        }
+        other => panic!("expected GeminiApi error, got {:?}", other),
+    }
+    server.finish();
+}
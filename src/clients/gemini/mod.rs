@@ -2,9 +2,11 @@ mod client;
 mod mock;
 mod traits;
 
+#[cfg(test)]
+mod fixture_tests;
 #[cfg(test)]
 mod tests;
 
-pub use client::GeminiClient;
+pub use client::{GeminiClient, GeminiConfig};
 pub use mock::MockGeminiClient;
-pub use traits::{Embedder, TextGenerator};
+pub use traits::{Embedder, EmbeddingPurpose, TextGenerator};
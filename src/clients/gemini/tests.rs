@@ -1,4 +1,445 @@
 use super::*;
+use crate::clients::{
+    usage, ClientError, EmbeddingPurpose, GeminiConfig, GenerationOptions, UsageContext,
+    UsageFeature, UsageOperation,
+};
+use axum::{
+    http::{HeaderMap, StatusCode, Uri},
+    Json, Router,
+};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+
+/// (경로 + 쿼리, x-goog-api-key 헤더, 본문)
+type Captured = Arc<Mutex<Vec<(String, Option<String>, Value)>>>;
+
+/// Gemini API를 흉내 내는 로컬 서버. 요청마다 `respond`로 응답을 만들고 요청 내용을 기록한다
+async fn spawn_stand_in(
+    respond: impl Fn(&str, &Value) -> (StatusCode, Value) + Clone + Send + Sync + 'static,
+) -> (String, Captured) {
+    let captured: Captured = Arc::new(Mutex::new(Vec::new()));
+
+    let handler = {
+        let captured = captured.clone();
+        move |uri: Uri, headers: HeaderMap, Json(body): Json<Value>| {
+            let captured = captured.clone();
+            let respond = respond.clone();
+            async move {
+                let path = uri
+                    .path_and_query()
+                    .map(|p| p.to_string())
+                    .unwrap_or_default();
+                let api_key = headers
+                    .get("x-goog-api-key")
+                    .and_then(|v| v.to_str().ok())
+                    .map(|v| v.to_string());
+                let (status, response) = respond(uri.path(), &body);
+                captured.lock().unwrap().push((path, api_key, body));
+                (status, Json(response))
+            }
+        }
+    };
+
+    let app = Router::new().fallback(handler);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    (format!("http://{}/v1beta", addr), captured)
+}
+
+fn client_for(base_url: String) -> GeminiClient {
+    GeminiClient::with_config(GeminiConfig {
+        base_url,
+        api_key: "test-key".to_string(),
+        embedding_model: "models/test-embedding".to_string(),
+        generation_model: "test-flash".to_string(),
+        embedding_dimension: 3,
+    })
+}
+
+#[tokio::test]
+async fn test_embed_uses_configured_model_and_header_key() {
+    let (base_url, captured) = spawn_stand_in(|_, _| {
+        (
+            StatusCode::OK,
+            json!({ "embedding": { "values": [0.1, 0.2, 0.3] } }),
+        )
+    })
+    .await;
+    let client = client_for(base_url);
+
+    let vector = client
+        .embed("안녕하세요", EmbeddingPurpose::RetrievalDocument)
+        .await
+        .unwrap();
+
+    assert_eq!(vector, vec![0.1, 0.2, 0.3]);
+    assert_eq!(client.dimension(), 3);
+    assert_eq!(client.model_id(), "test-embedding");
+
+    let requests = captured.lock().unwrap();
+    assert_eq!(requests[0].0, "/v1beta/models/test-embedding:embedContent");
+    assert_eq!(requests[0].1.as_deref(), Some("test-key"));
+    assert_eq!(requests[0].2["content"]["parts"][0]["text"], "안녕하세요");
+    assert_eq!(requests[0].2["taskType"], "RETRIEVAL_DOCUMENT");
+}
+
+#[tokio::test]
+async fn test_embed_sends_task_type_for_purpose() {
+    let (base_url, captured) = spawn_stand_in(|_, _| {
+        (
+            StatusCode::OK,
+            json!({ "embedding": { "values": [0.1, 0.2, 0.3] } }),
+        )
+    })
+    .await;
+    let client = client_for(base_url);
+
+    client
+        .embed("질문", EmbeddingPurpose::RetrievalQuery)
+        .await
+        .unwrap();
+    client
+        .embed("문장", EmbeddingPurpose::SemanticSimilarity)
+        .await
+        .unwrap();
+
+    let requests = captured.lock().unwrap();
+    assert_eq!(requests[0].2["taskType"], "RETRIEVAL_QUERY");
+    assert_eq!(requests[1].2["taskType"], "SEMANTIC_SIMILARITY");
+}
+
+#[tokio::test]
+async fn test_embed_batch_splits_requests_by_item_limit() {
+    let (base_url, captured) = spawn_stand_in(|_, body| {
+        let count = body["requests"].as_array().unwrap().len();
+        let embeddings: Vec<Value> = (0..count)
+            .map(|i| json!({ "values": [i as f32, 0.0, 0.0] }))
+            .collect();
+        (StatusCode::OK, json!({ "embeddings": embeddings }))
+    })
+    .await;
+    let client = client_for(base_url);
+
+    let texts: Vec<String> = (0..150).map(|i| format!("메모 {}", i)).collect();
+    let inputs: Vec<&str> = texts.iter().map(|t| t.as_str()).collect();
+
+    let vectors = client
+        .embed_batch(&inputs, EmbeddingPurpose::RetrievalDocument)
+        .await
+        .unwrap();
+
+    assert_eq!(vectors.len(), 150);
+    assert_eq!(vectors[100], vec![0.0, 0.0, 0.0]);
+
+    let requests = captured.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[0].0,
+        "/v1beta/models/test-embedding:batchEmbedContents"
+    );
+    assert_eq!(requests[0].2["requests"].as_array().unwrap().len(), 100);
+    assert_eq!(requests[1].2["requests"].as_array().unwrap().len(), 50);
+    assert_eq!(
+        requests[0].2["requests"][0]["model"],
+        "models/test-embedding"
+    );
+    assert_eq!(
+        requests[1].2["requests"][0]["content"]["parts"][0]["text"],
+        "메모 100"
+    );
+    assert_eq!(
+        requests[1].2["requests"][0]["taskType"],
+        "RETRIEVAL_DOCUMENT"
+    );
+}
+
+#[tokio::test]
+async fn test_complete_parses_candidate_text() {
+    let (base_url, captured) = spawn_stand_in(|_, _| {
+        (
+            StatusCode::OK,
+            json!({
+                "candidates": [{
+                    "content": { "parts": [{ "text": "생성된 글" }], "role": "model" },
+                    "finishReason": "STOP"
+                }]
+            }),
+        )
+    })
+    .await;
+    let client = client_for(base_url);
+
+    let text = client
+        .complete("글을 써줘", &GenerationOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(text, "생성된 글");
+    let requests = captured.lock().unwrap();
+    assert_eq!(requests[0].0, "/v1beta/models/test-flash:generateContent");
+    assert_eq!(
+        requests[0].2["contents"][0]["parts"][0]["text"],
+        "글을 써줘"
+    );
+}
+
+#[tokio::test]
+async fn test_usage_metadata_is_reported_to_scope() {
+    let (base_url, _) = spawn_stand_in(|path, _| {
+        if path.ends_with(":batchEmbedContents") {
+            (
+                StatusCode::OK,
+                json!({ "embeddings": [{ "values": [0.1] }, { "values": [0.2] }] }),
+            )
+        } else {
+            (
+                StatusCode::OK,
+                json!({
+                    "candidates": [{ "content": { "parts": [{ "text": "생성된 글" }] } }],
+                    "usageMetadata": {
+                        "promptTokenCount": 12,
+                        "candidatesTokenCount": 30,
+                        "totalTokenCount": 42
+                    }
+                }),
+            )
+        }
+    })
+    .await;
+    let client = client_for(base_url);
+    let context = UsageContext {
+        user_id: 1,
+        project_id: None,
+        feature: UsageFeature::Assist,
+    };
+
+    let (_, records) = usage::track(context, async {
+        client
+            .embed_batch(&["a", "b"], EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+        client
+            .complete("글을 써줘", &GenerationOptions::default())
+            .await
+            .unwrap();
+    })
+    .await;
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].operation, UsageOperation::Embed);
+    assert_eq!(records[0].model, "test-embedding");
+    assert_eq!(records[0].items, 2);
+    assert_eq!(records[1].operation, UsageOperation::Generate);
+    assert_eq!(records[1].model, "test-flash");
+    assert_eq!(records[1].tokens.prompt_tokens, 12);
+    assert_eq!(records[1].tokens.candidate_tokens, 30);
+    assert_eq!(records[1].tokens.total_tokens, 42);
+}
+
+#[tokio::test]
+async fn test_generation_options_map_to_config_and_system_instruction() {
+    let (base_url, captured) = spawn_stand_in(|_, _| {
+        (
+            StatusCode::OK,
+            json!({ "candidates": [{ "content": { "parts": [{ "text": "ok" }] } }] }),
+        )
+    })
+    .await;
+    let client = client_for(base_url);
+
+    let options = GenerationOptions {
+        temperature: Some(0.3),
+        top_p: Some(0.9),
+        max_output_tokens: Some(256),
+        stop_sequences: vec!["END".to_string()],
+        system_instruction: Some("간결하게 답하세요.".to_string()),
+        response_language: Some("ja".to_string()),
+    };
+    client.complete("글을 써줘", &options).await.unwrap();
+    client
+        .complete("글을 써줘", &GenerationOptions::default())
+        .await
+        .unwrap();
+
+    let requests = captured.lock().unwrap();
+    let body = &requests[0].2;
+    assert_eq!(body["generationConfig"]["temperature"], 0.3);
+    assert_eq!(body["generationConfig"]["topP"], 0.9);
+    assert_eq!(body["generationConfig"]["maxOutputTokens"], 256);
+    assert_eq!(body["generationConfig"]["stopSequences"], json!(["END"]));
+    let system = body["systemInstruction"]["parts"][0]["text"]
+        .as_str()
+        .unwrap();
+    assert!(system.starts_with("간결하게 답하세요."));
+    assert!(system.contains("'ja'"));
+
+    // 옵션이 없으면 필드를 보내지 않아 프로바이더 기본값을 사용
+    let body = &requests[1].2;
+    assert!(body.get("generationConfig").is_none());
+    assert!(body.get("systemInstruction").is_none());
+}
+
+/// 고정된 generateContent 응답을 돌려주는 클라이언트
+async fn client_returning(response: Value) -> GeminiClient {
+    let (base_url, _) = spawn_stand_in(move |_, _| (StatusCode::OK, response.clone())).await;
+    client_for(base_url)
+}
+
+#[tokio::test]
+async fn test_blocked_prompt_is_content_blocked() {
+    let client = client_returning(json!({
+        "promptFeedback": {
+            "blockReason": "SAFETY",
+            "safetyRatings": [
+                { "category": "HARM_CATEGORY_HARASSMENT", "probability": "HIGH", "blocked": true },
+                { "category": "HARM_CATEGORY_HATE_SPEECH", "probability": "NEGLIGIBLE" }
+            ]
+        },
+        "usageMetadata": { "promptTokenCount": 8, "totalTokenCount": 8 }
+    }))
+    .await;
+
+    match client
+        .complete("차단될 프롬프트", &GenerationOptions::default())
+        .await
+    {
+        Err(ClientError::ContentBlocked {
+            reason,
+            categories,
+            partial,
+        }) => {
+            assert_eq!(reason, "SAFETY");
+            assert_eq!(categories, vec!["HARM_CATEGORY_HARASSMENT"]);
+            assert_eq!(partial, None);
+        }
+        other => panic!("expected ContentBlocked, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_safety_finish_reason_keeps_partial_text() {
+    let client = client_returning(json!({
+        "candidates": [{
+            "content": { "parts": [{ "text": "중간까지 생성된" }], "role": "model" },
+            "finishReason": "SAFETY",
+            "safetyRatings": [
+                { "category": "HARM_CATEGORY_DANGEROUS_CONTENT", "probability": "MEDIUM" }
+            ]
+        }]
+    }))
+    .await;
+
+    match client
+        .complete("글을 써줘", &GenerationOptions::default())
+        .await
+    {
+        Err(ClientError::ContentBlocked {
+            reason,
+            categories,
+            partial,
+        }) => {
+            assert_eq!(reason, "SAFETY");
+            assert_eq!(categories, vec!["HARM_CATEGORY_DANGEROUS_CONTENT"]);
+            assert_eq!(partial.as_deref(), Some("중간까지 생성된"));
+        }
+        other => panic!("expected ContentBlocked, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_max_tokens_is_output_truncated_with_partial_text() {
+    let client = client_returning(json!({
+        "candidates": [{
+            "content": { "parts": [{ "text": "앞부분, " }, { "text": "잘린 뒷부분" }] },
+            "finishReason": "MAX_TOKENS"
+        }]
+    }))
+    .await;
+
+    match client
+        .complete("긴 글을 써줘", &GenerationOptions::default())
+        .await
+    {
+        Err(ClientError::OutputTruncated { partial }) => {
+            assert_eq!(partial, "앞부분, 잘린 뒷부분");
+        }
+        other => panic!("expected OutputTruncated, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_empty_candidates_is_api_error() {
+    let client = client_returning(json!({ "candidates": [] })).await;
+
+    match client
+        .complete("글을 써줘", &GenerationOptions::default())
+        .await
+    {
+        Err(ClientError::GeminiApi(msg)) => assert!(msg.contains("No response")),
+        other => panic!("expected GeminiApi, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_blocked_response_still_reports_usage() {
+    let client = client_returning(json!({
+        "promptFeedback": { "blockReason": "OTHER" },
+        "usageMetadata": { "promptTokenCount": 5, "totalTokenCount": 5 }
+    }))
+    .await;
+    let context = UsageContext {
+        user_id: 1,
+        project_id: None,
+        feature: UsageFeature::Assist,
+    };
+
+    let (result, records) = usage::track(
+        context,
+        client.complete("프롬프트", &GenerationOptions::default()),
+    )
+    .await;
+
+    assert!(matches!(result, Err(ClientError::ContentBlocked { .. })));
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].tokens.prompt_tokens, 5);
+}
+
+#[tokio::test]
+async fn test_error_status_maps_to_client_error() {
+    let (base_url, _) = spawn_stand_in(|path, _| {
+        if path.ends_with(":embedContent") {
+            (
+                StatusCode::BAD_REQUEST,
+                json!({ "error": { "code": 400, "message": "API key not valid", "status": "INVALID_ARGUMENT" } }),
+            )
+        } else {
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                json!({ "error": { "code": 429, "status": "RESOURCE_EXHAUSTED" } }),
+            )
+        }
+    })
+    .await;
+    let client = client_for(base_url);
+
+    match client
+        .embed("hello", EmbeddingPurpose::RetrievalDocument)
+        .await
+    {
+        Err(ClientError::GeminiApi(msg)) => assert!(msg.contains("API key not valid")),
+        other => panic!("unexpected result: {:?}", other.map(|v| v.len())),
+    }
+    assert!(matches!(
+        client
+            .complete("hello", &GenerationOptions::default())
+            .await,
+        Err(ClientError::RateLimited { .. })
+    ));
+}
 
 #[tokio::test]
 #[ignore]
@@ -8,7 +449,9 @@ async fn test_real_gemini_embedding() {
 
     let client = GeminiClient::new(api_key);
 
-    let result = client.embed("안녕하세요").await;
+    let result = client
+        .embed("안녕하세요", EmbeddingPurpose::RetrievalDocument)
+        .await;
 
     assert!(result.is_ok(), "Embedding failed: {:?}", result.err());
     let vector = result.unwrap();
@@ -25,6 +468,28 @@ async fn test_real_gemini_embedding() {
     println!("   벡터 샘플: {:?}", &vector[0..5]);
 }
 
+#[tokio::test]
+#[ignore]
+async fn test_real_gemini_batch_embedding() {
+    dotenv::dotenv().ok();
+    let api_key = std::env::var("GEMINI_API_KEY").expect("GEMINI_API_KEY not set");
+
+    let client = GeminiClient::new(api_key);
+
+    // 요청당 최대 항목 수를 넘는 입력은 나누어 전송된다
+    let texts: Vec<String> = (0..150).map(|i| format!("메모 {}", i)).collect();
+    let inputs: Vec<&str> = texts.iter().map(|t| t.as_str()).collect();
+
+    let result = client
+        .embed_batch(&inputs, EmbeddingPurpose::RetrievalDocument)
+        .await;
+
+    assert!(result.is_ok(), "Batch embedding failed: {:?}", result.err());
+    let vectors = result.unwrap();
+    assert_eq!(vectors.len(), 150);
+    assert!(vectors.iter().all(|v| v.len() == 768));
+}
+
 #[tokio::test]
 #[ignore]
 async fn test_real_gemini_generation() {
@@ -37,14 +502,11 @@ async fn test_real_gemini_generation() {
         .generate(
             "사랑에 대해 쓰고 싶어",
             vec!["사랑은 수용이다".to_string()],
+            &GenerationOptions::default(),
         )
         .await;
 
-    assert!(
-        result.is_ok(),
-        "Text generation failed: {:?}",
-        result.err()
-    );
+    assert!(result.is_ok(), "Text generation failed: {:?}", result.err());
     let text = result.unwrap();
     assert!(!text.is_empty(), "Generated text is empty");
 
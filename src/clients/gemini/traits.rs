@@ -1,9 +1,53 @@
-use crate::clients::ClientError;
+use crate::clients::{circuit_breaker::CircuitState, ClientError, GenerationOptions};
+
+/// 임베딩 용도. 질의와 문서를 구분해 최적화하는 프로바이더(Gemini `taskType`)에 전달되며,
+/// 지원하지 않는 프로바이더는 무시한다
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmbeddingPurpose {
+    /// 검색 질의 (어시스트 프롬프트)
+    RetrievalQuery,
+    /// 검색 대상 문서 (메모)
+    RetrievalDocument,
+    /// 텍스트 간 유사도 비교
+    SemanticSimilarity,
+}
+
+impl EmbeddingPurpose {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::RetrievalQuery => "retrieval_query",
+            Self::RetrievalDocument => "retrieval_document",
+            Self::SemanticSimilarity => "semantic_similarity",
+        }
+    }
+}
 
 #[async_trait::async_trait]
 pub trait Embedder: Send + Sync {
-    async fn embed(&self, text: &str) -> Result<Vec<f32>, ClientError>;
+    async fn embed(&self, text: &str, purpose: EmbeddingPurpose) -> Result<Vec<f32>, ClientError>;
     fn dimension(&self) -> usize;
+
+    /// 임베딩 모델 식별자. 같은 텍스트라도 모델이 다르면 벡터가 다르므로 캐시 키에 포함된다
+    fn model_id(&self) -> &str;
+
+    /// 여러 텍스트를 임베딩하여 입력 순서대로 반환.
+    /// 기본 구현은 한 건씩 순차 호출하므로 배치 API가 있는 프로바이더는 재정의한다
+    async fn embed_batch(
+        &self,
+        texts: &[&str],
+        purpose: EmbeddingPurpose,
+    ) -> Result<Vec<Vec<f32>>, ClientError> {
+        let mut vectors = Vec::with_capacity(texts.len());
+        for text in texts {
+            vectors.push(self.embed(text, purpose).await?);
+        }
+        Ok(vectors)
+    }
+
+    /// 서킷 브레이커로 보호되는 경우 현재 회로 상태
+    fn circuit_state(&self) -> Option<CircuitState> {
+        None
+    }
 }
 
 #[async_trait::async_trait]
@@ -12,5 +56,18 @@ pub trait TextGenerator: Send + Sync {
         &self,
         prompt: &str,
         context: Vec<String>,
+        options: &GenerationOptions,
+    ) -> Result<String, ClientError>;
+
+    /// 메모 컨텍스트 템플릿 없이 프롬프트를 그대로 모델에 전달
+    async fn complete(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
     ) -> Result<String, ClientError>;
+
+    /// 서킷 브레이커로 보호되는 경우 현재 회로 상태
+    fn circuit_state(&self) -> Option<CircuitState> {
+        None
+    }
 }
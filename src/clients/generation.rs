@@ -0,0 +1,50 @@
+/// 텍스트 생성 옵션. 지정하지 않은 값은 프로바이더 기본값을 사용한다
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GenerationOptions {
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+    pub max_output_tokens: Option<u32>,
+    pub stop_sequences: Vec<String>,
+    pub system_instruction: Option<String>,
+    /// 응답 언어 코드 (예: ko, en, ja). 시스템 지시문에 언어 지시를 덧붙여 전달한다
+    pub response_language: Option<String>,
+}
+
+impl GenerationOptions {
+    /// 응답 언어 지시를 포함한 최종 시스템 지시문
+    pub fn system_text(&self) -> Option<String> {
+        let language = self
+            .response_language
+            .as_ref()
+            .map(|lang| format!("응답은 언어 코드 '{}'에 해당하는 언어로 작성하세요.", lang));
+
+        match (&self.system_instruction, language) {
+            (Some(instruction), Some(language)) => Some(format!("{}\n\n{}", instruction, language)),
+            (Some(instruction), None) => Some(instruction.clone()),
+            (None, language) => language,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_text_combines_instruction_and_language() {
+        let options = GenerationOptions {
+            system_instruction: Some("간결하게 답하세요.".to_string()),
+            response_language: Some("en".to_string()),
+            ..Default::default()
+        };
+
+        let text = options.system_text().unwrap();
+        assert!(text.starts_with("간결하게 답하세요."));
+        assert!(text.contains("'en'"));
+    }
+
+    #[test]
+    fn test_system_text_is_none_without_instruction_or_language() {
+        assert_eq!(GenerationOptions::default().system_text(), None);
+    }
+}
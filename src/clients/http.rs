@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+use reqwest::{header::RETRY_AFTER, RequestBuilder, Response, StatusCode};
+use std::time::Duration;
+
+use crate::clients::ClientError;
+
+/// 요청을 보내고 실패 응답을 상태 코드에 맞는 [`ClientError`]로 변환.
+/// 429/5xx는 재시도 가능한 에러로, 그 외 실패는 `api_error`로 감싼다
+pub(crate) async fn send(
+    request: RequestBuilder,
+    api_error: fn(String) -> ClientError,
+) -> Result<Response, ClientError> {
+    let response = request.send().await.map_err(|e| {
+        if e.is_timeout() {
+            ClientError::Timeout(format!("Request timed out: {}", e))
+        } else {
+            ClientError::Network(format!("Failed to send request: {}", e))
+        }
+    })?;
+
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let retry_after = response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_retry_after);
+    let error_text = response
+        .text()
+        .await
+        .unwrap_or_else(|_| "Unknown error".to_string());
+    let message = format!("API request failed with status {}: {}", status, error_text);
+
+    Err(match status {
+        StatusCode::TOO_MANY_REQUESTS => ClientError::RateLimited {
+            message,
+            retry_after,
+        },
+        StatusCode::INTERNAL_SERVER_ERROR
+        | StatusCode::BAD_GATEWAY
+        | StatusCode::SERVICE_UNAVAILABLE
+        | StatusCode::GATEWAY_TIMEOUT => ClientError::Unavailable {
+            message,
+            retry_after,
+        },
+        _ => api_error(message),
+    })
+}
+
+/// Retry-After 헤더 파싱. 초 단위 정수와 HTTP-date 형식을 모두 지원한다
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.with_timezone(&Utc) - Utc::now();
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_retry_after_seconds() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after(" 0 "), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_parse_retry_after_http_date() {
+        let future = (Utc::now() + chrono::Duration::seconds(30)).to_rfc2822();
+        let wait = parse_retry_after(&future).unwrap();
+        assert!(wait > Duration::from_secs(25) && wait <= Duration::from_secs(30));
+
+        // 이미 지난 시각이면 즉시 재시도
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after_invalid() {
+        assert_eq!(parse_retry_after("soon"), None);
+        assert_eq!(parse_retry_after("-5"), None);
+    }
+}
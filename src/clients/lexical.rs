@@ -0,0 +1,210 @@
+use crate::clients::{
+    usage::{self, TokenUsage, UsageOperation},
+    ClientError, Embedder, EmbeddingPurpose,
+};
+
+/// 알고리즘이 바뀌면 저장된 벡터와 호환되지 않으므로 버전을 모델 식별자에 포함한다
+const MODEL_ID: &str = "lexical-hash-v1";
+
+pub const DEFAULT_LEXICAL_DIMENSION: usize = 768;
+
+/// 외부 API 없이 동작하는 어휘 기반 임베더.
+///
+/// 토큰의 문자 n-gram을 고정 해시(FNV-1a)로 `dimension`개 버킷에 투영한 뒤 L2 정규화한다.
+/// 한글 토큰은 음절 단위 1~2-gram을 사용해 "커피를"/"커피가"처럼 조사가 붙은 형태도 겹치게 하고,
+/// 그 외 토큰은 경계 표시를 붙인 3-gram과 단어 전체를 특징으로 쓴다.
+/// 의미적 유사도는 알 수 없지만 같은 입력에는 항상 같은 벡터를 돌려준다
+#[derive(Debug, Clone)]
+pub struct LexicalHashEmbedder {
+    dimension: usize,
+}
+
+impl LexicalHashEmbedder {
+    pub fn new(dimension: usize) -> Self {
+        assert!(
+            dimension > 0,
+            "lexical embedding dimension must be positive"
+        );
+        Self { dimension }
+    }
+
+    fn vectorize(&self, text: &str) -> Vec<f32> {
+        let mut vector = vec![0.0f32; self.dimension];
+
+        for token in tokenize(text) {
+            for (feature, weight) in features(&token) {
+                let hash = fnv1a(feature.as_bytes());
+                let index = (hash % self.dimension as u64) as usize;
+                // 상위 비트로 부호를 정해 해시 충돌이 한쪽으로 쌓이지 않게 한다
+                let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+                vector[index] += sign * weight;
+            }
+        }
+
+        let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|v| *v /= norm);
+        }
+        vector
+    }
+}
+
+impl Default for LexicalHashEmbedder {
+    fn default() -> Self {
+        Self::new(DEFAULT_LEXICAL_DIMENSION)
+    }
+}
+
+#[async_trait::async_trait]
+impl Embedder for LexicalHashEmbedder {
+    async fn embed(&self, text: &str, _purpose: EmbeddingPurpose) -> Result<Vec<f32>, ClientError> {
+        usage::report(UsageOperation::Embed, MODEL_ID, TokenUsage::default(), 1);
+        Ok(self.vectorize(text))
+    }
+
+    async fn embed_batch(
+        &self,
+        texts: &[&str],
+        _purpose: EmbeddingPurpose,
+    ) -> Result<Vec<Vec<f32>>, ClientError> {
+        usage::report(
+            UsageOperation::Embed,
+            MODEL_ID,
+            TokenUsage::default(),
+            texts.len(),
+        );
+        Ok(texts.iter().map(|text| self.vectorize(text)).collect())
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn model_id(&self) -> &str {
+        MODEL_ID
+    }
+}
+
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+}
+
+fn is_hangul_syllable(c: char) -> bool {
+    ('\u{AC00}'..='\u{D7A3}').contains(&c)
+}
+
+fn features(token: &str) -> Vec<(String, f32)> {
+    let chars: Vec<char> = token.chars().collect();
+    let mut features = vec![(format!("w:{}", token), 1.0)];
+
+    if chars.iter().any(|c| is_hangul_syllable(*c)) {
+        // 한글은 음절 하나가 형태소에 가까우므로 음절 자체도 약하게 반영한다
+        for c in &chars {
+            features.push((format!("s:{}", c), 0.5));
+        }
+        for pair in chars.windows(2) {
+            features.push((format!("b:{}{}", pair[0], pair[1]), 1.0));
+        }
+    } else {
+        let padded: Vec<char> = std::iter::once('<')
+            .chain(chars.iter().copied())
+            .chain(std::iter::once('>'))
+            .collect();
+        for gram in padded.windows(3) {
+            features.push((format!("t:{}", gram.iter().collect::<String>()), 1.0));
+        }
+    }
+
+    features
+}
+
+/// 64비트 FNV-1a. 프로세스나 Rust 버전과 무관하게 같은 값을 내야 하므로 std 해셔를 쓰지 않는다
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PURPOSE: EmbeddingPurpose = EmbeddingPurpose::RetrievalDocument;
+
+    fn cosine(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    async fn rank(embedder: &LexicalHashEmbedder, query: &str, memos: &[&str]) -> Vec<usize> {
+        let query = embedder
+            .embed(query, EmbeddingPurpose::RetrievalQuery)
+            .await
+            .unwrap();
+        let vectors = embedder.embed_batch(memos, PURPOSE).await.unwrap();
+
+        let mut ranked: Vec<usize> = (0..memos.len()).collect();
+        ranked.sort_by(|a, b| {
+            cosine(&query, &vectors[*b])
+                .partial_cmp(&cosine(&query, &vectors[*a]))
+                .unwrap()
+        });
+        ranked
+    }
+
+    #[tokio::test]
+    async fn test_vectors_are_deterministic_and_normalized() {
+        let embedder = LexicalHashEmbedder::new(256);
+
+        let first = embedder
+            .embed("주인공은 비 오는 밤에 떠났다", PURPOSE)
+            .await
+            .unwrap();
+        let second = embedder
+            .embed("주인공은 비 오는 밤에 떠났다", PURPOSE)
+            .await
+            .unwrap();
+
+        assert_eq!(first.len(), 256);
+        assert_eq!(first, second);
+        assert!((cosine(&first, &first) - 1.0).abs() < 1e-5);
+        assert_eq!(embedder.model_id(), "lexical-hash-v1");
+    }
+
+    #[tokio::test]
+    async fn test_empty_text_yields_zero_vector() {
+        let embedder = LexicalHashEmbedder::new(16);
+        let vector = embedder.embed("  ...  ", PURPOSE).await.unwrap();
+        assert!(vector.iter().all(|v| *v == 0.0));
+    }
+
+    #[tokio::test]
+    async fn test_related_korean_memo_ranks_first() {
+        let embedder = LexicalHashEmbedder::default();
+        let memos = [
+            "오늘 점심으로 김치찌개를 먹었다",
+            "카페에서 마신 커피가 너무 썼다",
+            "주말에 등산을 다녀왔다",
+        ];
+
+        assert_eq!(rank(&embedder, "커피를 마셨던 기억", &memos).await[0], 1);
+        assert_eq!(rank(&embedder, "김치찌개 맛집", &memos).await[0], 0);
+    }
+
+    #[tokio::test]
+    async fn test_related_english_memo_ranks_first() {
+        let embedder = LexicalHashEmbedder::default();
+        let memos = [
+            "The detective searched the abandoned lighthouse",
+            "A recipe for lemon cake with vanilla frosting",
+            "Notes about the sailing trip along the coast",
+        ];
+
+        assert_eq!(
+            rank(&embedder, "lighthouse detective scene", &memos).await[0],
+            0
+        );
+        assert_eq!(rank(&embedder, "baking cakes", &memos).await[0], 1);
+    }
+}
@@ -1,5 +1,25 @@
+pub mod circuit_breaker;
+pub mod embedding_cache;
 pub mod errors;
+pub mod factory;
 pub mod gemini;
+pub mod generation;
+mod http;
+pub mod lexical;
+pub mod openai;
+pub mod prompt;
+#[cfg(test)]
+pub(crate) mod replay;
+pub mod retry;
+pub mod usage;
 
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState, WithBreaker};
+pub use embedding_cache::CachedEmbedder;
 pub use errors::ClientError;
-pub use gemini::{Embedder, GeminiClient, TextGenerator};
+pub use factory::{build_providers, AiProviderConfig, AiProviders, ProviderKind};
+pub use gemini::{Embedder, EmbeddingPurpose, GeminiClient, GeminiConfig, TextGenerator};
+pub use generation::GenerationOptions;
+pub use lexical::LexicalHashEmbedder;
+pub use openai::{OpenAiClient, OpenAiConfig};
+pub use retry::{RetryPolicy, Retrying};
+pub use usage::{UsageContext, UsageFeature, UsageOperation, UsageRecord};
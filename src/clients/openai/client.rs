@@ -0,0 +1,313 @@
+use crate::clients::{
+    http,
+    prompt::assist_prompt,
+    usage::{self, TokenUsage, UsageOperation},
+    ClientError, Embedder, EmbeddingPurpose, GenerationOptions, TextGenerator,
+};
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
+const DEFAULT_EMBEDDING_MODEL: &str = "text-embedding-3-small";
+const DEFAULT_CHAT_MODEL: &str = "gpt-4o-mini";
+const DEFAULT_EMBEDDING_DIMENSION: usize = 1536;
+
+/// OpenAI 호환 API 설정. OpenAI, Azure OpenAI(v1 API), llama.cpp, vLLM 등
+/// `/embeddings`, `/chat/completions` 엔드포인트를 제공하는 서버에 사용할 수 있다
+#[derive(Debug, Clone)]
+pub struct OpenAiConfig {
+    /// `/v1`까지 포함한 API 기본 URL (예: http://localhost:8000/v1)
+    pub base_url: String,
+    /// 로컬 서버처럼 인증이 필요 없는 경우 None
+    pub api_key: Option<String>,
+    pub embedding_model: String,
+    pub chat_model: String,
+    /// 임베딩 모델이 반환하는 벡터 차원
+    pub embedding_dimension: usize,
+}
+
+impl Default for OpenAiConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            api_key: None,
+            embedding_model: DEFAULT_EMBEDDING_MODEL.to_string(),
+            chat_model: DEFAULT_CHAT_MODEL.to_string(),
+            embedding_dimension: DEFAULT_EMBEDDING_DIMENSION,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct OpenAiClient {
+    config: OpenAiConfig,
+    client: reqwest::Client,
+}
+
+impl OpenAiClient {
+    pub fn new(config: OpenAiConfig) -> Self {
+        Self {
+            config: OpenAiConfig {
+                base_url: config.base_url.trim_end_matches('/').to_string(),
+                ..config
+            },
+            client: reqwest::Client::new(),
+        }
+    }
+
+    async fn post<T: Serialize, R: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        body: &T,
+    ) -> Result<R, ClientError> {
+        let mut request = self
+            .client
+            .post(format!("{}{}", self.config.base_url, path))
+            .json(body);
+
+        if let Some(ref api_key) = self.config.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = http::send(request, ClientError::OpenAiApi).await?;
+
+        response
+            .json()
+            .await
+            .map_err(|e| ClientError::ParseError(format!("Failed to parse response: {}", e)))
+    }
+}
+
+/// `/embeddings` 한 요청에 담을 최대 입력 수
+const MAX_BATCH_ITEMS: usize = 256;
+
+#[derive(Serialize)]
+struct EmbeddingRequest<'a> {
+    model: &'a str,
+    input: &'a str,
+}
+
+#[derive(Serialize)]
+struct BatchEmbeddingRequest<'a> {
+    model: &'a str,
+    input: &'a [&'a str],
+}
+
+#[derive(Deserialize)]
+struct EmbeddingResponse {
+    data: Vec<EmbeddingData>,
+    #[serde(default)]
+    usage: Option<Usage>,
+}
+
+/// 응답의 `usage` 필드. 임베딩 응답에는 completion_tokens가 없다
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct Usage {
+    prompt_tokens: i32,
+    completion_tokens: i32,
+    total_tokens: i32,
+}
+
+impl From<Usage> for TokenUsage {
+    fn from(usage: Usage) -> Self {
+        Self {
+            prompt_tokens: usage.prompt_tokens,
+            candidate_tokens: usage.completion_tokens,
+            total_tokens: usage.total_tokens,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct EmbeddingData {
+    #[serde(default)]
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+#[async_trait::async_trait]
+impl Embedder for OpenAiClient {
+    async fn embed(&self, text: &str, _purpose: EmbeddingPurpose) -> Result<Vec<f32>, ClientError> {
+        let request_body = EmbeddingRequest {
+            model: &self.config.embedding_model,
+            input: text,
+        };
+
+        let response: EmbeddingResponse = self.post("/embeddings", &request_body).await?;
+
+        let vector = response
+            .data
+            .into_iter()
+            .next()
+            .map(|d| d.embedding)
+            .ok_or_else(|| ClientError::OpenAiApi("No embedding returned".to_string()))?;
+
+        usage::report(
+            UsageOperation::Embed,
+            &self.config.embedding_model,
+            response.usage.map(TokenUsage::from).unwrap_or_default(),
+            1,
+        );
+        Ok(vector)
+    }
+
+    async fn embed_batch(
+        &self,
+        texts: &[&str],
+        _purpose: EmbeddingPurpose,
+    ) -> Result<Vec<Vec<f32>>, ClientError> {
+        let mut vectors = Vec::with_capacity(texts.len());
+
+        for chunk in texts.chunks(MAX_BATCH_ITEMS) {
+            let request_body = BatchEmbeddingRequest {
+                model: &self.config.embedding_model,
+                input: chunk,
+            };
+
+            let mut response: EmbeddingResponse = self.post("/embeddings", &request_body).await?;
+
+            if response.data.len() != chunk.len() {
+                return Err(ClientError::OpenAiApi(format!(
+                    "Expected {} embeddings, got {}",
+                    chunk.len(),
+                    response.data.len()
+                )));
+            }
+
+            usage::report(
+                UsageOperation::Embed,
+                &self.config.embedding_model,
+                response
+                    .usage
+                    .take()
+                    .map(TokenUsage::from)
+                    .unwrap_or_default(),
+                chunk.len(),
+            );
+
+            // 응답 순서는 보장되지 않으므로 입력 인덱스로 정렬
+            response.data.sort_by_key(|d| d.index);
+            vectors.extend(response.data.into_iter().map(|d| d.embedding));
+        }
+
+        Ok(vectors)
+    }
+
+    fn dimension(&self) -> usize {
+        self.config.embedding_dimension
+    }
+
+    fn model_id(&self) -> &str {
+        &self.config.embedding_model
+    }
+}
+
+#[derive(Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    stop: &'a [String],
+}
+
+#[derive(Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    choices: Vec<ChatChoice>,
+    #[serde(default)]
+    usage: Option<Usage>,
+}
+
+#[derive(Deserialize)]
+struct ChatChoice {
+    message: ChatResponseMessage,
+    #[serde(default)]
+    finish_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ChatResponseMessage {
+    content: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl TextGenerator for OpenAiClient {
+    async fn generate(
+        &self,
+        prompt: &str,
+        context: Vec<String>,
+        options: &GenerationOptions,
+    ) -> Result<String, ClientError> {
+        let prompt_text = assist_prompt(prompt, &context);
+
+        self.complete(&prompt_text, options).await
+    }
+
+    async fn complete(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
+    ) -> Result<String, ClientError> {
+        let system_text = options.system_text();
+        let mut messages = Vec::new();
+        if let Some(ref system_text) = system_text {
+            messages.push(ChatMessage {
+                role: "system",
+                content: system_text,
+            });
+        }
+        messages.push(ChatMessage {
+            role: "user",
+            content: prompt,
+        });
+
+        let request_body = ChatRequest {
+            model: &self.config.chat_model,
+            messages,
+            temperature: options.temperature,
+            top_p: options.top_p,
+            max_tokens: options.max_output_tokens,
+            stop: &options.stop_sequences,
+        };
+
+        let response: ChatResponse = self.post("/chat/completions", &request_body).await?;
+
+        let choice = response
+            .choices
+            .into_iter()
+            .next()
+            .ok_or_else(|| ClientError::OpenAiApi("No response generated".to_string()))?;
+        let text = choice.message.content.unwrap_or_default();
+
+        usage::report(
+            UsageOperation::Generate,
+            &self.config.chat_model,
+            response.usage.map(TokenUsage::from).unwrap_or_default(),
+            1,
+        );
+
+        match choice.finish_reason.as_deref() {
+            Some("length") => Err(ClientError::OutputTruncated { partial: text }),
+            Some("content_filter") => Err(ClientError::ContentBlocked {
+                reason: "content_filter".to_string(),
+                categories: Vec::new(),
+                partial: Some(text).filter(|text| !text.is_empty()),
+            }),
+            _ if text.is_empty() => {
+                Err(ClientError::OpenAiApi("No response generated".to_string()))
+            }
+            _ => Ok(text),
+        }
+    }
+}
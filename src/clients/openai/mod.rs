@@ -0,0 +1,6 @@
+mod client;
+
+#[cfg(test)]
+mod tests;
+
+pub use client::{OpenAiClient, OpenAiConfig};
@@ -0,0 +1,324 @@
+use super::*;
+use crate::clients::{
+    usage, ClientError, Embedder, EmbeddingPurpose, GenerationOptions, TextGenerator, UsageContext,
+    UsageFeature,
+};
+use axum::{http::HeaderMap, http::StatusCode, routing::post, Json, Router};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+
+type Captured = Arc<Mutex<Vec<(Option<String>, Value)>>>;
+
+/// OpenAI 호환 API를 흉내 내는 로컬 서버. 받은 요청의 Authorization 헤더와 본문을 기록한다
+async fn spawn_stand_in(status: StatusCode, response: Value) -> (String, Captured) {
+    let captured: Captured = Arc::new(Mutex::new(Vec::new()));
+
+    let handler = {
+        let captured = captured.clone();
+        move |headers: HeaderMap, Json(body): Json<Value>| {
+            let captured = captured.clone();
+            let response = response.clone();
+            async move {
+                let auth = headers
+                    .get("authorization")
+                    .and_then(|v| v.to_str().ok())
+                    .map(|v| v.to_string());
+                captured.lock().unwrap().push((auth, body));
+                (status, Json(response))
+            }
+        }
+    };
+
+    let app = Router::new()
+        .route("/v1/embeddings", post(handler.clone()))
+        .route("/v1/chat/completions", post(handler));
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    (format!("http://{}/v1", addr), captured)
+}
+
+fn client_for(base_url: String, api_key: Option<&str>) -> OpenAiClient {
+    OpenAiClient::new(OpenAiConfig {
+        base_url,
+        api_key: api_key.map(|k| k.to_string()),
+        embedding_model: "test-embedding".to_string(),
+        chat_model: "test-chat".to_string(),
+        embedding_dimension: 3,
+    })
+}
+
+#[tokio::test]
+async fn test_embed_parses_response_and_sends_model() {
+    let (base_url, captured) = spawn_stand_in(
+        StatusCode::OK,
+        json!({
+            "object": "list",
+            "data": [{ "object": "embedding", "index": 0, "embedding": [0.1, 0.2, 0.3] }],
+            "model": "test-embedding"
+        }),
+    )
+    .await;
+    let client = client_for(base_url, Some("sk-test"));
+
+    let vector = client
+        .embed("안녕하세요", EmbeddingPurpose::RetrievalDocument)
+        .await
+        .unwrap();
+
+    assert_eq!(vector, vec![0.1, 0.2, 0.3]);
+    assert_eq!(client.dimension(), 3);
+
+    let requests = captured.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].0.as_deref(), Some("Bearer sk-test"));
+    assert_eq!(requests[0].1["model"], "test-embedding");
+    assert_eq!(requests[0].1["input"], "안녕하세요");
+}
+
+#[tokio::test]
+async fn test_embed_batch_sends_all_inputs_and_orders_by_index() {
+    let (base_url, captured) = spawn_stand_in(
+        StatusCode::OK,
+        json!({
+            "data": [
+                { "index": 1, "embedding": [0.0, 1.0, 0.0] },
+                { "index": 0, "embedding": [1.0, 0.0, 0.0] }
+            ]
+        }),
+    )
+    .await;
+    let client = client_for(base_url, None);
+
+    let vectors = client
+        .embed_batch(&["첫 번째", "두 번째"], EmbeddingPurpose::RetrievalDocument)
+        .await
+        .unwrap();
+
+    assert_eq!(vectors, vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]]);
+    let requests = captured.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].1["input"], json!(["첫 번째", "두 번째"]));
+}
+
+#[tokio::test]
+async fn test_embed_batch_count_mismatch_is_error() {
+    let (base_url, _) = spawn_stand_in(
+        StatusCode::OK,
+        json!({ "data": [{ "index": 0, "embedding": [1.0, 0.0, 0.0] }] }),
+    )
+    .await;
+    let client = client_for(base_url, None);
+
+    let result = client
+        .embed_batch(&["a", "b"], EmbeddingPurpose::RetrievalDocument)
+        .await;
+    assert!(matches!(result, Err(ClientError::OpenAiApi(_))));
+}
+
+#[tokio::test]
+async fn test_generate_sends_memo_context_in_chat_message() {
+    let (base_url, captured) = spawn_stand_in(
+        StatusCode::OK,
+        json!({
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": "생성된 글" },
+                "finish_reason": "stop"
+            }]
+        }),
+    )
+    .await;
+    let client = client_for(base_url, None);
+
+    let text = client
+        .generate(
+            "사랑에 대해 쓰고 싶어",
+            vec!["사랑은 수용이다".to_string()],
+            &GenerationOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(text, "생성된 글");
+
+    let requests = captured.lock().unwrap();
+    assert_eq!(requests[0].0, None);
+    assert_eq!(requests[0].1["model"], "test-chat");
+    assert_eq!(requests[0].1["messages"][0]["role"], "user");
+    let content = requests[0].1["messages"][0]["content"].as_str().unwrap();
+    assert!(content.contains("사랑은 수용이다"));
+    assert!(content.contains("사랑에 대해 쓰고 싶어"));
+}
+
+#[tokio::test]
+async fn test_chat_usage_is_reported_to_scope() {
+    let (base_url, _) = spawn_stand_in(
+        StatusCode::OK,
+        json!({
+            "choices": [{ "index": 0, "message": { "role": "assistant", "content": "글" } }],
+            "usage": { "prompt_tokens": 9, "completion_tokens": 3, "total_tokens": 12 }
+        }),
+    )
+    .await;
+    let client = client_for(base_url, None);
+    let context = UsageContext {
+        user_id: 1,
+        project_id: Some(2),
+        feature: UsageFeature::Assist,
+    };
+
+    let (_, records) = usage::track(
+        context,
+        client.complete("hi", &GenerationOptions::default()),
+    )
+    .await;
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].context, context);
+    assert_eq!(records[0].model, "test-chat");
+    assert_eq!(records[0].tokens.prompt_tokens, 9);
+    assert_eq!(records[0].tokens.candidate_tokens, 3);
+    assert_eq!(records[0].tokens.total_tokens, 12);
+}
+
+#[tokio::test]
+async fn test_finish_reasons_map_to_truncated_and_blocked() {
+    let (base_url, _) = spawn_stand_in(
+        StatusCode::OK,
+        json!({
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": "잘린 글" },
+                "finish_reason": "length"
+            }]
+        }),
+    )
+    .await;
+    match client_for(base_url, None)
+        .complete("hi", &GenerationOptions::default())
+        .await
+    {
+        Err(ClientError::OutputTruncated { partial }) => assert_eq!(partial, "잘린 글"),
+        other => panic!("expected OutputTruncated, got {:?}", other),
+    }
+
+    let (base_url, _) = spawn_stand_in(
+        StatusCode::OK,
+        json!({
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": null },
+                "finish_reason": "content_filter"
+            }]
+        }),
+    )
+    .await;
+    match client_for(base_url, None)
+        .complete("hi", &GenerationOptions::default())
+        .await
+    {
+        Err(ClientError::ContentBlocked {
+            reason, partial, ..
+        }) => {
+            assert_eq!(reason, "content_filter");
+            assert_eq!(partial, None);
+        }
+        other => panic!("expected ContentBlocked, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_complete_without_choices_is_error() {
+    let (base_url, _) = spawn_stand_in(StatusCode::OK, json!({ "choices": [] })).await;
+    let client = client_for(base_url, None);
+
+    let result = client
+        .complete("hello", &GenerationOptions::default())
+        .await;
+    assert!(matches!(result, Err(ClientError::OpenAiApi(_))));
+}
+
+#[tokio::test]
+async fn test_error_status_is_api_error() {
+    let (base_url, _) = spawn_stand_in(
+        StatusCode::UNAUTHORIZED,
+        json!({ "error": { "message": "Incorrect API key provided", "type": "invalid_request_error" } }),
+    )
+    .await;
+    let client = client_for(base_url, Some("wrong"));
+
+    let result = client
+        .embed("hello", EmbeddingPurpose::RetrievalDocument)
+        .await;
+    match result {
+        Err(ClientError::OpenAiApi(msg)) => assert!(msg.contains("Incorrect API key")),
+        other => panic!("unexpected result: {:?}", other.map(|v| v.len())),
+    }
+}
+
+#[tokio::test]
+async fn test_rate_limit_and_server_errors_are_retryable() {
+    let app = Router::new()
+        .route(
+            "/v1/embeddings",
+            post(|| async {
+                (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [("retry-after", "7")],
+                    Json(json!({ "error": { "message": "Rate limit reached" } })),
+                )
+            }),
+        )
+        .route(
+            "/v1/chat/completions",
+            post(|| async { (StatusCode::SERVICE_UNAVAILABLE, "overloaded") }),
+        );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    let client = client_for(format!("http://{}/v1", addr), None);
+
+    let err = client
+        .embed("hello", EmbeddingPurpose::RetrievalDocument)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::RateLimited { .. }));
+    assert!(err.is_retryable());
+    assert_eq!(err.retry_after(), Some(std::time::Duration::from_secs(7)));
+
+    let err = client
+        .complete("hello", &GenerationOptions::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ClientError::Unavailable {
+            retry_after: None,
+            ..
+        }
+    ));
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_base_url_trailing_slash_is_ignored() {
+    let (base_url, captured) = spawn_stand_in(
+        StatusCode::OK,
+        json!({ "data": [{ "embedding": [1.0, 0.0, 0.0] }] }),
+    )
+    .await;
+    let client = client_for(format!("{}/", base_url), None);
+
+    assert!(client
+        .embed("hello", EmbeddingPurpose::RetrievalDocument)
+        .await
+        .is_ok());
+    assert_eq!(captured.lock().unwrap().len(), 1);
+}
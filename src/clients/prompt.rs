@@ -0,0 +1,15 @@
+/// 메모 컨텍스트를 포함한 글쓰기 도움 프롬프트. 모든 TextGenerator 구현이 공유한다
+pub fn assist_prompt(prompt: &str, context: &[String]) -> String {
+    let mut prompt_text = String::from("다음은 사용자가 과거에 작성한 메모들입니다:\n\n");
+
+    for (i, memo) in context.iter().enumerate() {
+        prompt_text.push_str(&format!("메모 {}:\n{}\n\n", i + 1, memo));
+    }
+
+    prompt_text.push_str(&format!(
+        "위 메모들을 참고하여, 다음 주제에 대한 글쓰기를 도와주세요:\n{}",
+        prompt
+    ));
+
+    prompt_text
+}
@@ -0,0 +1,225 @@
+//! AI 클라이언트 테스트용 녹화/재생 서버.
+//!
+//! 기본(재생) 모드에서는 `tests/fixtures/{name}.json`에 저장된 요청/응답 쌍을 순서대로 돌려주며,
+//! 클라이언트가 보낸 요청이 녹화된 요청과 다르면 테스트를 실패시킨다.
+//! `AI_FIXTURES_RECORD=1`로 실행하면 요청을 실제 프로바이더로 중계하고 응답을 픽스처로 저장한다.
+//! API 키 헤더는 중계만 하고 픽스처에는 남기지 않는다
+
+use axum::{
+    body::Bytes,
+    http::{HeaderMap, HeaderValue, StatusCode, Uri},
+    response::{IntoResponse, Response},
+    Router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+const RECORD_ENV: &str = "AI_FIXTURES_RECORD";
+
+/// 업스트림으로 중계하는 인증 헤더
+const FORWARDED_HEADERS: [&str; 2] = ["x-goog-api-key", "authorization"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct RecordedRequest {
+    pub path: String,
+    pub body: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RecordedResponse {
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<String>,
+    pub body: Value,
+}
+
+enum Mode {
+    Replay {
+        remaining: Vec<Interaction>,
+        mismatches: Vec<String>,
+    },
+    Record {
+        upstream: String,
+        recorded: Vec<Interaction>,
+    },
+}
+
+pub(crate) struct ReplayServer {
+    name: String,
+    url: String,
+    mode: Arc<Mutex<Mode>>,
+}
+
+impl ReplayServer {
+    /// `name` 픽스처를 재생하는 로컬 서버 시작. 녹화 모드에서는 `upstream`(스킴과 호스트)으로 중계한다
+    pub async fn start(name: &str, upstream: &str) -> Self {
+        let mode = if Self::recording() {
+            Mode::Record {
+                upstream: upstream.trim_end_matches('/').to_string(),
+                recorded: Vec::new(),
+            }
+        } else {
+            let raw = std::fs::read_to_string(fixture_path(name)).unwrap_or_else(|e| {
+                panic!(
+                    "fixture '{}' not found ({}); record it with {}=1",
+                    name, e, RECORD_ENV
+                )
+            });
+            let mut interactions: Vec<Interaction> =
+                serde_json::from_str(&raw).expect("fixture must be valid JSON");
+            interactions.reverse();
+            Mode::Replay {
+                remaining: interactions,
+                mismatches: Vec::new(),
+            }
+        };
+        let mode = Arc::new(Mutex::new(mode));
+
+        let handler = {
+            let mode = mode.clone();
+            move |uri: Uri, headers: HeaderMap, body: Bytes| {
+                let mode = mode.clone();
+                async move { handle(mode, uri, headers, body).await }
+            }
+        };
+
+        let app = Router::new().fallback(handler);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        Self {
+            name: name.to_string(),
+            url: format!("http://{}", addr),
+            mode,
+        }
+    }
+
+    pub fn recording() -> bool {
+        std::env::var(RECORD_ENV).is_ok_and(|v| v == "1")
+    }
+
+    /// 스킴과 호스트까지의 서버 주소. 클라이언트 기본 URL은 여기에 API 버전 경로를 붙여 만든다
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// 녹화 모드면 픽스처를 저장하고, 재생 모드면 모든 요청이 녹화와 일치했는지 확인
+    pub fn finish(self) {
+        match &*self.mode.lock().unwrap() {
+            Mode::Record { recorded, .. } => {
+                let path = fixture_path(&self.name);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                let json = serde_json::to_string_pretty(recorded).unwrap();
+                std::fs::write(&path, json + "\n").unwrap();
+            }
+            Mode::Replay {
+                remaining,
+                mismatches,
+            } => {
+                assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+                assert!(
+                    remaining.is_empty(),
+                    "{} recorded interaction(s) of '{}' were not replayed",
+                    remaining.len(),
+                    self.name
+                );
+            }
+        }
+    }
+}
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(format!("{}.json", name))
+}
+
+async fn handle(mode: Arc<Mutex<Mode>>, uri: Uri, headers: HeaderMap, body: Bytes) -> Response {
+    let request = RecordedRequest {
+        path: uri.path().to_string(),
+        body: serde_json::from_slice(&body).unwrap_or(Value::Null),
+    };
+
+    let upstream = match &mut *mode.lock().unwrap() {
+        Mode::Replay {
+            remaining,
+            mismatches,
+        } => {
+            let Some(interaction) = remaining.pop() else {
+                mismatches.push(format!("unexpected request to {}", request.path));
+                return (StatusCode::NOT_IMPLEMENTED, "no recorded interaction left")
+                    .into_response();
+            };
+            if interaction.request != request {
+                mismatches.push(format!(
+                    "request does not match fixture\n  expected: {}\n  actual:   {}",
+                    serde_json::to_string(&interaction.request).unwrap(),
+                    serde_json::to_string(&request).unwrap()
+                ));
+            }
+            return respond(&interaction.response);
+        }
+        Mode::Record { upstream, .. } => upstream.clone(),
+    };
+
+    let response = forward(&upstream, &uri, &headers, body).await;
+    let reply = respond(&response);
+    if let Mode::Record { recorded, .. } = &mut *mode.lock().unwrap() {
+        recorded.push(Interaction { request, response });
+    }
+    reply
+}
+
+async fn forward(upstream: &str, uri: &Uri, headers: &HeaderMap, body: Bytes) -> RecordedResponse {
+    let path = uri.path_and_query().map(|p| p.as_str()).unwrap_or("/");
+    let mut request = reqwest::Client::new()
+        .post(format!("{}{}", upstream, path))
+        .header("content-type", "application/json")
+        .body(body);
+    for name in FORWARDED_HEADERS {
+        if let Some(value) = headers.get(name) {
+            request = request.header(name, value.as_bytes());
+        }
+    }
+
+    let response = request.send().await.expect("upstream request failed");
+    let status = response.status().as_u16();
+    let retry_after = response
+        .headers()
+        .get("retry-after")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string());
+    let text = response.text().await.unwrap_or_default();
+
+    RecordedResponse {
+        status,
+        retry_after,
+        body: serde_json::from_str(&text).unwrap_or(Value::String(text)),
+    }
+}
+
+fn respond(recorded: &RecordedResponse) -> Response {
+    let status = StatusCode::from_u16(recorded.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let mut response = (status, axum::Json(recorded.body.clone())).into_response();
+    if let Some(retry_after) = recorded
+        .retry_after
+        .as_deref()
+        .and_then(|v| HeaderValue::from_str(v).ok())
+    {
+        response.headers_mut().insert("retry-after", retry_after);
+    }
+    response
+}
@@ -0,0 +1,408 @@
+use rand::Rng;
+use std::{future::Future, sync::Arc, time::Duration};
+use tokio::time::{sleep, timeout, Instant};
+use tracing::warn;
+
+use crate::clients::{
+    circuit_breaker::CircuitState, ClientError, Embedder, EmbeddingPurpose, GenerationOptions,
+    TextGenerator,
+};
+
+/// 일시적 실패에 대한 재시도 정책.
+/// 지수 백오프에 지터를 더해 대기하며, 전체 소요 시간은 `max_elapsed`를 넘지 않는다
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// 첫 시도를 포함한 최대 시도 횟수
+    pub max_attempts: u32,
+    /// 첫 재시도 전 대기 시간. 재시도마다 두 배씩 늘어난다
+    pub base_delay: Duration,
+    /// 한 번의 백오프 대기 시간 상한
+    pub max_delay: Duration,
+    /// 모든 시도와 대기를 합친 시간 상한
+    pub max_elapsed: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+            max_elapsed: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// `retry`번째 재시도 전 대기 시간. [지수 백오프의 절반, 지수 백오프] 구간에서 무작위로 고른다
+    fn backoff(&self, retry: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .min(self.max_delay);
+        let half = exponential / 2;
+        let jitter = rand::thread_rng().gen_range(0..=half.as_millis() as u64);
+        half + Duration::from_millis(jitter)
+    }
+}
+
+/// [`Embedder`]/[`TextGenerator`] 호출을 [`RetryPolicy`]에 따라 재시도하는 데코레이터.
+/// 429/5xx/네트워크 오류처럼 재시도 가능한 실패만 다시 시도하고,
+/// 프로바이더가 Retry-After를 보내면 백오프 대신 그 시간만큼 기다린다
+pub struct Retrying<T: ?Sized> {
+    inner: Arc<T>,
+    policy: RetryPolicy,
+}
+
+impl<T: ?Sized> Retrying<T> {
+    pub fn new(inner: Arc<T>, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    async fn run<R, F, Fut>(&self, operation: &str, mut call: F) -> Result<R, ClientError>
+    where
+        F: FnMut() -> Fut + Send,
+        Fut: Future<Output = Result<R, ClientError>> + Send,
+    {
+        let started = Instant::now();
+        let mut attempt = 1;
+
+        loop {
+            let remaining = self.policy.max_elapsed.saturating_sub(started.elapsed());
+            let error = match timeout(remaining, call()).await {
+                Ok(Ok(value)) => return Ok(value),
+                Ok(Err(e)) => e,
+                Err(_) => {
+                    return Err(ClientError::Timeout(format!(
+                        "{} did not complete within {:?}",
+                        operation, self.policy.max_elapsed
+                    )))
+                }
+            };
+
+            if !error.is_retryable() || attempt >= self.policy.max_attempts {
+                return Err(error);
+            }
+
+            let delay = error
+                .retry_after()
+                .unwrap_or_else(|| self.policy.backoff(attempt));
+
+            // 대기 후 시도할 시간이 남지 않으면 바로 실패를 돌려준다
+            if started.elapsed() + delay >= self.policy.max_elapsed {
+                return Err(error);
+            }
+
+            warn!(
+                "{} failed (attempt {}/{}), retrying in {:?}: {}",
+                operation, attempt, self.policy.max_attempts, delay, error
+            );
+            sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: Embedder + ?Sized> Embedder for Retrying<T> {
+    async fn embed(&self, text: &str, purpose: EmbeddingPurpose) -> Result<Vec<f32>, ClientError> {
+        self.run("Embedding request", || self.inner.embed(text, purpose))
+            .await
+    }
+
+    async fn embed_batch(
+        &self,
+        texts: &[&str],
+        purpose: EmbeddingPurpose,
+    ) -> Result<Vec<Vec<f32>>, ClientError> {
+        self.run("Batch embedding request", || {
+            self.inner.embed_batch(texts, purpose)
+        })
+        .await
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension()
+    }
+
+    fn model_id(&self) -> &str {
+        self.inner.model_id()
+    }
+
+    fn circuit_state(&self) -> Option<CircuitState> {
+        self.inner.circuit_state()
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: TextGenerator + ?Sized> TextGenerator for Retrying<T> {
+    async fn generate(
+        &self,
+        prompt: &str,
+        context: Vec<String>,
+        options: &GenerationOptions,
+    ) -> Result<String, ClientError> {
+        self.run("Generation request", || {
+            self.inner.generate(prompt, context.clone(), options)
+        })
+        .await
+    }
+
+    async fn complete(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
+    ) -> Result<String, ClientError> {
+        self.run("Generation request", || {
+            self.inner.complete(prompt, options)
+        })
+        .await
+    }
+
+    fn circuit_state(&self) -> Option<CircuitState> {
+        self.inner.circuit_state()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// 미리 정해 둔 에러를 차례로 돌려준 뒤 성공하는 클라이언트
+    struct Flaky {
+        failures: Mutex<Vec<ClientError>>,
+        calls: Mutex<Vec<Instant>>,
+    }
+
+    impl Flaky {
+        fn new(failures: Vec<ClientError>) -> Arc<Self> {
+            Arc::new(Self {
+                failures: Mutex::new(failures.into_iter().rev().collect()),
+                calls: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn calls(&self) -> Vec<Instant> {
+            self.calls.lock().unwrap().clone()
+        }
+
+        fn next(&self) -> Result<(), ClientError> {
+            self.calls.lock().unwrap().push(Instant::now());
+            match self.failures.lock().unwrap().pop() {
+                Some(error) => Err(error),
+                None => Ok(()),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Embedder for Flaky {
+        async fn embed(
+            &self,
+            _text: &str,
+            _purpose: EmbeddingPurpose,
+        ) -> Result<Vec<f32>, ClientError> {
+            self.next().map(|_| vec![1.0, 0.0])
+        }
+
+        fn dimension(&self) -> usize {
+            2
+        }
+
+        fn model_id(&self) -> &str {
+            "test"
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl TextGenerator for Flaky {
+        async fn generate(
+            &self,
+            prompt: &str,
+            _context: Vec<String>,
+            options: &GenerationOptions,
+        ) -> Result<String, ClientError> {
+            self.complete(prompt, options).await
+        }
+
+        async fn complete(
+            &self,
+            prompt: &str,
+            _options: &GenerationOptions,
+        ) -> Result<String, ClientError> {
+            self.next().map(|_| prompt.to_string())
+        }
+    }
+
+    fn rate_limited(retry_after: Option<Duration>) -> ClientError {
+        ClientError::RateLimited {
+            message: "429".to_string(),
+            retry_after,
+        }
+    }
+
+    fn unavailable() -> ClientError {
+        ClientError::Unavailable {
+            message: "503".to_string(),
+            retry_after: None,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retries_transient_failures_until_success() {
+        let inner = Flaky::new(vec![unavailable(), ClientError::Network("reset".into())]);
+        let client = Retrying::new(inner.clone(), RetryPolicy::default());
+
+        let vector = client
+            .embed("hello", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+
+        assert_eq!(vector, vec![1.0, 0.0]);
+        assert_eq!(inner.calls().len(), 3);
+        assert_eq!(client.dimension(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_embed_batch_uses_sequential_default_and_retries() {
+        let inner = Flaky::new(vec![unavailable()]);
+        let client = Retrying::new(inner.clone(), RetryPolicy::default());
+
+        let vectors = client
+            .embed_batch(&["a", "b", "c"], EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+
+        assert_eq!(vectors.len(), 3);
+        // 첫 호출이 실패하면 배치 전체를 다시 시도한다
+        assert_eq!(inner.calls().len(), 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_does_not_retry_permanent_failures() {
+        let inner = Flaky::new(vec![ClientError::GeminiApi("400 bad request".into())]);
+        let client = Retrying::new(inner.clone(), RetryPolicy::default());
+
+        let result = client
+            .complete("hello", &GenerationOptions::default())
+            .await;
+
+        assert!(matches!(result, Err(ClientError::GeminiApi(_))));
+        assert_eq!(inner.calls().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_gives_up_after_max_attempts() {
+        let inner = Flaky::new((0..10).map(|_| unavailable()).collect());
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            ..RetryPolicy::default()
+        };
+        let client = Retrying::new(inner.clone(), policy);
+
+        let result = client
+            .embed("hello", EmbeddingPurpose::RetrievalDocument)
+            .await;
+
+        assert!(matches!(result, Err(ClientError::Unavailable { .. })));
+        assert_eq!(inner.calls().len(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_backoff_grows_exponentially_with_jitter() {
+        let inner = Flaky::new(vec![unavailable(), unavailable(), unavailable()]);
+        let policy = RetryPolicy {
+            max_attempts: 4,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            max_elapsed: Duration::from_secs(60),
+        };
+        let client = Retrying::new(inner.clone(), policy);
+
+        client
+            .complete("hello", &GenerationOptions::default())
+            .await
+            .unwrap();
+
+        let calls = inner.calls();
+        let waits: Vec<Duration> = calls.windows(2).map(|w| w[1] - w[0]).collect();
+        for (wait, full) in waits.iter().zip([1, 2, 4]) {
+            let full = Duration::from_secs(full);
+            assert!(
+                *wait >= full / 2 && *wait <= full,
+                "{:?} vs {:?}",
+                wait,
+                full
+            );
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_honors_retry_after() {
+        let inner = Flaky::new(vec![rate_limited(Some(Duration::from_secs(7)))]);
+        let client = Retrying::new(inner.clone(), RetryPolicy::default());
+
+        client
+            .embed("hello", EmbeddingPurpose::RetrievalDocument)
+            .await
+            .unwrap();
+
+        let calls = inner.calls();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[1] - calls[0], Duration::from_secs(7));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_after_beyond_budget_fails_fast() {
+        let inner = Flaky::new(vec![rate_limited(Some(Duration::from_secs(120)))]);
+        let client = Retrying::new(inner.clone(), RetryPolicy::default());
+        let started = Instant::now();
+
+        let result = client
+            .embed("hello", EmbeddingPurpose::RetrievalDocument)
+            .await;
+
+        assert!(matches!(result, Err(ClientError::RateLimited { .. })));
+        assert_eq!(inner.calls().len(), 1);
+        assert_eq!(started.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_total_time_is_bounded() {
+        struct Hanging;
+
+        #[async_trait::async_trait]
+        impl Embedder for Hanging {
+            async fn embed(
+                &self,
+                _text: &str,
+                _purpose: EmbeddingPurpose,
+            ) -> Result<Vec<f32>, ClientError> {
+                std::future::pending().await
+            }
+
+            fn dimension(&self) -> usize {
+                2
+            }
+
+            fn model_id(&self) -> &str {
+                "test"
+            }
+        }
+
+        let policy = RetryPolicy {
+            max_elapsed: Duration::from_secs(5),
+            ..RetryPolicy::default()
+        };
+        let client = Retrying::new(Arc::new(Hanging), policy);
+        let started = Instant::now();
+
+        let result = client
+            .embed("hello", EmbeddingPurpose::RetrievalDocument)
+            .await;
+
+        assert!(matches!(result, Err(ClientError::Timeout(_))));
+        assert_eq!(started.elapsed(), Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,159 @@
+use std::{future::Future, sync::Mutex};
+
+/// AI 호출을 어느 사용자/프로젝트/기능의 사용량으로 기록할지 나타내는 컨텍스트
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UsageContext {
+    pub user_id: i32,
+    pub project_id: Option<i32>,
+    pub feature: UsageFeature,
+}
+
+/// AI 호출을 일으킨 기능. 기능별 할당량 계산에 사용한다
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageFeature {
+    Assist,
+    MemoIndexing,
+    Translation,
+    Digest,
+}
+
+impl UsageFeature {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Assist => "assist",
+            Self::MemoIndexing => "memo_indexing",
+            Self::Translation => "translation",
+            Self::Digest => "digest",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageOperation {
+    Embed,
+    Generate,
+}
+
+impl UsageOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Embed => "embed",
+            Self::Generate => "generate",
+        }
+    }
+}
+
+/// 프로바이더 응답에 포함된 토큰 사용량. 임베딩처럼 보고하지 않는 경우 0
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenUsage {
+    pub prompt_tokens: i32,
+    pub candidate_tokens: i32,
+    pub total_tokens: i32,
+}
+
+/// 프로바이더 호출 한 번의 사용량
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageRecord {
+    pub context: UsageContext,
+    pub operation: UsageOperation,
+    pub model: String,
+    pub tokens: TokenUsage,
+    /// 한 번의 호출로 처리한 입력 수 (배치 임베딩의 텍스트 수)
+    pub items: i32,
+}
+
+struct UsageScope {
+    context: UsageContext,
+    records: Mutex<Vec<UsageRecord>>,
+}
+
+tokio::task_local! {
+    static SCOPE: UsageScope;
+}
+
+/// `future` 안에서 일어난 프로바이더 호출의 사용량을 `context`로 모아 함께 반환.
+/// 클라이언트는 [`report`]로 사용량을 알리며, 스코프 밖의 호출은 기록되지 않는다
+pub async fn track<F: Future>(context: UsageContext, future: F) -> (F::Output, Vec<UsageRecord>) {
+    let scope = UsageScope {
+        context,
+        records: Mutex::new(Vec::new()),
+    };
+
+    SCOPE
+        .scope(scope, async move {
+            let output = future.await;
+            let records = SCOPE.with(|scope| std::mem::take(&mut *scope.records.lock().unwrap()));
+            (output, records)
+        })
+        .await
+}
+
+/// 성공한 프로바이더 호출의 사용량을 현재 스코프에 기록
+pub fn report(operation: UsageOperation, model: &str, tokens: TokenUsage, items: usize) {
+    let _ = SCOPE.try_with(|scope| {
+        scope.records.lock().unwrap().push(UsageRecord {
+            context: scope.context,
+            operation,
+            model: model.to_string(),
+            tokens,
+            items: items as i32,
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTEXT: UsageContext = UsageContext {
+        user_id: 7,
+        project_id: Some(3),
+        feature: UsageFeature::Assist,
+    };
+
+    #[tokio::test]
+    async fn test_track_collects_reports_with_context() {
+        let (output, records) = track(CONTEXT, async {
+            report(
+                UsageOperation::Embed,
+                "embed-model",
+                TokenUsage::default(),
+                2,
+            );
+            tokio::task::yield_now().await;
+            report(
+                UsageOperation::Generate,
+                "chat-model",
+                TokenUsage {
+                    prompt_tokens: 10,
+                    candidate_tokens: 5,
+                    total_tokens: 15,
+                },
+                1,
+            );
+            "done"
+        })
+        .await;
+
+        assert_eq!(output, "done");
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].context, CONTEXT);
+        assert_eq!(records[0].operation, UsageOperation::Embed);
+        assert_eq!(records[0].items, 2);
+        assert_eq!(records[1].model, "chat-model");
+        assert_eq!(records[1].tokens.total_tokens, 15);
+    }
+
+    #[tokio::test]
+    async fn test_report_outside_scope_is_ignored() {
+        report(
+            UsageOperation::Embed,
+            "embed-model",
+            TokenUsage::default(),
+            1,
+        );
+
+        let (_, records) = track(CONTEXT, async {}).await;
+        assert!(records.is_empty());
+    }
+}
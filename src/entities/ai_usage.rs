@@ -0,0 +1,54 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// AI 프로바이더 호출 한 번의 사용량 기록
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "ai_usage")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    #[sea_orm(indexed)]
+    pub user_id: i32,
+
+    pub project_id: Option<i32>,
+
+    /// 사용량을 일으킨 기능 ("assist", "memo_indexing", "translation", "digest")
+    pub feature: String,
+
+    /// "embed" 또는 "generate"
+    pub operation: String,
+
+    pub model: String,
+
+    pub prompt_tokens: i32,
+
+    pub candidate_tokens: i32,
+
+    pub total_tokens: i32,
+
+    /// 한 번의 호출로 처리한 입력 수
+    pub item_count: i32,
+
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    User,
+}
+
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -17,6 +17,12 @@ pub struct Model {
     #[sea_orm(indexed)]
     pub is_pinned: bool,
 
+    pub source_essay_id: Option<i32>,
+
+    pub language: Option<String>,
+
+    pub is_translation_stale: bool,
+
     pub created_at: DateTime,
 
     pub updated_at: DateTime,
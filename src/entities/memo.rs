@@ -1,5 +1,6 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "memos")]
@@ -14,11 +15,34 @@ pub struct Model {
 
     pub is_pinned: bool,
 
+    /// 벡터 인덱싱 진행 상태. 저장 후 인덱싱 작업이 처리될 때까지 `Pending`
+    pub index_status: IndexStatus,
+
+    /// 마지막으로 벡터 DB에 인덱싱한 내용의 해시
+    pub content_hash: Option<String>,
+
+    pub embedded_at: Option<DateTime>,
+
     pub created_at: DateTime,
 
     pub updated_at: DateTime,
 }
 
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "lowercase")]
+pub enum IndexStatus {
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    #[sea_orm(string_value = "indexed")]
+    Indexed,
+    /// 재시도를 모두 소진해 인덱싱 작업이 dead 상태가 됨
+    #[sea_orm(string_value = "failed")]
+    Failed,
+}
+
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(
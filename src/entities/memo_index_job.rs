@@ -0,0 +1,61 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 메모 벡터 인덱싱 작업 (outbox). 메모당 하나이며, 처리되면 삭제된다
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "memo_index_jobs")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    #[sea_orm(unique)]
+    pub memo_id: i32,
+
+    pub status: JobStatus,
+
+    /// 지금까지 처리를 시작한 횟수
+    pub attempts: i32,
+
+    pub last_error: Option<String>,
+
+    /// 이 시각 이후에 처리한다 (재시도 백오프)
+    pub run_after: DateTime,
+
+    /// 처리 중인 워커의 임대 만료 시각. 워커가 죽으면 만료 후 다른 워커가 다시 가져간다
+    pub locked_until: Option<DateTime>,
+
+    /// 마지막으로 작업이 등록된 시각. 처리 중 메모가 다시 수정되었는지 판별하는 버전으로 쓴다
+    pub enqueued_at: DateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+pub enum JobStatus {
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    #[sea_orm(string_value = "processing")]
+    Processing,
+    /// 재시도를 모두 소진하거나 재시도할 수 없는 오류로 중단된 작업
+    #[sea_orm(string_value = "dead")]
+    Dead,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::memo::Entity",
+        from = "Column::MemoId",
+        to = "super::memo::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Memo,
+}
+
+impl Related<super::memo::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Memo.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
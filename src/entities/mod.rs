@@ -1,13 +1,23 @@
+pub mod ai_usage;
 pub mod essay;
 pub mod memo;
+pub mod memo_index_job;
 pub mod oauth_account;
 pub mod project;
+pub mod project_digest;
+pub mod project_vector_deletion;
 pub mod refresh_token;
 pub mod user;
+pub mod vector_reindex_run;
 
+pub use ai_usage::Entity as AiUsage;
 pub use essay::Entity as Essay;
 pub use memo::Entity as Memo;
+pub use memo_index_job::Entity as MemoIndexJob;
 pub use oauth_account::Entity as OAuthAccount;
 pub use project::Entity as Project;
+pub use project_digest::Entity as ProjectDigest;
+pub use project_vector_deletion::Entity as ProjectVectorDeletion;
 pub use refresh_token::Entity as RefreshToken;
 pub use user::Entity as User;
+pub use vector_reindex_run::Entity as VectorReindexRun;
@@ -33,6 +33,9 @@ pub enum Relation {
 
     #[sea_orm(has_many = "super::essay::Entity")]
     Essays,
+
+    #[sea_orm(has_many = "super::project_digest::Entity")]
+    Digests,
 }
 
 impl Related<super::user::Entity> for Entity {
@@ -53,4 +56,10 @@ impl Related<super::essay::Entity> for Entity {
     }
 }
 
+impl Related<super::project_digest::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Digests.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
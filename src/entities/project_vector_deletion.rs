@@ -0,0 +1,29 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 삭제된 프로젝트의 벡터 삭제 작업 (outbox). 프로젝트당 하나이며, 처리되면 삭제된다
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "project_vector_deletions")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    #[sea_orm(unique)]
+    pub project_id: i32,
+
+    /// 지금까지 처리를 시작한 횟수
+    pub attempts: i32,
+
+    pub last_error: Option<String>,
+
+    /// 이 시각 이후에 처리한다. 처리를 시작하면 임대 만료 시각으로 미뤄 두어
+    /// 워커가 중단되어도 만료 후 다른 워커가 다시 가져간다
+    pub run_after: DateTime,
+
+    pub enqueued_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,48 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 벡터 컬렉션 재인덱싱 진행 기록. 중단되면 `last_memo_id` 다음 메모부터 이어서 처리한다
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "vector_reindex_runs")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    /// 재인덱싱 대상으로 새로 만든 버전별 컬렉션
+    #[sea_orm(unique)]
+    pub collection_name: String,
+
+    /// 이 컬렉션을 채우는 임베딩 모델과 차원
+    pub model_id: String,
+    pub dimension: i32,
+
+    pub status: ReindexStatus,
+
+    /// 마지막으로 임베딩을 마친 메모 ID
+    pub last_memo_id: i32,
+    pub processed: i32,
+    /// 시작(또는 재개) 시점의 전체 메모 수
+    pub total: i32,
+
+    pub started_at: DateTime,
+    pub updated_at: DateTime,
+    pub completed_at: Option<DateTime>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+pub enum ReindexStatus {
+    #[sea_orm(string_value = "running")]
+    Running,
+    /// 임베딩을 마치고 검색 별칭을 새 컬렉션으로 옮김
+    #[sea_orm(string_value = "completed")]
+    Completed,
+    /// 임베딩 모델이 바뀌어 이어서 처리할 수 없게 된 실행
+    #[sea_orm(string_value = "abandoned")]
+    Abandoned,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -10,3 +10,35 @@ pub struct ErrorResponse {
     #[schema(example = "Resource not found")]
     pub error: String,
 }
+
+/// 할당량 초과(429) 응답 본문
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QuotaExceededResponse {
+    #[schema(example = "AI usage quota exceeded, please try again after the reset time")]
+    pub error: String,
+    /// 초과한 할당량 (daily_assist_calls, daily_tokens, hourly_memo_embeds)
+    #[schema(example = "daily_assist_calls")]
+    pub quota: String,
+    #[schema(example = 100)]
+    pub limit: u64,
+    /// 할당량이 초기화되는 시각 (RFC 3339, UTC)
+    #[schema(example = "2024-01-16T00:00:00+00:00")]
+    pub reset_at: String,
+}
+
+/// AI 안전 필터 차단(422) 또는 출력 잘림(502) 응답 본문
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AiContentErrorResponse {
+    #[schema(
+        example = "The request was blocked by the AI provider's safety filters. Please rephrase the prompt or remove sensitive content and try again."
+    )]
+    pub error: String,
+    /// 프로바이더가 보고한 차단/종료 사유 (SAFETY, RECITATION, MAX_TOKENS 등)
+    #[schema(example = "SAFETY")]
+    pub reason: Option<String>,
+    /// 차단을 일으킨 안전 카테고리
+    #[schema(example = json!(["HARM_CATEGORY_HARASSMENT"]))]
+    pub categories: Vec<String>,
+    /// 중단되기 전까지 생성된 텍스트
+    pub partial_text: Option<String>,
+}
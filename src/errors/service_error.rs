@@ -1,11 +1,13 @@
 use axum::{
-    http::StatusCode,
+    http::{header, HeaderName, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
+use chrono::{NaiveDateTime, Utc};
 use sea_orm::DbErr;
 use thiserror::Error;
 
+use super::{AiContentErrorResponse, QuotaExceededResponse};
 use crate::clients::ClientError;
 
 #[derive(Debug, Error)]
@@ -28,12 +30,43 @@ pub enum ServiceError {
     #[error("Unauthorized: you don't have permission to access this memo")]
     Unauthorized,
 
+    #[error("Invalid pagination cursor")]
+    InvalidCursor,
+
     #[error("Gemini API error: {0}")]
     GeminiApi(String),
 
+    /// 재시도 후에도 해결되지 않은 일시적 AI 서비스 장애 (rate limit, 5xx, 타임아웃, 열린 회로)
+    #[error("AI service unavailable: {0}")]
+    AiUnavailable(String),
+
+    /// 프롬프트나 생성 결과가 AI 안전 필터에 차단됨
+    #[error("Content blocked by AI safety filters: {reason}")]
+    ContentBlocked {
+        reason: String,
+        categories: Vec<String>,
+        partial_text: Option<String>,
+    },
+
+    /// 최대 출력 길이에 도달해 AI 응답이 중간에 끊김
+    #[error("AI output was truncated")]
+    OutputTruncated { partial_text: String },
+
+    /// 사용자별 AI 할당량 초과. `reset_at`(UTC) 이후 다시 사용할 수 있다
+    #[error("Quota exceeded: {quota} (limit {limit}, resets at {reset_at})")]
+    QuotaExceeded {
+        quota: &'static str,
+        limit: u64,
+        reset_at: NaiveDateTime,
+    },
+
     #[error("Qdrant error: {0}")]
     Qdrant(String),
 
+    /// 활성 벡터 컬렉션이 이 서버의 임베더와 다른 형식. 재인덱싱 후 서버가 새 설정으로 재시작될 때까지 발생한다
+    #[error("Vector format mismatch: {0}")]
+    VectorFormatMismatch(String),
+
     #[error("Failed to generate JWT token")]
     TokenGenerationFailed,
 
@@ -62,14 +95,52 @@ impl IntoResponse for ServiceError {
             Self::ProjectNotFound => (StatusCode::NOT_FOUND, self.to_string()),
             Self::ProjectNameAlreadyExists => (StatusCode::CONFLICT, self.to_string()),
             Self::Unauthorized => (StatusCode::FORBIDDEN, self.to_string()),
+            Self::InvalidCursor => (StatusCode::BAD_REQUEST, self.to_string()),
             Self::GeminiApi(_) => (
                 StatusCode::BAD_GATEWAY,
                 "External AI service error".to_string(),
             ),
+            Self::AiUnavailable(_) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "AI service is temporarily unavailable, please try again later".to_string(),
+            ),
+            Self::ContentBlocked {
+                reason,
+                categories,
+                partial_text,
+            } => {
+                let body = AiContentErrorResponse {
+                    error: blocked_message(&reason).to_string(),
+                    reason: Some(reason),
+                    categories,
+                    partial_text,
+                };
+                return (StatusCode::UNPROCESSABLE_ENTITY, Json(body)).into_response();
+            }
+            Self::OutputTruncated { partial_text } => {
+                let body = AiContentErrorResponse {
+                    error: "The AI response was cut off at the maximum length. \
+                            Try a shorter request or fewer reference memos."
+                        .to_string(),
+                    reason: Some("MAX_TOKENS".to_string()),
+                    categories: Vec::new(),
+                    partial_text: Some(partial_text),
+                };
+                return (StatusCode::BAD_GATEWAY, Json(body)).into_response();
+            }
+            Self::QuotaExceeded {
+                quota,
+                limit,
+                reset_at,
+            } => return quota_exceeded_response(quota, limit, reset_at),
             Self::Qdrant(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Vector database error".to_string(),
             ),
+            Self::VectorFormatMismatch(_) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Memo search is being upgraded, please try again later".to_string(),
+            ),
             Self::TokenGenerationFailed => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Failed to generate authentication token".to_string(),
@@ -91,10 +162,79 @@ impl IntoResponse for ServiceError {
     }
 }
 
+/// 차단 사유별로 사용자가 취할 수 있는 조치를 안내하는 메시지
+fn blocked_message(reason: &str) -> &'static str {
+    match reason {
+        "RECITATION" => {
+            "The AI stopped because the response closely repeated existing published text. \
+             Try asking for a summary or rewording in your own words."
+        }
+        "SPII" => {
+            "The request appears to contain sensitive personal information. \
+             Remove personal details such as contact or ID numbers and try again."
+        }
+        _ => {
+            "The request was blocked by the AI provider's safety filters. \
+             Please rephrase the prompt or remove sensitive content and try again."
+        }
+    }
+}
+
+/// 429 응답. 클라이언트가 재시도 시점을 알 수 있도록 초기화 시각을 본문과 헤더에 모두 담는다
+fn quota_exceeded_response(quota: &'static str, limit: u64, reset_at: NaiveDateTime) -> Response {
+    let reset_at = reset_at.and_utc();
+    let retry_after = (reset_at - Utc::now()).num_seconds().max(1);
+
+    let body = QuotaExceededResponse {
+        error: "AI usage quota exceeded, please try again after the reset time".to_string(),
+        quota: quota.to_string(),
+        limit,
+        reset_at: reset_at.to_rfc3339(),
+    };
+
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [
+            (header::RETRY_AFTER, retry_after.to_string()),
+            (
+                HeaderName::from_static("x-ratelimit-limit"),
+                limit.to_string(),
+            ),
+            (
+                HeaderName::from_static("x-ratelimit-remaining"),
+                "0".to_string(),
+            ),
+            (
+                HeaderName::from_static("x-ratelimit-reset"),
+                reset_at.timestamp().to_string(),
+            ),
+        ],
+        Json(body),
+    )
+        .into_response()
+}
+
 impl From<ClientError> for ServiceError {
     fn from(err: ClientError) -> Self {
         match err {
             ClientError::GeminiApi(msg) => ServiceError::GeminiApi(msg),
+            ClientError::OpenAiApi(msg) => ServiceError::GeminiApi(msg),
+            ClientError::RateLimited { .. }
+            | ClientError::Unavailable { .. }
+            | ClientError::CircuitOpen(_)
+            | ClientError::Timeout(_) => ServiceError::AiUnavailable(err.to_string()),
+            ClientError::ContentBlocked {
+                reason,
+                categories,
+                partial,
+            } => ServiceError::ContentBlocked {
+                reason,
+                categories,
+                partial_text: partial,
+            },
+            ClientError::OutputTruncated { partial } => ServiceError::OutputTruncated {
+                partial_text: partial,
+            },
             ClientError::Network(msg) => ServiceError::GeminiApi(msg),
             ClientError::ParseError(msg) => ServiceError::GeminiApi(msg),
             ClientError::Qdrant(msg) => ServiceError::Qdrant(msg),
@@ -2,7 +2,7 @@ use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 use validator::Validate;
 
 use super::{auth::AuthenticatedUser, AppState};
-use crate::errors::ErrorResponse;
+use crate::errors::{AiContentErrorResponse, ErrorResponse, QuotaExceededResponse};
 use crate::models::assist_dto::{AssistRequest, AssistResponse};
 
 #[utoipa::path(
@@ -14,7 +14,11 @@ use crate::models::assist_dto::{AssistRequest, AssistResponse};
         (status = 200, description = "AI 어시스턴트 응답 성공", body = AssistResponse),
         (status = 400, description = "잘못된 요청", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 500, description = "서버 에러", body = ErrorResponse)
+        (status = 422, description = "AI 안전 필터에 의해 차단됨", body = AiContentErrorResponse),
+        (status = 429, description = "AI 사용 할당량 초과 (Retry-After, X-RateLimit-* 헤더 포함)", body = QuotaExceededResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse),
+        (status = 502, description = "AI 서비스 에러 또는 응답 길이 초과 (partial_text 포함)", body = AiContentErrorResponse),
+        (status = 503, description = "AI 서비스 일시 장애 또는 검색 인덱스 재구축 중", body = ErrorResponse)
     ),
     security(("bearer_auth" = []))
 )]
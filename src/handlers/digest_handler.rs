@@ -0,0 +1,37 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use super::{auth::AuthenticatedUser, AppState};
+use crate::errors::ErrorResponse;
+use crate::models::digest_dto::DigestResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/projects/{id}/digests",
+    tag = "Projects",
+    params(
+        ("id" = i32, Path, description = "프로젝트 ID")
+    ),
+    responses(
+        (status = 200, description = "프로젝트 다이제스트 목록 조회 성공 (최신순)", body = Vec<DigestResponse>),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "프로젝트를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_digests(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state.digest_service.list_digests(user.id, id).await {
+        Ok(digests) => (StatusCode::OK, Json(digests)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -8,12 +8,17 @@ use serde::Deserialize;
 use validator::Validate;
 
 use super::{auth::AuthenticatedUser, AppState};
-use crate::errors::ErrorResponse;
-use crate::models::essay_dto::{CreateEssayRequest, EssayResponse, UpdateEssayRequest};
+use crate::errors::{AiContentErrorResponse, ErrorResponse, QuotaExceededResponse};
+use crate::models::essay_dto::{
+    CreateEssayRequest, EssayResponse, TranslateEssayParams, UpdateEssayRequest,
+};
+use crate::models::{Page, PageParams};
 
 #[derive(Debug, Deserialize)]
 pub struct ListEssaysParams {
     pub project_id: Option<i32>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
 }
 
 #[utoipa::path(
@@ -55,10 +60,13 @@ pub async fn create_essay(
     path = "/api/essays",
     tag = "Essays",
     params(
-        ("project_id" = Option<i32>, Query, description = "프로젝트 ID (없으면 사용자의 모든 프로젝트의 에세이 조회)")
+        ("project_id" = Option<i32>, Query, description = "프로젝트 ID (없으면 사용자의 모든 프로젝트의 에세이 조회)"),
+        ("limit" = Option<u64>, Query, description = "한 번에 조회할 에세이 수 (1-100, 기본 20)"),
+        ("cursor" = Option<String>, Query, description = "이전 응답의 next_cursor (없으면 첫 페이지)")
     ),
     responses(
-        (status = 200, description = "에세이 목록 조회 성공", body = Vec<EssayResponse>),
+        (status = 200, description = "에세이 목록 조회 성공 (고정된 에세이, 최근 수정 순)", body = Page<EssayResponse>),
+        (status = 400, description = "잘못된 커서", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
         (status = 403, description = "권한 없음", body = ErrorResponse),
         (status = 404, description = "프로젝트를 찾을 수 없음", body = ErrorResponse),
@@ -71,33 +79,23 @@ pub async fn list_essays(
     user: AuthenticatedUser,
     Query(params): Query<ListEssaysParams>,
 ) -> impl IntoResponse {
-    if let Some(project_id) = params.project_id {
-        let essays = state
-            .essay_service
-            .list_essays_by_project(user.id, project_id)
-            .await;
-        match essays {
-            Ok(essays) => (StatusCode::OK, Json(essays)).into_response(),
-            Err(e) => e.into_response(),
-        }
-    } else {
-        let projects = state.project_service.list_projects(user.id).await;
-        match projects {
-            Ok(project_list) => {
-                let mut all_essays = Vec::new();
-                for project in project_list {
-                    if let Ok(essays) = state
-                        .essay_service
-                        .list_essays_by_project(user.id, project.id)
-                        .await
-                    {
-                        all_essays.extend(essays);
-                    }
-                }
-                (StatusCode::OK, Json(all_essays)).into_response()
-            }
-            Err(e) => e.into_response(),
+    let page = PageParams {
+        limit: params.limit,
+        cursor: params.cursor,
+    };
+    let essays = match params.project_id {
+        Some(project_id) => {
+            state
+                .essay_service
+                .list_essays_by_project(user.id, project_id, &page)
+                .await
         }
+        None => state.essay_service.list_essays(user.id, &page).await,
+    };
+
+    match essays {
+        Ok(essays) => (StatusCode::OK, Json(essays)).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
@@ -189,3 +187,48 @@ pub async fn delete_essay(
         Err(e) => e.into_response(),
     }
 }
+
+#[utoipa::path(
+    post,
+    path = "/api/essays/{id}/translate",
+    tag = "Essays",
+    params(
+        ("id" = i32, Path, description = "원본 에세이 ID"),
+        ("lang" = String, Query, description = "번역 대상 언어 코드 (예: en, ja, zh-TW)")
+    ),
+    responses(
+        (status = 201, description = "에세이 번역 성공 (같은 언어의 번역본이 있으면 갱신)", body = EssayResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "에세이를 찾을 수 없음", body = ErrorResponse),
+        (status = 422, description = "AI 안전 필터에 의해 차단됨", body = AiContentErrorResponse),
+        (status = 429, description = "AI 사용 할당량 초과 (Retry-After, X-RateLimit-* 헤더 포함)", body = QuotaExceededResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse),
+        (status = 502, description = "AI 서비스 에러 또는 응답 길이 초과 (partial_text 포함)", body = AiContentErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn translate_essay(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Path(id): Path<i32>,
+    Query(params): Query<TranslateEssayParams>,
+) -> impl IntoResponse {
+    if let Err(e) = params.validate() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": format!("Validation failed: {}", e) })),
+        )
+            .into_response();
+    }
+
+    match state
+        .translation_service
+        .translate_essay(user.id, id, &params.lang)
+        .await
+    {
+        Ok(essay) => (StatusCode::CREATED, Json(essay)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
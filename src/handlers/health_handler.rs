@@ -1,14 +1,24 @@
 use super::AppState;
+use crate::clients::CircuitState;
 use axum::{extract::State, http::StatusCode, Json};
 use serde::Serialize;
 use utoipa::ToSchema;
 
 #[derive(Serialize, ToSchema)]
 pub struct HealthResponse {
+    /// AI 프로바이더 회로가 하나라도 열려 있으면 "degraded"
     #[schema(example = "ok")]
     pub status: String,
     #[schema(example = "connected")]
     pub database: String,
+    pub ai: AiHealth,
+}
+
+/// AI 프로바이더별 서킷 브레이커 상태. 브레이커가 없으면 null
+#[derive(Serialize, ToSchema)]
+pub struct AiHealth {
+    pub embedding: Option<CircuitState>,
+    pub generation: Option<CircuitState>,
 }
 
 #[utoipa::path(
@@ -25,9 +35,20 @@ pub async fn health_check(State(state): State<AppState>) -> (StatusCode, Json<He
         Err(_) => "disconnected",
     };
 
+    let ai = AiHealth {
+        embedding: state.embedder.circuit_state(),
+        generation: state.text_generator.circuit_state(),
+    };
+    let status = if [ai.embedding, ai.generation].contains(&Some(CircuitState::Open)) {
+        "degraded"
+    } else {
+        "ok"
+    };
+
     let response = HealthResponse {
-        status: "ok".to_string(),
+        status: status.to_string(),
         database: db_status.to_string(),
+        ai,
     };
 
     (StatusCode::OK, Json(response))
@@ -8,12 +8,15 @@ use serde::Deserialize;
 use validator::Validate;
 
 use super::{auth::AuthenticatedUser, AppState};
-use crate::errors::ErrorResponse;
+use crate::errors::{ErrorResponse, QuotaExceededResponse};
 use crate::models::memo_dto::{CreateMemoRequest, MemoResponse, UpdateMemoRequest};
+use crate::models::{Page, PageParams};
 
 #[derive(Debug, Deserialize)]
 pub struct ListMemosParams {
     pub project_id: Option<i32>,
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
 }
 
 #[utoipa::path(
@@ -27,6 +30,7 @@ pub struct ListMemosParams {
         (status = 401, description = "인증 실패", body = ErrorResponse),
         (status = 403, description = "권한 없음", body = ErrorResponse),
         (status = 404, description = "프로젝트를 찾을 수 없음", body = ErrorResponse),
+        (status = 429, description = "메모 임베딩 할당량 초과 (Retry-After, X-RateLimit-* 헤더 포함)", body = QuotaExceededResponse),
         (status = 500, description = "서버 에러", body = ErrorResponse)
     ),
     security(("bearer_auth" = []))
@@ -55,10 +59,13 @@ pub async fn create_memo(
     path = "/api/memos",
     tag = "Memos",
     params(
-        ("project_id" = Option<i32>, Query, description = "프로젝트 ID (없으면 사용자의 모든 프로젝트의 메모 조회)")
+        ("project_id" = Option<i32>, Query, description = "프로젝트 ID (없으면 사용자의 모든 프로젝트의 메모 조회)"),
+        ("limit" = Option<u64>, Query, description = "한 번에 조회할 메모 수 (1-100, 기본 20)"),
+        ("cursor" = Option<String>, Query, description = "이전 응답의 next_cursor (없으면 첫 페이지)")
     ),
     responses(
-        (status = 200, description = "메모 목록 조회 성공", body = Vec<MemoResponse>),
+        (status = 200, description = "메모 목록 조회 성공 (고정된 메모, 최근 수정 순)", body = Page<MemoResponse>),
+        (status = 400, description = "잘못된 커서", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
         (status = 403, description = "권한 없음", body = ErrorResponse),
         (status = 404, description = "프로젝트를 찾을 수 없음", body = ErrorResponse),
@@ -71,33 +78,23 @@ pub async fn list_memos(
     user: AuthenticatedUser,
     Query(params): Query<ListMemosParams>,
 ) -> impl IntoResponse {
-    if let Some(project_id) = params.project_id {
-        let memos = state
-            .memo_service
-            .list_memos_by_project(user.id, project_id)
-            .await;
-        match memos {
-            Ok(memos) => (StatusCode::OK, Json(memos)).into_response(),
-            Err(e) => e.into_response(),
-        }
-    } else {
-        let projects = state.project_service.list_projects(user.id).await;
-        match projects {
-            Ok(project_list) => {
-                let mut all_memos = Vec::new();
-                for project in project_list {
-                    if let Ok(memos) = state
-                        .memo_service
-                        .list_memos_by_project(user.id, project.id)
-                        .await
-                    {
-                        all_memos.extend(memos);
-                    }
-                }
-                (StatusCode::OK, Json(all_memos)).into_response()
-            }
-            Err(e) => e.into_response(),
+    let page = PageParams {
+        limit: params.limit,
+        cursor: params.cursor,
+    };
+    let memos = match params.project_id {
+        Some(project_id) => {
+            state
+                .memo_service
+                .list_memos_by_project(user.id, project_id, &page)
+                .await
         }
+        None => state.memo_service.list_memos(user.id, &page).await,
+    };
+
+    match memos {
+        Ok(memos) => (StatusCode::OK, Json(memos)).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
@@ -140,6 +137,7 @@ pub async fn get_memo(
         (status = 400, description = "잘못된 요청", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
         (status = 404, description = "메모를 찾을 수 없음", body = ErrorResponse),
+        (status = 429, description = "메모 임베딩 할당량 초과 (Retry-After, X-RateLimit-* 헤더 포함)", body = QuotaExceededResponse),
         (status = 500, description = "서버 에러", body = ErrorResponse)
     ),
     security(("bearer_auth" = []))
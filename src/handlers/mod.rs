@@ -1,10 +1,12 @@
 pub mod assist_handler;
 pub mod auth;
 pub mod auth_handler;
+pub mod digest_handler;
 pub mod essay_handler;
 pub mod health_handler;
 pub mod memo_handler;
 pub mod project_handler;
+pub mod usage_handler;
 pub mod user_handler;
 
 use crate::{
@@ -12,9 +14,17 @@ use crate::{
     openapi::ApiDoc,
     repositories::QdrantRepo,
     services::{
-        assist_service::AssistService, essay_service::EssayService, memo_service::MemoService,
-        project_service::ProjectService, user_service::UserService,
+        assist_service::AssistService,
+        digest_service::{DigestConfig, DigestService},
+        essay_service::EssayService,
+        memo_service::MemoService,
+        project_service::ProjectService,
+        quota_service::QuotaConfig,
+        translation_service::TranslationService,
+        usage_service::UsageService,
+        user_service::UserService,
     },
+    utils::env::InvalidEnvVar,
 };
 use axum::{
     http::{header, HeaderValue, Method},
@@ -31,11 +41,16 @@ use utoipa_swagger_ui::SwaggerUi;
 #[derive(Clone)]
 pub struct AppState {
     pub db: Arc<DatabaseConnection>,
+    pub embedder: Arc<dyn Embedder>,
+    pub text_generator: Arc<dyn TextGenerator>,
     pub memo_service: Arc<MemoService>,
     pub assist_service: Arc<AssistService>,
     pub user_service: Arc<UserService>,
     pub project_service: Arc<ProjectService>,
     pub essay_service: Arc<EssayService>,
+    pub translation_service: Arc<TranslationService>,
+    pub digest_service: Arc<DigestService>,
+    pub usage_service: Arc<UsageService>,
 }
 
 pub fn create_router(
@@ -43,19 +58,23 @@ pub fn create_router(
     qdrant_repo: Arc<dyn QdrantRepo>,
     embedder: Arc<dyn Embedder>,
     text_generator: Arc<dyn TextGenerator>,
-) -> Router {
-    let memo_service = Arc::new(MemoService::new(
-        db.clone(),
-        qdrant_repo.clone(),
-        embedder.clone(),
-    ));
-
-    let assist_service = Arc::new(AssistService::new(
-        db.clone(),
-        qdrant_repo,
-        embedder,
-        text_generator,
-    ));
+) -> Result<Router, InvalidEnvVar> {
+    let quota_config = QuotaConfig::from_env()?;
+
+    let memo_service = Arc::new(
+        MemoService::new(db.clone(), qdrant_repo.clone(), embedder.clone())
+            .with_quota_config(quota_config.clone()),
+    );
+
+    let assist_service = Arc::new(
+        AssistService::new(
+            db.clone(),
+            qdrant_repo,
+            embedder.clone(),
+            text_generator.clone(),
+        )
+        .with_quota_config(quota_config.clone()),
+    );
 
     let user_service =
         Arc::new(UserService::new(db.clone()).expect("Failed to initialize UserService"));
@@ -64,13 +83,34 @@ pub fn create_router(
 
     let essay_service = Arc::new(EssayService::new(db.clone()));
 
+    let translation_service = Arc::new(
+        TranslationService::new(db.clone(), text_generator.clone())
+            .with_quota_config(quota_config.clone()),
+    );
+
+    let digest_service = Arc::new(
+        DigestService::new(
+            db.clone(),
+            text_generator.clone(),
+            DigestConfig::from_env()?,
+        )
+        .with_quota_config(quota_config),
+    );
+
+    let usage_service = Arc::new(UsageService::new(db.clone()));
+
     let app_state = AppState {
         db,
+        embedder,
+        text_generator,
         memo_service,
         assist_service,
         user_service,
         project_service,
         essay_service,
+        translation_service,
+        digest_service,
+        usage_service,
     };
 
     let openapi = ApiDoc::openapi();
@@ -94,11 +134,12 @@ pub fn create_router(
         .allow_credentials(true)
         .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION]);
 
-    Router::new()
+    Ok(Router::new()
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", openapi))
         .route("/api/health", get(health_handler::health_check))
         .route("/api/assist", post(assist_handler::assist))
         .route("/api/users/oauth-login", post(user_handler::oauth_login))
+        .route("/api/users/me/usage", get(usage_handler::get_my_usage))
         .route("/api/auth/refresh", post(auth_handler::refresh))
         .route("/api/auth/logout", post(auth_handler::logout))
         .route("/api/auth/logout-all", delete(auth_handler::logout_all))
@@ -109,7 +150,8 @@ pub fn create_router(
                 .route("/", get(project_handler::list_projects))
                 .route("/:id", get(project_handler::get_project))
                 .route("/:id", put(project_handler::update_project))
-                .route("/:id", delete(project_handler::delete_project)),
+                .route("/:id", delete(project_handler::delete_project))
+                .route("/:id/digests", get(digest_handler::list_digests)),
         )
         .nest(
             "/api/memos",
@@ -128,9 +170,10 @@ pub fn create_router(
                 .route("/", get(essay_handler::list_essays))
                 .route("/:id", get(essay_handler::get_essay))
                 .route("/:id", put(essay_handler::update_essay))
-                .route("/:id", delete(essay_handler::delete_essay)),
+                .route("/:id", delete(essay_handler::delete_essay))
+                .route("/:id/translate", post(essay_handler::translate_essay)),
         )
         .layer(CookieManagerLayer::new())
         .layer(cors)
-        .with_state(app_state)
+        .with_state(app_state))
 }
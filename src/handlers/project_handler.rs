@@ -1,5 +1,5 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
@@ -9,6 +9,7 @@ use validator::Validate;
 use super::{auth::AuthenticatedUser, AppState};
 use crate::errors::ErrorResponse;
 use crate::models::project_dto::{CreateProjectRequest, ProjectResponse, UpdateProjectRequest};
+use crate::models::{Page, PageParams};
 
 #[utoipa::path(
     post,
@@ -47,8 +48,13 @@ pub async fn create_project(
     get,
     path = "/api/projects",
     tag = "Projects",
+    params(
+        ("limit" = Option<u64>, Query, description = "한 번에 조회할 프로젝트 수 (1-100, 기본 20)"),
+        ("cursor" = Option<String>, Query, description = "이전 응답의 next_cursor (없으면 첫 페이지)")
+    ),
     responses(
-        (status = 200, description = "프로젝트 목록 조회 성공", body = Vec<ProjectResponse>),
+        (status = 200, description = "프로젝트 목록 조회 성공 (최근 생성 순)", body = Page<ProjectResponse>),
+        (status = 400, description = "잘못된 커서", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
         (status = 500, description = "서버 에러", body = ErrorResponse)
     ),
@@ -57,8 +63,9 @@ pub async fn create_project(
 pub async fn list_projects(
     State(state): State<AppState>,
     user: AuthenticatedUser,
+    Query(page): Query<PageParams>,
 ) -> impl IntoResponse {
-    match state.project_service.list_projects(user.id).await {
+    match state.project_service.list_projects(user.id, &page).await {
         Ok(projects) => (StatusCode::OK, Json(projects)).into_response(),
         Err(e) => e.into_response(),
     }
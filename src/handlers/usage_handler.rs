@@ -0,0 +1,47 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use validator::Validate;
+
+use super::{auth::AuthenticatedUser, AppState};
+use crate::errors::ErrorResponse;
+use crate::models::usage_dto::{DailyUsageResponse, UsageParams, DEFAULT_USAGE_DAYS};
+
+#[utoipa::path(
+    get,
+    path = "/api/users/me/usage",
+    tag = "Users",
+    params(
+        ("days" = Option<u32>, Query, description = "오늘을 포함해 조회할 일 수 (1-90, 기본 30)")
+    ),
+    responses(
+        (status = 200, description = "일별 AI 사용량 조회 성공 (최신 날짜순)", body = Vec<DailyUsageResponse>),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_my_usage(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Query(params): Query<UsageParams>,
+) -> impl IntoResponse {
+    if let Err(e) = params.validate() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": format!("Validation failed: {}", e) })),
+        )
+            .into_response();
+    }
+
+    let days = params.days.unwrap_or(DEFAULT_USAGE_DAYS);
+
+    match state.usage_service.daily_usage(user.id, days).await {
+        Ok(usage) => (StatusCode::OK, Json(usage)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+use tokio::{
+    task::JoinHandle,
+    time::{interval, MissedTickBehavior},
+};
+use tracing::{error, info};
+
+use crate::services::DigestService;
+
+/// 설정된 주기마다 다이제스트 생성 대상 프로젝트를 확인하는 백그라운드 작업.
+/// 중복 생성 방지는 DigestService가 DB 락으로 처리하므로 모든 레플리카에서 실행해도 된다
+pub fn spawn(digest_service: Arc<DigestService>) -> JoinHandle<()> {
+    let poll_interval = digest_service.config().poll_interval;
+
+    tokio::spawn(async move {
+        let mut ticker = interval(poll_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match digest_service.run_due_digests().await {
+                Ok(0) => {}
+                Ok(created) => info!("Created {} project digest(s)", created),
+                Err(e) => error!("Digest job failed: {}", e),
+            }
+        }
+    })
+}
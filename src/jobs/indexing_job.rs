@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use tokio::{
+    task::JoinHandle,
+    time::{interval, MissedTickBehavior},
+};
+use tracing::{error, info, warn};
+
+use crate::{errors::ServiceError, services::MemoService};
+
+/// 메모 인덱싱 작업과 삭제된 프로젝트의 벡터 삭제 작업(outbox)을 처리하는 백그라운드 워커.
+/// 작업은 DB 행 잠금으로 나누어 가지므로 모든 레플리카에서 실행해도 된다
+pub fn spawn(memo_service: Arc<MemoService>) -> JoinHandle<()> {
+    let config = memo_service.indexing_config().clone();
+
+    tokio::spawn(async move {
+        let mut ticker = interval(config.poll_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut paused = false;
+
+        loop {
+            ticker.tick().await;
+
+            // 가져온 작업이 배치를 가득 채우면 남은 작업이 있으므로 바로 이어서 처리
+            loop {
+                match memo_service.process_index_jobs().await {
+                    Ok(report) => {
+                        if paused {
+                            info!("Memo indexing resumed");
+                            paused = false;
+                        }
+                        if report.indexed > 0 {
+                            info!("Indexed {} memo(s)", report.indexed);
+                        }
+                        if report.dead > 0 {
+                            warn!("{} memo index job(s) moved to dead letter", report.dead);
+                        }
+                        if (report.claimed as u64) < config.batch_size {
+                            break;
+                        }
+                    }
+                    // 재인덱싱으로 별칭이 옮겨진 뒤 이 서버가 재시작될 때까지 인덱싱을 멈춘다
+                    Err(ServiceError::VectorFormatMismatch(reason)) => {
+                        if !paused {
+                            warn!("Memo indexing paused: {}", reason);
+                            paused = true;
+                        }
+                        break;
+                    }
+                    Err(e) => {
+                        error!("Memo indexing job failed: {}", e);
+                        break;
+                    }
+                }
+            }
+
+            match memo_service.process_project_deletions().await {
+                Ok(report) if report.deleted > 0 => {
+                    info!("Deleted vectors of {} project(s)", report.deleted)
+                }
+                Ok(_) => {}
+                Err(e) => error!("Project vector deletion job failed: {}", e),
+            }
+        }
+    })
+}
@@ -0,0 +1,3 @@
+pub mod digest_job;
+pub mod indexing_job;
+pub mod reconcile_job;
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use tokio::{
+    task::JoinHandle,
+    time::{interval_at, Instant, MissedTickBehavior},
+};
+use tracing::{error, info};
+
+use crate::services::MemoService;
+
+/// 설정된 주기마다 메모 테이블과 벡터 DB를 대조하는 백그라운드 작업.
+/// 고아 포인트 삭제와 인덱싱 작업 등록은 여러 번 실행해도 결과가 같으므로 모든 레플리카에서 실행해도 된다.
+/// `run_at_start`면 첫 주기를 기다리지 않고 바로 실행한다
+pub fn spawn(memo_service: Arc<MemoService>, run_at_start: bool) -> JoinHandle<()> {
+    let reconcile_interval = memo_service.reconcile_config().interval;
+
+    tokio::spawn(async move {
+        // 배포나 재시작 때마다 모든 레플리카가 동시에 전체 대조를 돌리지 않도록 보통은 첫 실행도 한 주기 뒤로 미룬다
+        let start = if run_at_start {
+            Instant::now()
+        } else {
+            Instant::now() + reconcile_interval
+        };
+        let mut ticker = interval_at(start, reconcile_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match memo_service.reconcile_index().await {
+                Ok(report) => info!(
+                    "Vector index reconciled: {} point(s) scanned, {} orphan(s) deleted, {} memo(s) requeued",
+                    report.points_scanned, report.orphans_deleted, report.missing_requeued
+                ),
+                Err(e) => error!("Vector index reconciliation failed: {}", e),
+            }
+        }
+    })
+}
@@ -1,8 +1,10 @@
+pub mod cli;
 pub mod clients;
 pub mod db;
 pub mod entities;
 pub mod errors;
 pub mod handlers;
+pub mod jobs;
 pub mod models;
 pub mod openapi;
 pub mod repositories;
@@ -10,9 +12,12 @@ pub mod services;
 pub mod test_utils;
 pub mod utils;
 
+#[cfg(test)]
+mod test_support;
+
 use anyhow::Result;
 use std::{env::var, sync::Arc};
-use tracing::info;
+use tracing::{info, warn};
 
 pub async fn run() -> Result<()> {
     dotenv::dotenv().ok();
@@ -21,28 +26,73 @@ pub async fn run() -> Result<()> {
     info!("Starting Inklings Server...");
 
     let database_url = var("DATABASE_URL").expect("DATABASE_URL must be set in .env file");
-    let qdrant_url = var("QDRANT_URL").expect("QDRANT_URL must be set in .env file");
-    let gemini_api_key = var("GEMINI_API_KEY").expect("GEMINI_API_KEY must be set in .env file");
     let host = var("SERVER_HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
     let port = var("SERVER_PORT").unwrap_or_else(|_| "8080".to_string());
     let addr = format!("{}:{}", host, port);
 
+    // 외부 연결 전에 AI 프로바이더와 작업 설정부터 검증하여 잘못된 설정이면 즉시 실패
+    let ai_config = clients::AiProviderConfig::from_env()?;
+    let vector_config = repositories::VectorStoreConfig::from_env()?;
+    let digest_config = services::DigestConfig::from_env()?;
+    let quota_config = services::QuotaConfig::from_env()?;
+    let indexing_config = services::IndexingConfig::from_env()?;
+    let reconcile_config = services::ReconcileConfig::from_env()?;
+    let providers = clients::build_providers(&ai_config)?;
+    info!(
+        "AI providers: embedding={}, generation={}",
+        ai_config.embedding, ai_config.generation
+    );
+
     let db = Arc::new(db::create_connection(&database_url).await?);
 
-    let qdrant_repo = Arc::new(
-        repositories::QdrantRepository::new(qdrant_url)
-            .await
-            .expect("Failed to initialize Qdrant repository"),
+    let vector_spec = repositories::VectorSpec::new(
+        providers.embedder.model_id(),
+        providers.embedder.dimension(),
+    );
+    let vector_store = repositories::VectorStore::connect(&vector_config, db.clone(), vector_spec)
+        .await
+        .expect("Failed to initialize vector store");
+    info!("Vector store: {}", vector_config.backend);
+    // 임베더와 벡터 형식이 다른 컬렉션으로는 저장과 검색이 모두 실패하므로 시작하지 않는다
+    vector_store.collections.validate_collection().await?;
+    let qdrant_repo = vector_store.repo;
+
+    if digest_config.enabled {
+        let digest_service = Arc::new(
+            services::DigestService::new(
+                db.clone(),
+                providers.text_generator.clone(),
+                digest_config,
+            )
+            .with_quota_config(quota_config),
+        );
+        jobs::digest_job::spawn(digest_service);
+        info!("Project digest job scheduled");
+    }
+
+    // 메모 저장 시 등록된 인덱싱 작업을 임베딩하여 벡터 DB에 반영
+    let indexing_service = Arc::new(
+        services::MemoService::new(db.clone(), qdrant_repo.clone(), providers.embedder.clone())
+            .with_indexing_config(indexing_config)
+            .with_reconcile_config(reconcile_config),
     );
+    jobs::indexing_job::spawn(indexing_service.clone());
 
-    let gemini_client = Arc::new(clients::GeminiClient::new(gemini_api_key));
+    // 메모 테이블과 벡터 DB 사이의 불일치를 주기적으로 복구
+    if indexing_service.reconcile_config().enabled {
+        // 재시작하면 비어 있는 저장소는 다음 주기까지 검색 결과가 없으므로 바로 다시 인덱싱한다
+        jobs::reconcile_job::spawn(indexing_service, vector_config.loses_vectors_on_restart());
+        info!("Vector index reconciliation job scheduled");
+    } else if vector_config.loses_vectors_on_restart() {
+        warn!("RECONCILE_ENABLED=false with VECTOR_STORE=memory and no VECTOR_SNAPSHOT_PATH: vectors lost on restart will not be re-indexed");
+    }
 
     let app = handlers::create_router(
         db,
         qdrant_repo,
-        gemini_client.clone() as Arc<dyn clients::Embedder>,
-        gemini_client as Arc<dyn clients::TextGenerator>,
-    );
+        providers.embedder,
+        providers.text_generator,
+    )?;
 
     let listener = tokio::net::TcpListener::bind(&addr)
         .await
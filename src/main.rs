@@ -1,7 +1,14 @@
 use anyhow::Result;
+use inklings_server::cli::Command;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // lib.rs에 있는 run 함수를 호출하여 서버를 실행합니다.
-    inklings_server::run().await
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match Command::parse(&args)? {
+        // 운영용 명령 실행 (cli.rs 참고)
+        Some(command) => inklings_server::cli::run(command).await,
+        // lib.rs에 있는 run 함수를 호출하여 서버를 실행합니다.
+        None => inklings_server::run().await,
+    }
 }
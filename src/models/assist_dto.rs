@@ -3,6 +3,14 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
 
+use super::essay_dto::validate_language_code;
+use crate::clients::GenerationOptions;
+
+/// 어시스트 응답 temperature 허용 범위
+const TEMPERATURE_RANGE: (f32, f32) = (0.0, 1.0);
+/// 어시스트 응답 최대 토큰 허용 범위
+const MAX_OUTPUT_TOKENS_RANGE: (u32, u32) = (64, 2048);
+
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct AssistRequest {
     #[schema(example = 1)]
@@ -16,12 +24,46 @@ pub struct AssistRequest {
     #[validate(range(min = 1, max = 20, message = "Limit must be 1-20"))]
     #[schema(example = 5)]
     pub limit: u64,
+
+    /// 응답의 다양성 (0.0-1.0). 범위를 벗어나면 가까운 경계값으로 조정된다
+    #[serde(default)]
+    #[schema(example = 0.7)]
+    pub temperature: Option<f32>,
+
+    /// 최대 응답 길이 (토큰, 64-2048). 범위를 벗어나면 가까운 경계값으로 조정된다
+    #[serde(default)]
+    #[schema(example = 1024)]
+    pub max_output_tokens: Option<u32>,
+
+    /// 응답 언어 코드 (예: ko, en, ja)
+    #[serde(default)]
+    #[validate(custom(function = "validate_language_code"))]
+    #[schema(example = "ko")]
+    pub response_language: Option<String>,
 }
 
 fn default_limit() -> u64 {
     5
 }
 
+impl AssistRequest {
+    /// 사용자가 지정할 수 있는 생성 옵션만 서버 허용 범위로 조정하여 변환.
+    /// 시스템 지시문과 중단 시퀀스는 서버에서만 정한다
+    pub fn generation_options(&self) -> GenerationOptions {
+        GenerationOptions {
+            temperature: self
+                .temperature
+                .filter(|t| t.is_finite())
+                .map(|t| t.clamp(TEMPERATURE_RANGE.0, TEMPERATURE_RANGE.1)),
+            max_output_tokens: self
+                .max_output_tokens
+                .map(|n| n.clamp(MAX_OUTPUT_TOKENS_RANGE.0, MAX_OUTPUT_TOKENS_RANGE.1)),
+            response_language: self.response_language.clone(),
+            ..GenerationOptions::default()
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct AssistResponse {
     #[schema(
@@ -40,3 +82,57 @@ pub struct SimilarMemo {
     #[schema(example = "2024-01-15T10:30:00")]
     pub created_at: NaiveDateTime,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> AssistRequest {
+        AssistRequest {
+            project_id: 1,
+            prompt: "글쓰기 도와줘".to_string(),
+            limit: 5,
+            temperature: None,
+            max_output_tokens: None,
+            response_language: None,
+        }
+    }
+
+    #[test]
+    fn test_generation_options_default_to_provider_defaults() {
+        assert_eq!(request().generation_options(), GenerationOptions::default());
+    }
+
+    #[test]
+    fn test_generation_options_are_clamped() {
+        let low = AssistRequest {
+            temperature: Some(-0.5),
+            max_output_tokens: Some(1),
+            ..request()
+        };
+        let options = low.generation_options();
+        assert_eq!(options.temperature, Some(0.0));
+        assert_eq!(options.max_output_tokens, Some(64));
+
+        let nan = AssistRequest {
+            temperature: Some(f32::NAN),
+            ..request()
+        };
+        assert_eq!(nan.generation_options().temperature, None);
+    }
+
+    #[test]
+    fn test_response_language_is_validated() {
+        let valid = AssistRequest {
+            response_language: Some("zh-TW".to_string()),
+            ..request()
+        };
+        assert!(valid.validate().is_ok());
+
+        let invalid = AssistRequest {
+            response_language: Some("English".to_string()),
+            ..request()
+        };
+        assert!(invalid.validate().is_err());
+    }
+}
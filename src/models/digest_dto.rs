@@ -0,0 +1,39 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::project_digest;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct DigestResponse {
+    #[schema(example = 7)]
+    pub id: i32,
+    #[schema(example = 1)]
+    pub project_id: i32,
+    #[schema(
+        example = "## 주요 주제\n- Rust 비동기 프로그래밍\n\n## 열린 질문\n- tokio 런타임은 언제 블로킹될까?"
+    )]
+    pub summary: String,
+    #[schema(example = 12)]
+    pub memo_count: i32,
+    #[schema(example = "2024-01-08T00:00:00")]
+    pub period_start: NaiveDateTime,
+    #[schema(example = "2024-01-15T00:00:00")]
+    pub period_end: NaiveDateTime,
+    #[schema(example = "2024-01-15T00:00:05")]
+    pub created_at: NaiveDateTime,
+}
+
+impl From<project_digest::Model> for DigestResponse {
+    fn from(digest: project_digest::Model) -> Self {
+        Self {
+            id: digest.id,
+            project_id: digest.project_id,
+            summary: digest.summary,
+            memo_count: digest.memo_count,
+            period_start: digest.period_start,
+            period_end: digest.period_end,
+            created_at: digest.created_at,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
 use crate::entities::essay;
 
@@ -31,6 +31,31 @@ pub struct UpdateEssayRequest {
     pub content: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema, Validate)]
+pub struct TranslateEssayParams {
+    /// 번역 대상 언어 코드 (예: en, ja, zh-TW)
+    #[schema(example = "en")]
+    #[validate(custom(function = "validate_language_code"))]
+    pub lang: String,
+}
+
+pub(crate) fn validate_language_code(lang: &str) -> Result<(), ValidationError> {
+    let mut parts = lang.split('-');
+    let primary = parts.next().unwrap_or_default();
+    let primary_valid =
+        (2..=3).contains(&primary.len()) && primary.chars().all(|c| c.is_ascii_lowercase());
+    let subtags_valid = parts
+        .all(|tag| (2..=8).contains(&tag.len()) && tag.chars().all(|c| c.is_ascii_alphanumeric()));
+
+    if primary_valid && subtags_valid && lang.len() <= 16 {
+        Ok(())
+    } else {
+        let mut err = ValidationError::new("language_code");
+        err.message = Some("Language must be a valid language code such as 'en' or 'zh-TW'".into());
+        Err(err)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
 pub struct EssayResponse {
     #[schema(example = 42)]
@@ -43,6 +68,12 @@ pub struct EssayResponse {
     pub content: String,
     #[schema(example = false)]
     pub is_pinned: bool,
+    #[schema(example = 41)]
+    pub source_essay_id: Option<i32>,
+    #[schema(example = "en")]
+    pub language: Option<String>,
+    #[schema(example = false)]
+    pub is_translation_stale: bool,
     #[schema(example = "2024-01-15T10:30:00")]
     pub created_at: NaiveDateTime,
     #[schema(example = "2024-01-15T10:30:00")]
@@ -57,6 +88,9 @@ impl From<essay::Model> for EssayResponse {
             title: essay.title,
             content: essay.content,
             is_pinned: essay.is_pinned,
+            source_essay_id: essay.source_essay_id,
+            language: essay.language,
+            is_translation_stale: essay.is_translation_stale,
             created_at: essay.created_at,
             updated_at: essay.updated_at,
         }
@@ -219,4 +253,24 @@ mod tests {
         };
         assert!(request.validate().is_err());
     }
+
+    #[test]
+    fn test_translate_essay_params_valid() {
+        for lang in ["en", "ko", "ja", "zh-TW", "pt-BR", "fil"] {
+            let params = TranslateEssayParams {
+                lang: lang.to_string(),
+            };
+            assert!(params.validate().is_ok(), "{} should be valid", lang);
+        }
+    }
+
+    #[test]
+    fn test_translate_essay_params_invalid() {
+        for lang in ["", "e", "EN", "english", "en-", "en_US", "ko-한국"] {
+            let params = TranslateEssayParams {
+                lang: lang.to_string(),
+            };
+            assert!(params.validate().is_err(), "{} should be invalid", lang);
+        }
+    }
 }
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
 
-use crate::entities::memo;
+use crate::entities::memo::{self, IndexStatus};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema, Validate)]
 pub struct CreateMemoRequest {
@@ -33,6 +33,8 @@ pub struct MemoResponse {
     pub content: String,
     #[schema(example = false)]
     pub is_pinned: bool,
+    /// 유사 메모 검색에 반영되었는지 여부. 저장 직후에는 `pending`
+    pub index_status: IndexStatus,
     #[schema(example = "2024-01-15T10:30:00")]
     pub created_at: NaiveDateTime,
     #[schema(example = "2024-01-15T10:30:00")]
@@ -46,6 +48,7 @@ impl From<memo::Model> for MemoResponse {
             project_id: memo.project_id,
             content: memo.content,
             is_pinned: memo.is_pinned,
+            index_status: memo.index_status,
             created_at: memo.created_at,
             updated_at: memo.updated_at,
         }
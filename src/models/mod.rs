@@ -1,11 +1,17 @@
 pub mod assist_dto;
+pub mod digest_dto;
 pub mod essay_dto;
 pub mod memo_dto;
+pub mod page_dto;
 pub mod project_dto;
+pub mod usage_dto;
 pub mod user_dto;
 
 pub use assist_dto::{AssistRequest, AssistResponse, SimilarMemo};
-pub use essay_dto::{CreateEssayRequest, EssayResponse, UpdateEssayRequest};
+pub use digest_dto::DigestResponse;
+pub use essay_dto::{CreateEssayRequest, EssayResponse, TranslateEssayParams, UpdateEssayRequest};
 pub use memo_dto::{CreateMemoRequest, MemoResponse, UpdateMemoRequest};
+pub use page_dto::{Page, PageParams};
 pub use project_dto::{CreateProjectRequest, ProjectResponse, UpdateProjectRequest};
+pub use usage_dto::{DailyUsageResponse, UsageParams};
 pub use user_dto::{AuthResponse, OAuthLoginRequest, UserResponse};
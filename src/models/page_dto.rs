@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 목록 조회 한 번에 반환하는 항목 수 기본값
+pub const DEFAULT_PAGE_LIMIT: u64 = 20;
+/// 목록 조회 한 번에 반환하는 최대 항목 수
+pub const MAX_PAGE_LIMIT: u64 = 100;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, ToSchema)]
+pub struct PageParams {
+    /// 한 번에 조회할 항목 수 (1-100, 기본 20). 범위를 벗어나면 가까운 경계값으로 조정된다
+    #[schema(example = 20)]
+    pub limit: Option<u64>,
+
+    /// 이전 응답의 `next_cursor`. 없으면 첫 페이지부터 조회
+    pub cursor: Option<String>,
+}
+
+impl PageParams {
+    pub fn limit(&self) -> u64 {
+        self.limit
+            .unwrap_or(DEFAULT_PAGE_LIMIT)
+            .clamp(1, MAX_PAGE_LIMIT)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+
+    /// 다음 페이지를 조회할 커서. 마지막 페이지이면 null
+    pub next_cursor: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_params_limit_is_clamped() {
+        assert_eq!(PageParams::default().limit(), DEFAULT_PAGE_LIMIT);
+
+        let params = |limit| PageParams {
+            limit: Some(limit),
+            cursor: None,
+        };
+        assert_eq!(params(0).limit(), 1);
+        assert_eq!(params(50).limit(), 50);
+        assert_eq!(params(1000).limit(), MAX_PAGE_LIMIT);
+    }
+}
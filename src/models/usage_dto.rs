@@ -0,0 +1,81 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::repositories::DailyUsageRow;
+
+/// 조회 기간 기본값 (일)
+pub const DEFAULT_USAGE_DAYS: u32 = 30;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema, Validate)]
+pub struct UsageParams {
+    /// 오늘을 포함해 거슬러 올라갈 일 수 (기본 30일)
+    #[schema(example = 30)]
+    #[validate(range(min = 1, max = 90, message = "Days must be between 1 and 90"))]
+    pub days: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct DailyUsageResponse {
+    #[schema(example = "2024-01-15")]
+    pub date: NaiveDate,
+
+    /// "embed" 또는 "generate"
+    #[schema(example = "generate")]
+    pub operation: String,
+
+    #[schema(example = "gemini-2.5-flash")]
+    pub model: String,
+
+    /// 프로바이더 호출 수
+    #[schema(example = 12)]
+    pub calls: i64,
+
+    /// 처리한 입력 수 (배치 임베딩은 텍스트 수만큼 집계)
+    #[schema(example = 12)]
+    pub items: i64,
+
+    #[schema(example = 4200)]
+    pub prompt_tokens: i64,
+
+    #[schema(example = 1800)]
+    pub candidate_tokens: i64,
+
+    #[schema(example = 6000)]
+    pub total_tokens: i64,
+}
+
+impl From<DailyUsageRow> for DailyUsageResponse {
+    fn from(row: DailyUsageRow) -> Self {
+        Self {
+            date: row.day,
+            operation: row.operation,
+            model: row.model,
+            calls: row.calls,
+            items: row.items,
+            prompt_tokens: row.prompt_tokens,
+            candidate_tokens: row.candidate_tokens,
+            total_tokens: row.total_tokens,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_params_valid() {
+        for days in [None, Some(1), Some(30), Some(90)] {
+            assert!(UsageParams { days }.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_usage_params_out_of_range() {
+        for days in [0, 91] {
+            assert!(UsageParams { days: Some(days) }.validate().is_err());
+        }
+    }
+}
@@ -1,12 +1,17 @@
 use utoipa::{Modify, OpenApi};
 
+use crate::clients::CircuitState;
+use crate::entities::memo::IndexStatus;
 use crate::entities::oauth_account::OAuthProvider;
-use crate::errors::ErrorResponse;
-use crate::handlers::health_handler::HealthResponse;
+use crate::errors::{AiContentErrorResponse, ErrorResponse, QuotaExceededResponse};
+use crate::handlers::health_handler::{AiHealth, HealthResponse};
 use crate::models::assist_dto::{AssistRequest, AssistResponse, SimilarMemo};
+use crate::models::digest_dto::DigestResponse;
 use crate::models::essay_dto::{CreateEssayRequest, EssayResponse, UpdateEssayRequest};
 use crate::models::memo_dto::{CreateMemoRequest, MemoResponse, UpdateMemoRequest};
+use crate::models::page_dto::Page;
 use crate::models::project_dto::{CreateProjectRequest, ProjectResponse, UpdateProjectRequest};
+use crate::models::usage_dto::DailyUsageResponse;
 use crate::models::user_dto::{AuthResponse, LogoutResponse, OAuthLoginRequest, UserResponse};
 
 #[derive(OpenApi)]
@@ -19,6 +24,7 @@ use crate::models::user_dto::{AuthResponse, LogoutResponse, OAuthLoginRequest, U
     paths(
         crate::handlers::health_handler::health_check,
         crate::handlers::user_handler::oauth_login,
+        crate::handlers::usage_handler::get_my_usage,
         crate::handlers::auth_handler::refresh,
         crate::handlers::auth_handler::logout,
         crate::handlers::auth_handler::logout_all,
@@ -27,11 +33,13 @@ use crate::models::user_dto::{AuthResponse, LogoutResponse, OAuthLoginRequest, U
         crate::handlers::project_handler::get_project,
         crate::handlers::project_handler::update_project,
         crate::handlers::project_handler::delete_project,
+        crate::handlers::digest_handler::list_digests,
         crate::handlers::essay_handler::create_essay,
         crate::handlers::essay_handler::list_essays,
         crate::handlers::essay_handler::get_essay,
         crate::handlers::essay_handler::update_essay,
         crate::handlers::essay_handler::delete_essay,
+        crate::handlers::essay_handler::translate_essay,
         crate::handlers::memo_handler::create_memo,
         crate::handlers::memo_handler::list_memos,
         crate::handlers::memo_handler::get_memo,
@@ -43,24 +51,34 @@ use crate::models::user_dto::{AuthResponse, LogoutResponse, OAuthLoginRequest, U
     components(
         schemas(
             HealthResponse,
+            AiHealth,
+            CircuitState,
             OAuthLoginRequest,
             UserResponse,
             AuthResponse,
             LogoutResponse,
             OAuthProvider,
+            IndexStatus,
+            DailyUsageResponse,
             CreateProjectRequest,
             UpdateProjectRequest,
             ProjectResponse,
+            Page<ProjectResponse>,
+            DigestResponse,
             CreateEssayRequest,
             UpdateEssayRequest,
             EssayResponse,
+            Page<EssayResponse>,
             CreateMemoRequest,
             UpdateMemoRequest,
             MemoResponse,
+            Page<MemoResponse>,
             AssistRequest,
             AssistResponse,
             SimilarMemo,
             ErrorResponse,
+            QuotaExceededResponse,
+            AiContentErrorResponse,
         )
     ),
     tags(
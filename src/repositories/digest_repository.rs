@@ -0,0 +1,82 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::*;
+use std::sync::Arc;
+
+use crate::entities::project_digest::{self, Entity as ProjectDigest};
+
+/// pg_try_advisory_xact_lock(key1, key2)의 key1. 다른 잡의 락과 겹치지 않도록 고정값 사용
+const DIGEST_LOCK_NAMESPACE: i32 = 0x4449_4753; // "DIGS"
+
+#[derive(Clone)]
+pub struct DigestRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl DigestRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn find_by_project_id(
+        &self,
+        project_id: i32,
+    ) -> Result<Vec<project_digest::Model>, DbErr> {
+        ProjectDigest::find()
+            .filter(project_digest::Column::ProjectId.eq(project_id))
+            .order_by_desc(project_digest::Column::PeriodEnd)
+            .all(self.db.as_ref())
+            .await
+    }
+
+    /// 트랜잭션 범위의 프로젝트 단위 advisory lock. 다른 레플리카가 잡고 있으면 false
+    pub async fn try_lock_project<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        project_id: i32,
+    ) -> Result<bool, DbErr> {
+        let row = conn
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT pg_try_advisory_xact_lock($1, $2) AS locked",
+                [DIGEST_LOCK_NAMESPACE.into(), project_id.into()],
+            ))
+            .await?
+            .ok_or(DbErr::Custom("Advisory lock query returned no rows".into()))?;
+
+        row.try_get("", "locked")
+    }
+
+    pub async fn find_latest_by_project_id<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        project_id: i32,
+    ) -> Result<Option<project_digest::Model>, DbErr> {
+        ProjectDigest::find()
+            .filter(project_digest::Column::ProjectId.eq(project_id))
+            .order_by_desc(project_digest::Column::PeriodEnd)
+            .one(conn)
+            .await
+    }
+
+    pub async fn create<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        project_id: i32,
+        summary: String,
+        memo_count: i32,
+        period_start: NaiveDateTime,
+        period_end: NaiveDateTime,
+    ) -> Result<project_digest::Model, DbErr> {
+        let active_model = project_digest::ActiveModel {
+            project_id: Set(project_id),
+            summary: Set(summary),
+            memo_count: Set(memo_count),
+            period_start: Set(period_start),
+            period_end: Set(period_end),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        active_model.insert(conn).await
+    }
+}
@@ -2,7 +2,28 @@ use chrono::Utc;
 use sea_orm::*;
 use std::sync::Arc;
 
-use crate::entities::essay::{self, Entity as Essay};
+use super::keyset::PinnedOrderKey;
+use crate::entities::{
+    essay::{self, Entity as Essay},
+    project,
+};
+
+fn page(select: Select<Essay>, after: Option<PinnedOrderKey>, limit: u64) -> Select<Essay> {
+    let select = match after {
+        Some(key) => select.filter(key.after(
+            essay::Column::IsPinned,
+            essay::Column::UpdatedAt,
+            essay::Column::Id,
+        )),
+        None => select,
+    };
+
+    select
+        .order_by_desc(essay::Column::IsPinned)
+        .order_by_desc(essay::Column::UpdatedAt)
+        .order_by_desc(essay::Column::Id)
+        .limit(limit)
+}
 
 #[derive(Clone)]
 pub struct EssayRepository {
@@ -18,13 +39,38 @@ impl EssayRepository {
         Essay::find_by_id(id).one(self.db.as_ref()).await
     }
 
-    pub async fn find_by_project_id(&self, project_id: i32) -> Result<Vec<essay::Model>, DbErr> {
-        Essay::find()
-            .filter(essay::Column::ProjectId.eq(project_id))
-            .order_by_desc(essay::Column::IsPinned)
-            .order_by_desc(essay::Column::UpdatedAt)
-            .all(self.db.as_ref())
-            .await
+    /// 프로젝트의 에세이를 고정 여부, 수정 시각, ID 내림차순으로 `after` 다음부터 최대 `limit`개 조회
+    pub async fn find_page_by_project_id(
+        &self,
+        project_id: i32,
+        after: Option<PinnedOrderKey>,
+        limit: u64,
+    ) -> Result<Vec<essay::Model>, DbErr> {
+        page(
+            Essay::find().filter(essay::Column::ProjectId.eq(project_id)),
+            after,
+            limit,
+        )
+        .all(self.db.as_ref())
+        .await
+    }
+
+    /// 사용자의 모든 프로젝트의 에세이를 [`Self::find_page_by_project_id`]와 같은 순서로 조회
+    pub async fn find_page_by_user_id(
+        &self,
+        user_id: i32,
+        after: Option<PinnedOrderKey>,
+        limit: u64,
+    ) -> Result<Vec<essay::Model>, DbErr> {
+        page(
+            Essay::find()
+                .inner_join(project::Entity)
+                .filter(project::Column::UserId.eq(user_id)),
+            after,
+            limit,
+        )
+        .all(self.db.as_ref())
+        .await
     }
 
     pub async fn create(
@@ -40,6 +86,7 @@ impl EssayRepository {
             title: Set(title),
             content: Set(content),
             is_pinned: Set(false),
+            is_translation_stale: Set(false),
             created_at: Set(now),
             updated_at: Set(now),
             ..Default::default()
@@ -67,6 +114,63 @@ impl EssayRepository {
         active_model.update(self.db.as_ref()).await
     }
 
+    /// 원본 + 언어 조합의 번역본을 생성하거나, 이미 있으면 새 번역으로 덮어쓰고 stale 표시를 해제.
+    /// 동시 요청에도 유니크 인덱스 충돌 없이 하나의 번역본만 남도록 한 문장으로 처리한다.
+    pub async fn upsert_translation(
+        &self,
+        source: &essay::Model,
+        language: String,
+        title: String,
+        content: String,
+    ) -> Result<essay::Model, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let active_model = essay::ActiveModel {
+            project_id: Set(source.project_id),
+            title: Set(title),
+            content: Set(content),
+            is_pinned: Set(false),
+            source_essay_id: Set(Some(source.id)),
+            language: Set(Some(language)),
+            is_translation_stale: Set(false),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        Essay::insert(active_model)
+            .on_conflict(
+                sea_query::OnConflict::columns([
+                    essay::Column::SourceEssayId,
+                    essay::Column::Language,
+                ])
+                .update_columns([
+                    essay::Column::Title,
+                    essay::Column::Content,
+                    essay::Column::IsTranslationStale,
+                    essay::Column::UpdatedAt,
+                ])
+                .to_owned(),
+            )
+            .exec_with_returning(self.db.as_ref())
+            .await
+    }
+
+    /// 원본 에세이가 수정되었을 때 연결된 번역본들을 stale로 표시
+    pub async fn mark_translations_stale(
+        &self,
+        source_essay_id: i32,
+    ) -> Result<UpdateResult, DbErr> {
+        Essay::update_many()
+            .col_expr(
+                essay::Column::IsTranslationStale,
+                sea_query::Expr::value(true),
+            )
+            .filter(essay::Column::SourceEssayId.eq(source_essay_id))
+            .exec(self.db.as_ref())
+            .await
+    }
+
     pub async fn delete(&self, id: i32) -> Result<DeleteResult, DbErr> {
         Essay::delete_by_id(id).exec(self.db.as_ref()).await
     }
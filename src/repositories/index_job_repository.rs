@@ -0,0 +1,238 @@
+use chrono::{Duration, NaiveDateTime, Utc};
+use sea_orm::{sea_query::Expr, *};
+use std::sync::Arc;
+
+use crate::entities::memo_index_job::{self, Entity as MemoIndexJob};
+
+#[derive(Clone)]
+pub struct IndexJobRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl IndexJobRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn find_by_memo_id(
+        &self,
+        memo_id: i32,
+    ) -> Result<Option<memo_index_job::Model>, DbErr> {
+        MemoIndexJob::find()
+            .filter(memo_index_job::Column::MemoId.eq(memo_id))
+            .one(self.db.as_ref())
+            .await
+    }
+
+    /// 메모 인덱싱 작업 등록. 메모 쓰기와 같은 트랜잭션에서 호출한다.
+    /// 이미 작업이 있으면 재시도 횟수를 초기화하고 버전(`enqueued_at`)을 올린다.
+    /// 처리 중인 작업은 임대를 유지하여 두 워커가 같은 메모를 동시에 임베딩하지 않게 하고,
+    /// 처리하던 워커가 버전 불일치를 보고 작업을 다시 대기 상태로 돌린다
+    pub async fn enqueue<C: ConnectionTrait>(&self, conn: &C, memo_id: i32) -> Result<(), DbErr> {
+        let now = Utc::now().naive_utc();
+
+        conn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"
+            INSERT INTO memo_index_jobs (memo_id, status, attempts, run_after, enqueued_at)
+            VALUES ($1, 'pending', 0, $2, $2)
+            ON CONFLICT (memo_id) DO UPDATE SET
+                status = CASE WHEN memo_index_jobs.status = 'processing'
+                              THEN 'processing' ELSE 'pending' END,
+                attempts = 0,
+                last_error = NULL,
+                run_after = EXCLUDED.run_after,
+                enqueued_at = EXCLUDED.enqueued_at
+            "#,
+            [memo_id.into(), now.into()],
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    /// 실행할 때가 된 작업을 최대 `limit`개 가져와 `lease` 동안 처리 중으로 표시.
+    /// 임대가 만료된 처리 중 작업(워커 중단)도 다시 가져오며,
+    /// SKIP LOCKED로 여러 레플리카가 같은 작업을 가져가지 않는다.
+    /// `memo_ids`를 주면 해당 메모의 작업만 가져온다
+    pub async fn claim(
+        &self,
+        memo_ids: Option<&[i32]>,
+        limit: u64,
+        lease: Duration,
+    ) -> Result<Vec<memo_index_job::Model>, DbErr> {
+        let now = Utc::now().naive_utc();
+        let memo_ids: Option<Vec<i32>> = memo_ids.map(|ids| ids.to_vec());
+
+        memo_index_job::Model::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"
+            UPDATE memo_index_jobs SET
+                status = 'processing',
+                attempts = attempts + 1,
+                locked_until = $2
+            WHERE id IN (
+                SELECT id FROM memo_index_jobs
+                WHERE ((status = 'pending' AND run_after <= $1)
+                       OR (status = 'processing' AND locked_until < $1))
+                  AND ($4::int[] IS NULL OR memo_id = ANY($4))
+                ORDER BY run_after
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING *
+            "#,
+            [
+                now.into(),
+                (now + lease).into(),
+                (limit as i64).into(),
+                memo_ids.into(),
+            ],
+        ))
+        .all(self.db.as_ref())
+        .await
+    }
+
+    /// 처리 완료된 작업 삭제. 처리 중 다시 등록되었으면 삭제하지 않고 false
+    pub async fn complete<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        job: &memo_index_job::Model,
+    ) -> Result<bool, DbErr> {
+        let result = MemoIndexJob::delete_many()
+            .filter(memo_index_job::Column::Id.eq(job.id))
+            .filter(memo_index_job::Column::EnqueuedAt.eq(job.enqueued_at))
+            .exec(conn)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// 실패한 작업을 `run_after` 이후에 다시 처리하도록 대기 상태로 되돌림.
+    /// 시도 횟수에 넣지 않을 실패는 `attempts`에 가져가기 전 횟수를 준다
+    pub async fn reschedule(
+        &self,
+        job: &memo_index_job::Model,
+        error: &str,
+        run_after: NaiveDateTime,
+        attempts: i32,
+    ) -> Result<bool, DbErr> {
+        let result = MemoIndexJob::update_many()
+            .col_expr(
+                memo_index_job::Column::Status,
+                Expr::value(memo_index_job::JobStatus::Pending),
+            )
+            .col_expr(memo_index_job::Column::Attempts, Expr::value(attempts))
+            .col_expr(memo_index_job::Column::LastError, Expr::value(error))
+            .col_expr(memo_index_job::Column::RunAfter, Expr::value(run_after))
+            .col_expr(
+                memo_index_job::Column::LockedUntil,
+                Expr::value(Option::<NaiveDateTime>::None),
+            )
+            .filter(memo_index_job::Column::Id.eq(job.id))
+            .filter(memo_index_job::Column::EnqueuedAt.eq(job.enqueued_at))
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// 더 이상 재시도하지 않는 작업으로 표시 (dead letter)
+    pub async fn mark_dead<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        job: &memo_index_job::Model,
+        error: &str,
+    ) -> Result<bool, DbErr> {
+        let result = MemoIndexJob::update_many()
+            .col_expr(
+                memo_index_job::Column::Status,
+                Expr::value(memo_index_job::JobStatus::Dead),
+            )
+            .col_expr(memo_index_job::Column::LastError, Expr::value(error))
+            .col_expr(
+                memo_index_job::Column::LockedUntil,
+                Expr::value(Option::<NaiveDateTime>::None),
+            )
+            .filter(memo_index_job::Column::Id.eq(job.id))
+            .filter(memo_index_job::Column::EnqueuedAt.eq(job.enqueued_at))
+            .exec(conn)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// dead 작업을 재시도 횟수를 초기화해 대기 상태로 되돌리고 해당 메모 ID를 반환.
+    /// `memo_ids`를 주면 해당 메모의 작업만 되돌린다
+    pub async fn requeue_dead<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        memo_ids: Option<&[i32]>,
+    ) -> Result<Vec<i32>, DbErr> {
+        let now = Utc::now().naive_utc();
+        let memo_ids: Option<Vec<i32>> = memo_ids.map(|ids| ids.to_vec());
+
+        let rows = conn
+            .query_all(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"
+                UPDATE memo_index_jobs SET
+                    status = 'pending',
+                    attempts = 0,
+                    run_after = $1
+                WHERE status = 'dead'
+                  AND ($2::int[] IS NULL OR memo_id = ANY($2))
+                RETURNING memo_id
+                "#,
+                [now.into(), memo_ids.into()],
+            ))
+            .await?;
+
+        rows.iter()
+            .map(|row| row.try_get::<i32>("", "memo_id"))
+            .collect()
+    }
+
+    /// 아직 임베딩되지 않은(대기·처리 중) 사용자 메모 작업 수
+    pub async fn count_queued_by_user(&self, user_id: i32) -> Result<i64, DbErr> {
+        let row = self
+            .db
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"
+                SELECT COUNT(*) AS queued
+                FROM memo_index_jobs j
+                JOIN memos m ON m.id = j.memo_id
+                JOIN projects p ON p.id = m.project_id
+                WHERE p.user_id = $1
+                  AND j.status IN ('pending', 'processing')
+                "#,
+                [user_id.into()],
+            ))
+            .await?;
+
+        match row {
+            Some(row) => row.try_get("", "queued"),
+            None => Ok(0),
+        }
+    }
+
+    /// 처리 중 다시 등록된 작업의 임대를 풀어 최신 내용으로 다시 처리되게 함
+    pub async fn release(&self, job: &memo_index_job::Model) -> Result<(), DbErr> {
+        MemoIndexJob::update_many()
+            .col_expr(
+                memo_index_job::Column::Status,
+                Expr::value(memo_index_job::JobStatus::Pending),
+            )
+            .col_expr(
+                memo_index_job::Column::LockedUntil,
+                Expr::value(Option::<NaiveDateTime>::None),
+            )
+            .filter(memo_index_job::Column::Id.eq(job.id))
+            .filter(memo_index_job::Column::Status.eq(memo_index_job::JobStatus::Processing))
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+}
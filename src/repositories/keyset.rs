@@ -0,0 +1,81 @@
+use chrono::NaiveDateTime;
+use sea_orm::{
+    sea_query::{Expr, SimpleExpr},
+    ColumnTrait, IntoSimpleExpr,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{essay, memo, project};
+
+/// 고정 여부, 수정 시각, ID 내림차순 목록(메모, 에세이)에서 한 항목의 위치
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PinnedOrderKey {
+    pub is_pinned: bool,
+    pub updated_at: NaiveDateTime,
+    pub id: i32,
+}
+
+impl PinnedOrderKey {
+    /// 이 항목 다음에 오는 행의 조건. 세 컬럼 모두 내림차순이므로 행 값 비교 하나로 표현된다
+    pub(crate) fn after(
+        &self,
+        is_pinned: impl ColumnTrait,
+        updated_at: impl ColumnTrait,
+        id: impl ColumnTrait,
+    ) -> SimpleExpr {
+        Expr::tuple([
+            is_pinned.into_simple_expr(),
+            updated_at.into_simple_expr(),
+            id.into_simple_expr(),
+        ])
+        .lt(Expr::tuple([
+            self.is_pinned.into(),
+            self.updated_at.into(),
+            self.id.into(),
+        ]))
+    }
+}
+
+impl From<&memo::Model> for PinnedOrderKey {
+    fn from(memo: &memo::Model) -> Self {
+        Self {
+            is_pinned: memo.is_pinned,
+            updated_at: memo.updated_at,
+            id: memo.id,
+        }
+    }
+}
+
+impl From<&essay::Model> for PinnedOrderKey {
+    fn from(essay: &essay::Model) -> Self {
+        Self {
+            is_pinned: essay.is_pinned,
+            updated_at: essay.updated_at,
+            id: essay.id,
+        }
+    }
+}
+
+/// 생성 시각, ID 내림차순 목록(프로젝트)에서 한 항목의 위치
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreatedOrderKey {
+    pub created_at: NaiveDateTime,
+    pub id: i32,
+}
+
+impl CreatedOrderKey {
+    /// 이 항목 다음에 오는 행의 조건
+    pub(crate) fn after(&self, created_at: impl ColumnTrait, id: impl ColumnTrait) -> SimpleExpr {
+        Expr::tuple([created_at.into_simple_expr(), id.into_simple_expr()])
+            .lt(Expr::tuple([self.created_at.into(), self.id.into()]))
+    }
+}
+
+impl From<&project::Model> for CreatedOrderKey {
+    fn from(project: &project::Model) -> Self {
+        Self {
+            created_at: project.created_at,
+            id: project.id,
+        }
+    }
+}
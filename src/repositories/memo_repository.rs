@@ -1,8 +1,29 @@
-use chrono::Utc;
-use sea_orm::*;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{sea_query::Expr, *};
 use std::sync::Arc;
 
-use crate::entities::memo::{self, Entity as Memo};
+use super::keyset::PinnedOrderKey;
+use crate::entities::{
+    memo::{self, Entity as Memo, IndexStatus},
+    project,
+};
+
+fn page(select: Select<Memo>, after: Option<PinnedOrderKey>, limit: u64) -> Select<Memo> {
+    let select = match after {
+        Some(key) => select.filter(key.after(
+            memo::Column::IsPinned,
+            memo::Column::UpdatedAt,
+            memo::Column::Id,
+        )),
+        None => select,
+    };
+
+    select
+        .order_by_desc(memo::Column::IsPinned)
+        .order_by_desc(memo::Column::UpdatedAt)
+        .order_by_desc(memo::Column::Id)
+        .limit(limit)
+}
 
 #[derive(Clone)]
 pub struct MemoRepository {
@@ -18,41 +39,232 @@ impl MemoRepository {
         Memo::find_by_id(id).one(self.db.as_ref()).await
     }
 
-    pub async fn find_by_project_id(&self, project_id: i32) -> Result<Vec<memo::Model>, DbErr> {
+    /// 프로젝트의 메모를 고정 여부, 수정 시각, ID 내림차순으로 `after` 다음부터 최대 `limit`개 조회
+    pub async fn find_page_by_project_id(
+        &self,
+        project_id: i32,
+        after: Option<PinnedOrderKey>,
+        limit: u64,
+    ) -> Result<Vec<memo::Model>, DbErr> {
+        page(
+            Memo::find().filter(memo::Column::ProjectId.eq(project_id)),
+            after,
+            limit,
+        )
+        .all(self.db.as_ref())
+        .await
+    }
+
+    /// 사용자의 모든 프로젝트의 메모를 [`Self::find_page_by_project_id`]와 같은 순서로 조회
+    pub async fn find_page_by_user_id(
+        &self,
+        user_id: i32,
+        after: Option<PinnedOrderKey>,
+        limit: u64,
+    ) -> Result<Vec<memo::Model>, DbErr> {
+        page(
+            Memo::find()
+                .inner_join(project::Entity)
+                .filter(project::Column::UserId.eq(user_id)),
+            after,
+            limit,
+        )
+        .all(self.db.as_ref())
+        .await
+    }
+
+    pub async fn find_created_between(
+        &self,
+        project_id: i32,
+        since: NaiveDateTime,
+        until: NaiveDateTime,
+    ) -> Result<Vec<memo::Model>, DbErr> {
         Memo::find()
             .filter(memo::Column::ProjectId.eq(project_id))
-            .order_by_desc(memo::Column::IsPinned)
-            .order_by_desc(memo::Column::UpdatedAt)
+            .filter(memo::Column::CreatedAt.gt(since))
+            .filter(memo::Column::CreatedAt.lte(until))
+            .order_by_asc(memo::Column::CreatedAt)
+            .all(self.db.as_ref())
+            .await
+    }
+
+    /// 주어진 시각 이후 메모가 작성된 프로젝트 ID 목록
+    pub async fn find_project_ids_with_memos_since(
+        &self,
+        since: NaiveDateTime,
+    ) -> Result<Vec<i32>, DbErr> {
+        Memo::find()
+            .select_only()
+            .column(memo::Column::ProjectId)
+            .distinct()
+            .filter(memo::Column::CreatedAt.gt(since))
+            .into_tuple()
             .all(self.db.as_ref())
             .await
     }
 
-    pub async fn create(&self, project_id: i32, content: String) -> Result<memo::Model, DbErr> {
+    /// 인덱싱 대기 상태의 메모 생성. 인덱싱 작업 등록과 같은 트랜잭션에서 호출한다
+    pub async fn create<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        project_id: i32,
+        content: String,
+    ) -> Result<memo::Model, DbErr> {
         let now = Utc::now().naive_utc();
 
         let active_model = memo::ActiveModel {
             project_id: Set(project_id),
             content: Set(content),
             is_pinned: Set(false),
+            index_status: Set(IndexStatus::Pending),
             created_at: Set(now),
             updated_at: Set(now),
             ..Default::default()
         };
 
-        active_model.insert(self.db.as_ref()).await
+        active_model.insert(conn).await
     }
 
-    pub async fn update(&self, id: i32, content: String) -> Result<memo::Model, DbErr> {
-        let memo = self
-            .find_by_id(id)
-            .await?
-            .ok_or(DbErr::RecordNotFound("Memo not found".into()))?;
-
+    pub async fn update<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        memo: memo::Model,
+        content: String,
+        index_status: IndexStatus,
+    ) -> Result<memo::Model, DbErr> {
         let mut active_model: memo::ActiveModel = memo.into();
         active_model.content = Set(content);
+        active_model.index_status = Set(index_status);
         active_model.updated_at = Set(Utc::now().naive_utc());
 
-        active_model.update(self.db.as_ref()).await
+        active_model.update(conn).await
+    }
+
+    /// 벡터 DB 인덱싱 완료 기록
+    pub async fn mark_indexed<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        id: i32,
+        content_hash: String,
+    ) -> Result<(), DbErr> {
+        Memo::update_many()
+            .col_expr(memo::Column::IndexStatus, Expr::value(IndexStatus::Indexed))
+            .col_expr(memo::Column::ContentHash, Expr::value(content_hash))
+            .col_expr(
+                memo::Column::EmbeddedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(memo::Column::Id.eq(id))
+            .exec(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_index_status<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        id: i32,
+        index_status: IndexStatus,
+    ) -> Result<(), DbErr> {
+        Memo::update_many()
+            .col_expr(memo::Column::IndexStatus, Expr::value(index_status))
+            .filter(memo::Column::Id.eq(id))
+            .exec(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_index_status_many<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        ids: &[i32],
+        index_status: IndexStatus,
+    ) -> Result<(), DbErr> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        Memo::update_many()
+            .col_expr(memo::Column::IndexStatus, Expr::value(index_status))
+            .filter(memo::Column::Id.is_in(ids.iter().copied()))
+            .exec(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// 주어진 ID 중 실제로 존재하는 메모 ID. `project_id`를 주면 해당 프로젝트의 메모만
+    pub async fn find_existing_ids(
+        &self,
+        ids: &[i32],
+        project_id: Option<i32>,
+    ) -> Result<Vec<i32>, DbErr> {
+        let mut query = Memo::find()
+            .select_only()
+            .column(memo::Column::Id)
+            .filter(memo::Column::Id.is_in(ids.iter().copied()));
+        if let Some(project_id) = project_id {
+            query = query.filter(memo::Column::ProjectId.eq(project_id));
+        }
+
+        query.into_tuple().all(self.db.as_ref()).await
+    }
+
+    /// `before` 이전에 인덱싱을 마친 메모 ID를 `after` 다음부터 오름차순으로 최대 `limit`개.
+    /// 인덱싱 시각 기록 전에 인덱싱된 메모(`embedded_at`이 NULL)도 포함
+    pub async fn find_indexed_ids(
+        &self,
+        project_id: Option<i32>,
+        before: NaiveDateTime,
+        after: i32,
+        limit: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        let mut query = Memo::find()
+            .select_only()
+            .column(memo::Column::Id)
+            .filter(memo::Column::IndexStatus.eq(IndexStatus::Indexed))
+            .filter(
+                Condition::any()
+                    .add(memo::Column::EmbeddedAt.is_null())
+                    .add(memo::Column::EmbeddedAt.lt(before)),
+            )
+            .filter(memo::Column::Id.gt(after));
+        if let Some(project_id) = project_id {
+            query = query.filter(memo::Column::ProjectId.eq(project_id));
+        }
+
+        query
+            .order_by_asc(memo::Column::Id)
+            .limit(limit)
+            .into_tuple()
+            .all(self.db.as_ref())
+            .await
+    }
+
+    pub async fn count(&self) -> Result<u64, DbErr> {
+        Memo::find().count(self.db.as_ref()).await
+    }
+
+    /// `after` 다음 ID부터 오름차순으로 최대 `limit`개.
+    /// `updated_since`를 주면 그 이후 수정된 메모만
+    pub async fn find_after_id(
+        &self,
+        after: i32,
+        updated_since: Option<NaiveDateTime>,
+        limit: u64,
+    ) -> Result<Vec<memo::Model>, DbErr> {
+        let mut query = Memo::find().filter(memo::Column::Id.gt(after));
+        if let Some(since) = updated_since {
+            query = query.filter(memo::Column::UpdatedAt.gte(since));
+        }
+
+        query
+            .order_by_asc(memo::Column::Id)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await
     }
 
     pub async fn delete(&self, id: i32) -> Result<DeleteResult, DbErr> {
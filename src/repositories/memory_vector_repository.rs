@@ -0,0 +1,497 @@
+use async_trait::async_trait;
+use sea_orm::DbErr;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering as AtomicOrdering},
+        Arc, RwLock,
+    },
+};
+use tokio::sync::Mutex;
+
+use super::qdrant_repository::{
+    Distance, MemoPoint, QdrantRepo, VectorCollections, VectorSpec, DEFAULT_COLLECTION,
+};
+
+/// 정규화하여 저장한 메모 벡터와 필터용 페이로드
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredPoint {
+    project_id: i32,
+    vector: Vec<f32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct MemoryCollection {
+    model_id: String,
+    dimension: usize,
+    points: BTreeMap<i32, StoredPoint>,
+}
+
+/// 전체 저장소 상태. 스냅샷 파일에도 이 형식 그대로 기록한다
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct State {
+    active: String,
+    collections: BTreeMap<String, MemoryCollection>,
+}
+
+impl State {
+    fn active(&self) -> Result<(&str, &MemoryCollection), DbErr> {
+        self.collections
+            .get(&self.active)
+            .map(|collection| (self.active.as_str(), collection))
+            .ok_or_else(|| DbErr::Custom("No active vector collection".to_string()))
+    }
+
+    fn active_mut(&mut self) -> Result<(&str, &mut MemoryCollection), DbErr> {
+        self.collections
+            .get_mut(&self.active)
+            .map(|collection| (self.active.as_str(), collection))
+            .ok_or_else(|| DbErr::Custom("No active vector collection".to_string()))
+    }
+}
+
+struct SnapshotFile {
+    path: PathBuf,
+    /// 기록을 요청할 때마다 늘어나는 변경 번호
+    changes: AtomicU64,
+    /// 스냅샷을 쓰는 순서가 변경 순서와 같도록 한 번에 하나씩 기록하며, 마지막으로 기록한 변경 번호를 보관
+    written: Mutex<u64>,
+}
+
+/// 프로세스 메모리에 벡터를 보관하고 전수 비교로 코사인 유사도 검색을 하는 저장소.
+/// Qdrant 없이 개발하거나 메모가 많지 않은 소규모 배포에서 사용한다.
+///
+/// 스냅샷 파일을 지정하면 변경할 때마다 파일 전체를 다시 기록하고 시작할 때 불러온다.
+/// 기록 중에 들어온 변경은 다음 기록 한 번에 모아 쓰지만, 기록 비용은 전체 벡터 수에 비례하므로
+/// 768차원 기준 메모 수만 개(스냅샷 수백 MB) 이상이면 Qdrant나 pgvector를 사용한다.
+/// 지정하지 않으면 재시작 시 벡터가 사라지며, 대조 작업이 주기를 기다리지 않고 시작하자마자 메모를 다시 인덱싱한다.
+/// 이때 RECONCILE_ENABLED=false면 벡터가 복구되지 않는다
+#[derive(Clone)]
+pub struct InMemoryVectorRepository {
+    state: Arc<RwLock<State>>,
+    snapshot: Option<Arc<SnapshotFile>>,
+    spec: VectorSpec,
+}
+
+impl InMemoryVectorRepository {
+    /// 파일에 기록하지 않는 저장소
+    pub fn new(spec: VectorSpec) -> Self {
+        let repo = Self {
+            state: Arc::new(RwLock::new(State {
+                active: DEFAULT_COLLECTION.to_string(),
+                collections: BTreeMap::new(),
+            })),
+            snapshot: None,
+            spec,
+        };
+        repo.ensure_collection();
+        repo
+    }
+
+    /// `path`의 스냅샷을 불러오고 이후 변경을 같은 파일에 기록하는 저장소. 파일이 없으면 새로 만든다
+    pub async fn open(path: impl AsRef<Path>, spec: VectorSpec) -> Result<Self, DbErr> {
+        let path = path.as_ref().to_path_buf();
+        let mut repo = Self::new(spec);
+
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => {
+                let state: State = serde_json::from_slice(&bytes).map_err(|e| {
+                    DbErr::Custom(format!(
+                        "Failed to read vector snapshot {}: {}",
+                        path.display(),
+                        e
+                    ))
+                })?;
+                repo.state = Arc::new(RwLock::new(state));
+                repo.ensure_collection();
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                return Err(DbErr::Custom(format!(
+                    "Failed to read vector snapshot {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        }
+
+        repo.snapshot = Some(Arc::new(SnapshotFile {
+            path,
+            changes: AtomicU64::new(0),
+            written: Mutex::new(0),
+        }));
+        repo.persist().await?;
+
+        Ok(repo)
+    }
+
+    /// 활성 컬렉션이 없으면 `spec` 형식으로 만든다
+    fn ensure_collection(&self) {
+        let mut state = self.state.write().unwrap();
+        let active = state.active.clone();
+        state
+            .collections
+            .entry(active)
+            .or_insert_with(|| MemoryCollection {
+                model_id: self.spec.model_id.clone(),
+                dimension: self.spec.dimension as usize,
+                points: BTreeMap::new(),
+            });
+    }
+
+    /// 컬렉션에 저장된 메모 ID (정렬됨)
+    pub fn memo_ids_in(&self, collection: &str) -> Vec<i32> {
+        let state = self.state.read().unwrap();
+        state
+            .collections
+            .get(collection)
+            .map(|c| c.points.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// 스냅샷 파일이 있으면 현재 상태를 기록. 임시 파일에 쓴 뒤 교체하여 중간에 중단되어도 이전 스냅샷이 남는다.
+    /// 기다리는 동안 다른 호출이 이 변경 이후의 상태를 이미 기록했으면 다시 쓰지 않는다
+    async fn persist(&self) -> Result<(), DbErr> {
+        let Some(snapshot) = &self.snapshot else {
+            return Ok(());
+        };
+
+        let change = snapshot.changes.fetch_add(1, AtomicOrdering::SeqCst) + 1;
+        let mut written = snapshot.written.lock().await;
+        if *written >= change {
+            return Ok(());
+        }
+
+        // 상태를 읽기 전에 번호를 가져와야 이 번호까지의 변경이 모두 상태에 들어 있다
+        let latest = snapshot.changes.load(AtomicOrdering::SeqCst);
+        let bytes = serde_json::to_vec(&*self.state.read().unwrap())
+            .map_err(|e| DbErr::Custom(format!("Failed to serialize vector snapshot: {}", e)))?;
+
+        let tmp_path = snapshot.path.with_extension("tmp");
+        let write = async {
+            tokio::fs::write(&tmp_path, bytes).await?;
+            tokio::fs::rename(&tmp_path, &snapshot.path).await
+        };
+        write.await.map_err(|e| {
+            DbErr::Custom(format!(
+                "Failed to write vector snapshot {}: {}",
+                snapshot.path.display(),
+                e
+            ))
+        })?;
+        *written = latest;
+
+        Ok(())
+    }
+}
+
+/// 코사인 유사도를 내적으로 계산하도록 단위 벡터로 저장 (영벡터는 그대로)
+fn normalized(mut vector: Vec<f32>) -> Vec<f32> {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|v| *v /= norm);
+    }
+    vector
+}
+
+fn check_dimension(collection: &str, expected: usize, vector: &[f32]) -> Result<(), DbErr> {
+    if vector.len() == expected {
+        return Ok(());
+    }
+
+    Err(DbErr::Custom(format!(
+        "Vector has {} dimensions, but collection '{}' stores {}",
+        vector.len(),
+        collection,
+        expected
+    )))
+}
+
+fn insert_point(
+    name: &str,
+    collection: &mut MemoryCollection,
+    point: MemoPoint,
+) -> Result<(), DbErr> {
+    check_dimension(name, collection.dimension, &point.vector)?;
+    collection.points.insert(
+        point.memo_id,
+        StoredPoint {
+            project_id: point.project_id,
+            vector: normalized(point.vector),
+        },
+    );
+    Ok(())
+}
+
+#[async_trait]
+impl VectorCollections for InMemoryVectorRepository {
+    async fn active_collection(&self) -> Result<Option<String>, DbErr> {
+        Ok(Some(self.state.read().unwrap().active.clone()))
+    }
+
+    async fn list_collections(&self) -> Result<Vec<String>, DbErr> {
+        Ok(self
+            .state
+            .read()
+            .unwrap()
+            .collections
+            .keys()
+            .cloned()
+            .collect())
+    }
+
+    async fn create_collection(&self, name: &str, spec: &VectorSpec) -> Result<(), DbErr> {
+        {
+            let mut state = self.state.write().unwrap();
+            if state.collections.contains_key(name) {
+                return Ok(());
+            }
+            state.collections.insert(
+                name.to_string(),
+                MemoryCollection {
+                    model_id: spec.model_id.clone(),
+                    dimension: spec.dimension as usize,
+                    points: BTreeMap::new(),
+                },
+            );
+        }
+
+        self.persist().await
+    }
+
+    async fn upsert_into(&self, collection: &str, points: Vec<MemoPoint>) -> Result<(), DbErr> {
+        {
+            let mut state = self.state.write().unwrap();
+            let memos = state.collections.get_mut(collection).ok_or_else(|| {
+                DbErr::Custom(format!("Vector collection '{}' does not exist", collection))
+            })?;
+            for point in points {
+                insert_point(collection, memos, point)?;
+            }
+        }
+
+        self.persist().await
+    }
+
+    async fn switch_alias(&self, collection: &str) -> Result<(), DbErr> {
+        {
+            let mut state = self.state.write().unwrap();
+            if !state.collections.contains_key(collection) {
+                return Err(DbErr::Custom(format!(
+                    "Vector collection '{}' does not exist",
+                    collection
+                )));
+            }
+            state.active = collection.to_string();
+        }
+
+        self.persist().await
+    }
+
+    async fn active_format_mismatch(&self) -> Result<Option<String>, DbErr> {
+        let state = self.state.read().unwrap();
+        let (name, collection) = state.active()?;
+        if let Err(reason) = self
+            .spec
+            .check(name, collection.dimension as u64, Distance::Cosine)
+        {
+            return Ok(Some(reason));
+        }
+
+        self.spec.warn_if_other_model(name, &collection.model_id);
+
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl QdrantRepo for InMemoryVectorRepository {
+    async fn upsert_memo(&self, memo_id: i32, user_id: i32, vector: Vec<f32>) -> Result<(), DbErr> {
+        {
+            let mut state = self.state.write().unwrap();
+            let (name, collection) = state.active_mut()?;
+            let point = MemoPoint {
+                memo_id,
+                project_id: user_id,
+                vector,
+            };
+            insert_point(name, collection, point)?;
+        }
+
+        self.persist().await
+    }
+
+    async fn search_similar(
+        &self,
+        user_id: i32,
+        query_vector: Vec<f32>,
+        limit: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        let state = self.state.read().unwrap();
+        let (name, collection) = state.active()?;
+        check_dimension(name, collection.dimension, &query_vector)?;
+        let query = normalized(query_vector);
+
+        let mut scored: Vec<(f32, i32)> = collection
+            .points
+            .iter()
+            .filter(|(_, point)| point.project_id == user_id)
+            .map(|(&memo_id, point)| {
+                let score = point.vector.iter().zip(&query).map(|(a, b)| a * b).sum();
+                (score, memo_id)
+            })
+            .collect();
+        // 점수가 같으면 메모 ID 순으로 결과를 고정
+        scored.sort_by(|a, b| {
+            b.0.partial_cmp(&a.0)
+                .unwrap_or(Ordering::Equal)
+                .then(a.1.cmp(&b.1))
+        });
+
+        Ok(scored
+            .into_iter()
+            .take(limit as usize)
+            .map(|(_, memo_id)| memo_id)
+            .collect())
+    }
+
+    async fn delete_memo(&self, memo_id: i32) -> Result<(), DbErr> {
+        {
+            let mut state = self.state.write().unwrap();
+            let (_, collection) = state.active_mut()?;
+            collection.points.remove(&memo_id);
+        }
+
+        self.persist().await
+    }
+
+    async fn delete_by_project(&self, project_id: i32) -> Result<(), DbErr> {
+        {
+            let mut state = self.state.write().unwrap();
+            let (_, collection) = state.active_mut()?;
+            collection
+                .points
+                .retain(|_, point| point.project_id != project_id);
+        }
+
+        self.persist().await
+    }
+
+    async fn scroll_memo_ids(
+        &self,
+        user_id: Option<i32>,
+        after: Option<i32>,
+        limit: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        let state = self.state.read().unwrap();
+        let (_, collection) = state.active()?;
+        let start = match after {
+            Some(i32::MAX) => return Ok(Vec::new()),
+            Some(after) => after + 1,
+            None => i32::MIN,
+        };
+
+        Ok(collection
+            .points
+            .range(start..)
+            .filter(|(_, point)| user_id.is_none_or(|id| id == point.project_id))
+            .map(|(&memo_id, _)| memo_id)
+            .take(limit as usize)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec() -> VectorSpec {
+        VectorSpec::new("test-embedding", 3)
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_restores_collections_and_alias() {
+        let path =
+            std::env::temp_dir().join(format!("inklings_vectors_{}.json", uuid::Uuid::new_v4()));
+
+        let repo = InMemoryVectorRepository::open(&path, spec()).await.unwrap();
+        repo.upsert_memo(1, 10, vec![1.0, 0.0, 0.0]).await.unwrap();
+        repo.upsert_memo(2, 10, vec![0.0, 1.0, 0.0]).await.unwrap();
+        repo.create_collection("memo_embeddings_v2", &VectorSpec::new("next", 2))
+            .await
+            .unwrap();
+        repo.upsert_into(
+            "memo_embeddings_v2",
+            vec![MemoPoint {
+                memo_id: 1,
+                project_id: 10,
+                vector: vec![0.0, 1.0],
+            }],
+        )
+        .await
+        .unwrap();
+        repo.delete_memo(2).await.unwrap();
+
+        let reopened = InMemoryVectorRepository::open(&path, spec()).await.unwrap();
+        assert_eq!(reopened.memo_ids_in("memo_embeddings"), vec![1]);
+        assert_eq!(reopened.memo_ids_in("memo_embeddings_v2"), vec![1]);
+        assert_eq!(
+            reopened
+                .search_similar(10, vec![1.0, 0.0, 0.0], 10)
+                .await
+                .unwrap(),
+            vec![1]
+        );
+
+        reopened.switch_alias("memo_embeddings_v2").await.unwrap();
+        let stale = InMemoryVectorRepository::open(&path, spec()).await.unwrap();
+        assert!(stale.validate_collection().await.is_err());
+        let reopened = InMemoryVectorRepository::open(&path, VectorSpec::new("next", 2))
+            .await
+            .unwrap();
+        assert_eq!(
+            reopened.active_collection().await.unwrap().as_deref(),
+            Some("memo_embeddings_v2")
+        );
+        assert!(reopened.validate_collection().await.is_ok());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_writes_share_snapshot_writes() {
+        let path =
+            std::env::temp_dir().join(format!("inklings_vectors_{}.json", uuid::Uuid::new_v4()));
+        let repo = InMemoryVectorRepository::open(&path, spec()).await.unwrap();
+
+        let mut tasks = tokio::task::JoinSet::new();
+        for memo_id in 1..=50 {
+            let repo = repo.clone();
+            tasks.spawn(async move { repo.upsert_memo(memo_id, 10, vec![1.0, 0.0, 0.0]).await });
+        }
+        while let Some(result) = tasks.join_next().await {
+            result.unwrap().unwrap();
+        }
+
+        // 모든 쓰기가 끝나면 마지막 변경까지 기록되어 있다
+        let reopened = InMemoryVectorRepository::open(&path, spec()).await.unwrap();
+        assert_eq!(
+            reopened.memo_ids_in("memo_embeddings"),
+            (1..=50).collect::<Vec<_>>()
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_vectors_of_another_dimension() {
+        let repo = InMemoryVectorRepository::new(spec());
+
+        let err = repo.upsert_memo(1, 10, vec![1.0, 0.0]).await.unwrap_err();
+        assert!(err.to_string().contains("'memo_embeddings' stores 3"));
+        assert!(repo.search_similar(10, vec![], 10).await.is_err());
+    }
+}
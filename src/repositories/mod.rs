@@ -1,15 +1,35 @@
+pub mod digest_repository;
 pub mod essay_repository;
+pub mod index_job_repository;
+pub mod keyset;
 pub mod memo_repository;
+pub mod memory_vector_repository;
 pub mod oauth_account_repository;
+pub mod pgvector_repository;
 pub mod project_repository;
 pub mod qdrant_repository;
 pub mod refresh_token_repository;
+pub mod reindex_run_repository;
+pub mod usage_repository;
 pub mod user_repository;
+pub mod vector_deletion_repository;
+pub mod vector_store;
 
+pub use digest_repository::DigestRepository;
 pub use essay_repository::EssayRepository;
+pub use index_job_repository::IndexJobRepository;
+pub use keyset::{CreatedOrderKey, PinnedOrderKey};
 pub use memo_repository::MemoRepository;
+pub use memory_vector_repository::InMemoryVectorRepository;
 pub use oauth_account_repository::OAuthAccountRepository;
+pub use pgvector_repository::PgVectorRepository;
 pub use project_repository::ProjectRepository;
-pub use qdrant_repository::{QdrantRepo, QdrantRepository};
+pub use qdrant_repository::{
+    Distance, MemoPoint, QdrantConfig, QdrantRepo, QdrantRepository, VectorCollections, VectorSpec,
+};
 pub use refresh_token_repository::RefreshTokenRepository;
+pub use reindex_run_repository::ReindexRunRepository;
+pub use usage_repository::{DailyUsageRow, UsageRepository, UsageTotals};
 pub use user_repository::UserRepository;
+pub use vector_deletion_repository::VectorDeletionRepository;
+pub use vector_store::{VectorBackend, VectorStore, VectorStoreConfig};
@@ -0,0 +1,488 @@
+use async_trait::async_trait;
+use sea_orm::*;
+use std::sync::Arc;
+
+use super::qdrant_repository::{
+    Distance, MemoPoint, QdrantRepo, VectorCollections, VectorSpec, DEFAULT_COLLECTION,
+};
+
+/// HNSW 인덱스 이름(`idx-memo_vectors-hnsw-<name>`)이 Postgres 식별자 길이 제한을 넘지 않도록
+const MAX_COLLECTION_NAME_LEN: usize = 40;
+
+/// 활성 컬렉션 정보
+struct ActiveCollection {
+    name: String,
+    model_id: String,
+    dimension: usize,
+}
+
+/// pgvector 확장을 사용하는 벡터 저장소. 별도 Qdrant 서비스 없이 Postgres에 메모 벡터를 저장한다.
+///
+/// Qdrant 컬렉션과 별칭은 `vector_collections` 테이블의 행과 활성 플래그로 대응한다.
+/// 벡터 열은 차원을 지정하지 않으므로 컬렉션마다 해당 차원으로 캐스팅한 부분 HNSW 인덱스
+/// (`vector_cosine_ops`)를 만들고, 검색도 같은 식으로 정렬하여 인덱스를 사용한다.
+/// 프로젝트로 거른 검색에 반복 스캔을 쓰므로 pgvector 0.8.0 이상이 필요하다
+#[derive(Clone)]
+pub struct PgVectorRepository {
+    db: Arc<DatabaseConnection>,
+    spec: VectorSpec,
+}
+
+impl PgVectorRepository {
+    /// pgvector 확장과 테이블이 없으면 만들고, 활성 컬렉션이 없으면 `spec` 형식의 컬렉션을 만들어 활성화한다.
+    /// 기존 컬렉션이 `spec`과 맞는지는 [`VectorCollections::validate_collection`]으로 확인한다
+    pub async fn new(db: Arc<DatabaseConnection>, spec: VectorSpec) -> Result<Self, DbErr> {
+        let repo = Self { db, spec };
+
+        repo.ensure_schema().await?;
+        repo.ensure_collection().await?;
+
+        Ok(repo)
+    }
+
+    /// 마이그레이션은 pgvector 확장을 설치할 수 없는 DB(Qdrant만 쓰는 배포)에서 테이블을 만들지 않으므로
+    /// 백엔드를 처음 사용할 때 확장과 테이블을 만든다. 이미 있으면 아무것도 하지 않는다
+    async fn ensure_schema(&self) -> Result<(), DbErr> {
+        self.db
+            .execute_unprepared("CREATE EXTENSION IF NOT EXISTS vector")
+            .await
+            .map_err(|e| {
+                DbErr::Custom(format!(
+                    "Failed to enable the pgvector extension ({}). VECTOR_STORE=pgvector needs \
+                     pgvector installed in this database (e.g. the pgvector/pgvector image) and \
+                     a role allowed to create extensions",
+                    e
+                ))
+            })?;
+
+        // 프로젝트로 거른 검색에 반복 스캔(`hnsw.iterative_scan`)을 사용한다
+        let row = self
+            .db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                "SELECT extversion FROM pg_extension WHERE extname = 'vector'",
+            ))
+            .await?;
+        let version: String = match row {
+            Some(row) => row.try_get("", "extversion")?,
+            None => String::new(),
+        };
+        if !supports_iterative_scan(&version) {
+            return Err(DbErr::Custom(format!(
+                "pgvector {} is installed, but VECTOR_STORE=pgvector needs 0.8.0 or later. \
+                 Upgrade the package and run `ALTER EXTENSION vector UPDATE`",
+                version
+            )));
+        }
+
+        for statement in [
+            r#"CREATE TABLE IF NOT EXISTS vector_collections (
+                name VARCHAR(128) NOT NULL PRIMARY KEY,
+                model_id VARCHAR(128) NOT NULL,
+                dimension INTEGER NOT NULL,
+                is_active BOOLEAN NOT NULL DEFAULT FALSE,
+                created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )"#,
+            r#"CREATE UNIQUE INDEX IF NOT EXISTS "idx-vector_collections-active"
+                ON vector_collections (is_active) WHERE is_active"#,
+            r#"CREATE TABLE IF NOT EXISTS memo_vectors (
+                collection VARCHAR(128) NOT NULL,
+                memo_id INTEGER NOT NULL,
+                project_id INTEGER NOT NULL,
+                embedding vector NOT NULL,
+                PRIMARY KEY (collection, memo_id),
+                CONSTRAINT fk_memo_vectors_collection FOREIGN KEY (collection)
+                    REFERENCES vector_collections (name) ON DELETE CASCADE ON UPDATE CASCADE
+            )"#,
+            r#"CREATE INDEX IF NOT EXISTS "idx-memo_vectors-collection-project_id"
+                ON memo_vectors (collection, project_id)"#,
+        ] {
+            self.db.execute_unprepared(statement).await?;
+        }
+
+        Ok(())
+    }
+
+    /// 활성 컬렉션이 없으면 기본 컬렉션(없으면 새로 생성)을 활성화
+    pub async fn ensure_collection(&self) -> Result<(), DbErr> {
+        if self.active_collection().await?.is_some() {
+            return Ok(());
+        }
+
+        self.create_collection(DEFAULT_COLLECTION, &self.spec)
+            .await?;
+        self.switch_alias(DEFAULT_COLLECTION).await
+    }
+
+    async fn active(&self) -> Result<ActiveCollection, DbErr> {
+        let row = self
+            .db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                "SELECT name, model_id, dimension FROM vector_collections WHERE is_active",
+            ))
+            .await?
+            .ok_or_else(|| DbErr::Custom("No active vector collection".to_string()))?;
+
+        Ok(ActiveCollection {
+            name: row.try_get("", "name")?,
+            model_id: row.try_get("", "model_id")?,
+            dimension: row.try_get::<i32>("", "dimension")? as usize,
+        })
+    }
+
+    async fn dimension_of<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        collection: &str,
+    ) -> Result<Option<usize>, DbErr> {
+        let row = conn
+            .query_one(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT dimension FROM vector_collections WHERE name = $1",
+                [collection.into()],
+            ))
+            .await?;
+
+        row.map(|row| row.try_get::<i32>("", "dimension").map(|d| d as usize))
+            .transpose()
+    }
+
+    async fn upsert<C: ConnectionTrait>(
+        conn: &C,
+        collection: &str,
+        dimension: usize,
+        point: MemoPoint,
+    ) -> Result<(), DbErr> {
+        if point.vector.len() != dimension {
+            return Err(DbErr::Custom(format!(
+                "Vector for memo {} has {} dimensions, but collection '{}' stores {}",
+                point.memo_id,
+                point.vector.len(),
+                collection,
+                dimension
+            )));
+        }
+
+        conn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"
+            INSERT INTO memo_vectors (collection, memo_id, project_id, embedding)
+            VALUES ($1, $2, $3, $4::vector)
+            ON CONFLICT (collection, memo_id) DO UPDATE SET
+                project_id = EXCLUDED.project_id,
+                embedding = EXCLUDED.embedding
+            "#,
+            [
+                collection.into(),
+                point.memo_id.into(),
+                point.project_id.into(),
+                vector_literal(&point.vector).into(),
+            ],
+        ))
+        .await?;
+
+        Ok(())
+    }
+}
+
+/// 컬렉션 이름은 인덱스 이름과 인덱스 조건에 그대로 들어가므로 소문자, 숫자, `_`만 허용
+fn checked_name(name: &str) -> Result<&str, DbErr> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_COLLECTION_NAME_LEN
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if valid {
+        Ok(name)
+    } else {
+        Err(DbErr::Custom(format!(
+            "Invalid vector collection name '{}' (use up to {} lowercase letters, digits or '_')",
+            name, MAX_COLLECTION_NAME_LEN
+        )))
+    }
+}
+
+/// `hnsw.iterative_scan`은 pgvector 0.8.0부터 지원한다
+fn supports_iterative_scan(version: &str) -> bool {
+    let mut parts = version.split('.').map(|part| part.parse::<u32>().ok());
+    match (parts.next().flatten(), parts.next().flatten()) {
+        (Some(major), Some(minor)) => (major, minor) >= (0, 8),
+        _ => false,
+    }
+}
+
+/// pgvector 입력 형식 (`[0.1,0.2,...]`)
+fn vector_literal(vector: &[f32]) -> String {
+    let values: Vec<String> = vector.iter().map(|v| v.to_string()).collect();
+    format!("[{}]", values.join(","))
+}
+
+#[async_trait]
+impl VectorCollections for PgVectorRepository {
+    async fn active_collection(&self) -> Result<Option<String>, DbErr> {
+        let row = self
+            .db
+            .query_one(Statement::from_string(
+                DbBackend::Postgres,
+                "SELECT name FROM vector_collections WHERE is_active",
+            ))
+            .await?;
+
+        row.map(|row| row.try_get("", "name")).transpose()
+    }
+
+    async fn list_collections(&self) -> Result<Vec<String>, DbErr> {
+        let rows = self
+            .db
+            .query_all(Statement::from_string(
+                DbBackend::Postgres,
+                "SELECT name FROM vector_collections ORDER BY name",
+            ))
+            .await?;
+
+        rows.iter().map(|row| row.try_get("", "name")).collect()
+    }
+
+    async fn create_collection(&self, name: &str, spec: &VectorSpec) -> Result<(), DbErr> {
+        let name = checked_name(name)?;
+
+        self.db
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"
+                INSERT INTO vector_collections (name, model_id, dimension)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (name) DO NOTHING
+                "#,
+                [
+                    name.into(),
+                    spec.model_id.as_str().into(),
+                    (spec.dimension as i32).into(),
+                ],
+            ))
+            .await?;
+
+        // 이미 있던 컬렉션이면 기록된 차원으로 인덱스를 만든다
+        let dimension = self
+            .dimension_of(self.db.as_ref(), name)
+            .await?
+            .unwrap_or(spec.dimension as usize);
+        self.db
+            .execute_unprepared(&format!(
+                "CREATE INDEX IF NOT EXISTS \"idx-memo_vectors-hnsw-{name}\" ON memo_vectors \
+                 USING hnsw ((embedding::vector({dimension})) vector_cosine_ops) \
+                 WHERE collection = '{name}'"
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn upsert_into(&self, collection: &str, points: Vec<MemoPoint>) -> Result<(), DbErr> {
+        if points.is_empty() {
+            return Ok(());
+        }
+
+        let txn = self.db.begin().await?;
+        let dimension = self.dimension_of(&txn, collection).await?.ok_or_else(|| {
+            DbErr::Custom(format!("Vector collection '{}' does not exist", collection))
+        })?;
+        for point in points {
+            Self::upsert(&txn, collection, dimension, point).await?;
+        }
+        txn.commit().await
+    }
+
+    async fn switch_alias(&self, collection: &str) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+        if self.dimension_of(&txn, collection).await?.is_none() {
+            return Err(DbErr::Custom(format!(
+                "Vector collection '{}' does not exist",
+                collection
+            )));
+        }
+
+        // 활성 컬렉션은 부분 유니크 인덱스로 하나만 허용하므로 먼저 해제한다
+        txn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "UPDATE vector_collections SET is_active = FALSE WHERE is_active AND name <> $1",
+            [collection.into()],
+        ))
+        .await?;
+        txn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "UPDATE vector_collections SET is_active = TRUE WHERE name = $1",
+            [collection.into()],
+        ))
+        .await?;
+        txn.commit().await
+    }
+
+    async fn active_format_mismatch(&self) -> Result<Option<String>, DbErr> {
+        let active = self.active().await?;
+        if let Err(reason) =
+            self.spec
+                .check(&active.name, active.dimension as u64, Distance::Cosine)
+        {
+            return Ok(Some(reason));
+        }
+
+        self.spec
+            .warn_if_other_model(&active.name, &active.model_id);
+
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl QdrantRepo for PgVectorRepository {
+    async fn upsert_memo(&self, memo_id: i32, user_id: i32, vector: Vec<f32>) -> Result<(), DbErr> {
+        let active = self.active().await?;
+        let point = MemoPoint {
+            memo_id,
+            project_id: user_id,
+            vector,
+        };
+
+        Self::upsert(self.db.as_ref(), &active.name, active.dimension, point).await
+    }
+
+    async fn search_similar(
+        &self,
+        user_id: i32,
+        query_vector: Vec<f32>,
+        limit: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        let active = self.active().await?;
+        let name = checked_name(&active.name)?;
+        let dimension = active.dimension;
+
+        // HNSW 인덱스는 컬렉션 전체에서 가까운 후보를 찾은 뒤 프로젝트로 거르므로, 다른 프로젝트의 벡터가
+        // 많으면 결과가 `limit`보다 적다. 반복 스캔으로 충분히 찾을 때까지 인덱스를 이어서 탐색하고,
+        // 반복 스캔은 순서를 엄격히 지키지 않으므로 찾은 결과를 거리순으로 다시 정렬한다
+        let txn = self.db.begin().await?;
+        txn.execute_unprepared("SET LOCAL hnsw.iterative_scan = relaxed_order")
+            .await?;
+
+        // 부분 HNSW 인덱스와 같은 조건과 식을 써야 인덱스를 사용한다
+        let rows = txn
+            .query_all(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                format!(
+                    "WITH candidates AS MATERIALIZED ( \
+                         SELECT memo_id, \
+                                embedding::vector({dimension}) <=> $2::vector({dimension}) AS distance \
+                         FROM memo_vectors \
+                         WHERE collection = '{name}' AND project_id = $1 \
+                         ORDER BY distance \
+                         LIMIT $3 \
+                     ) \
+                     SELECT memo_id FROM candidates ORDER BY distance"
+                ),
+                [
+                    user_id.into(),
+                    vector_literal(&query_vector).into(),
+                    (limit as i64).into(),
+                ],
+            ))
+            .await
+            .map_err(|e| DbErr::Custom(format!("Failed to search similar memos: {}", e)))?;
+        txn.commit().await?;
+
+        rows.iter().map(|row| row.try_get("", "memo_id")).collect()
+    }
+
+    async fn delete_memo(&self, memo_id: i32) -> Result<(), DbErr> {
+        self.db
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"
+                DELETE FROM memo_vectors
+                WHERE memo_id = $1
+                  AND collection = (SELECT name FROM vector_collections WHERE is_active)
+                "#,
+                [memo_id.into()],
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_by_project(&self, project_id: i32) -> Result<(), DbErr> {
+        self.db
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"
+                DELETE FROM memo_vectors
+                WHERE project_id = $1
+                  AND collection = (SELECT name FROM vector_collections WHERE is_active)
+                "#,
+                [project_id.into()],
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn scroll_memo_ids(
+        &self,
+        user_id: Option<i32>,
+        after: Option<i32>,
+        limit: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        let rows = self
+            .db
+            .query_all(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"
+                SELECT memo_id FROM memo_vectors
+                WHERE collection = (SELECT name FROM vector_collections WHERE is_active)
+                  AND ($1::int IS NULL OR project_id = $1)
+                  AND memo_id > $2
+                ORDER BY memo_id
+                LIMIT $3
+                "#,
+                [
+                    user_id.into(),
+                    after.unwrap_or(i32::MIN).into(),
+                    (limit as i64).into(),
+                ],
+            ))
+            .await?;
+
+        rows.iter().map(|row| row.try_get("", "memo_id")).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collection_names_are_safe_identifiers() {
+        assert!(checked_name("memo_embeddings").is_ok());
+        assert!(checked_name("memo_embeddings_v20261018093000").is_ok());
+
+        assert!(checked_name("").is_err());
+        assert!(checked_name("memo'; DROP TABLE memos; --").is_err());
+        assert!(checked_name("Memo_Embeddings").is_err());
+        assert!(checked_name(&"a".repeat(MAX_COLLECTION_NAME_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_vector_literal_uses_pgvector_input_format() {
+        assert_eq!(vector_literal(&[1.0, -0.5, 0.25]), "[1,-0.5,0.25]");
+        assert_eq!(vector_literal(&[]), "[]");
+    }
+
+    #[test]
+    fn test_iterative_scan_needs_pgvector_0_8() {
+        assert!(supports_iterative_scan("0.8.0"));
+        assert!(supports_iterative_scan("0.10.1"));
+        assert!(supports_iterative_scan("1.0"));
+
+        assert!(!supports_iterative_scan("0.7.4"));
+        assert!(!supports_iterative_scan(""));
+    }
+}
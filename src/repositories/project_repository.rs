@@ -2,6 +2,7 @@ use chrono::Utc;
 use sea_orm::*;
 use std::sync::Arc;
 
+use super::keyset::CreatedOrderKey;
 use crate::entities::project::{self, Entity as Project};
 
 #[derive(Clone)]
@@ -18,10 +19,22 @@ impl ProjectRepository {
         Project::find_by_id(id).one(self.db.as_ref()).await
     }
 
-    pub async fn find_by_user_id(&self, user_id: i32) -> Result<Vec<project::Model>, DbErr> {
-        Project::find()
-            .filter(project::Column::UserId.eq(user_id))
+    /// 사용자의 프로젝트를 생성 시각, ID 내림차순으로 `after` 다음부터 최대 `limit`개 조회
+    pub async fn find_page_by_user_id(
+        &self,
+        user_id: i32,
+        after: Option<CreatedOrderKey>,
+        limit: u64,
+    ) -> Result<Vec<project::Model>, DbErr> {
+        let mut select = Project::find().filter(project::Column::UserId.eq(user_id));
+        if let Some(key) = after {
+            select = select.filter(key.after(project::Column::CreatedAt, project::Column::Id));
+        }
+
+        select
             .order_by_desc(project::Column::CreatedAt)
+            .order_by_desc(project::Column::Id)
+            .limit(limit)
             .all(self.db.as_ref())
             .await
     }
@@ -82,7 +95,12 @@ impl ProjectRepository {
         active_model.update(self.db.as_ref()).await
     }
 
-    pub async fn delete(&self, id: i32) -> Result<DeleteResult, DbErr> {
-        Project::delete_by_id(id).exec(self.db.as_ref()).await
+    /// 벡터 삭제 작업 등록과 같은 트랜잭션에서 호출한다
+    pub async fn delete<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        id: i32,
+    ) -> Result<DeleteResult, DbErr> {
+        Project::delete_by_id(id).exec(conn).await
     }
 }
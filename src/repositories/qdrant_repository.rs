@@ -1,13 +1,19 @@
 use async_trait::async_trait;
 use qdrant_client::{
     qdrant::{
-        vectors_config::Config, CreateCollection, Distance, PointStruct, Value, VectorParams,
-        VectorsConfig,
+        payload_index_params::IndexParams, quantization_config::Quantization,
+        vectors_config::Config, CreateCollection, CreateFieldIndexCollection,
+        Distance as QdrantDistance, FieldType, HnswConfigDiff, IntegerIndexParams,
+        PayloadIndexParams, PayloadSchemaInfo, PayloadSchemaType, PointStruct, QuantizationConfig,
+        QuantizationType, ScalarQuantization, Value, VectorParams, VectorsConfig,
     },
     Qdrant,
 };
 use sea_orm::DbErr;
 use std::collections::HashMap;
+use tracing::{info, warn};
+
+use crate::utils::env::{env_opt, env_or, InvalidEnvVar};
 
 #[async_trait]
 pub trait QdrantRepo: Send + Sync {
@@ -26,23 +32,310 @@ pub trait QdrantRepo: Send + Sync {
     ) -> Result<Vec<i32>, DbErr>;
 
     async fn delete_memo(&self, memo_id: i32) -> Result<(), DbErr>;
+
+    /// 프로젝트의 포인트를 모두 삭제. 프로젝트 ID는 `user_id` 페이로드에 저장되어 있다
+    async fn delete_by_project(&self, project_id: i32) -> Result<(), DbErr>;
+
+    /// 저장된 포인트의 메모 ID를 `after` 다음부터 오름차순으로 최대 `limit`개 조회.
+    /// `user_id`를 주면 해당 소유자의 포인트만 조회한다
+    async fn scroll_memo_ids(
+        &self,
+        user_id: Option<i32>,
+        after: Option<i32>,
+        limit: u64,
+    ) -> Result<Vec<i32>, DbErr>;
+
+    /// 활성 컬렉션의 벡터 형식이 이 서버의 임베더와 다르면 그 이유를 반환.
+    /// 재인덱싱으로 별칭이 옮겨진 뒤 아직 이전 모델로 실행 중인 서버는
+    /// 이 값을 보고 쓰기와 검색을 멈춘다
+    async fn format_mismatch(&self) -> Result<Option<String>, DbErr> {
+        Ok(None)
+    }
+}
+
+/// 활성 컬렉션이 없을 때 처음 만드는 컬렉션. 재인덱싱 도입 전부터 Qdrant가 사용하던 컬렉션이기도 하다
+pub(crate) const DEFAULT_COLLECTION: &str = "memo_embeddings";
+
+/// 검색과 쓰기에 사용하는 별칭. 재인덱싱이 끝나면 새 컬렉션으로 옮겨진다
+const ACTIVE_ALIAS: &str = "memo_embeddings_active";
+
+/// 컬렉션 메타데이터에 임베딩 모델을 기록하는 키
+const MODEL_METADATA_KEY: &str = "embedding_model";
+
+/// 검색, 삭제, 대조 작업이 필터로 쓰는 페이로드 필드.
+/// 인덱스가 없으면 필터가 모든 포인트를 훑으므로 컬렉션이 커질수록 느려진다
+const PAYLOAD_INDEXES: &[(&str, FieldType)] = &[("user_id", FieldType::Integer)];
+
+/// 새로 만드는 컬렉션의 저장 방식. 기존 컬렉션에는 적용되지 않으므로
+/// 바꾼 설정은 `just reindex`로 새 컬렉션을 만들 때 반영된다
+///
+/// - `QDRANT_HNSW_M`, `QDRANT_HNSW_EF_CONSTRUCT`: HNSW 그래프 파라미터. 없으면 Qdrant 기본값
+/// - `QDRANT_ON_DISK`: 벡터, HNSW 그래프, 페이로드와 페이로드 인덱스를 디스크에 저장 (기본값 false)
+/// - `QDRANT_SCALAR_QUANTIZATION`: 벡터를 int8로 양자화해 메모리에 두고 검색 (기본값 false)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QdrantConfig {
+    pub hnsw_m: Option<u64>,
+    pub hnsw_ef_construct: Option<u64>,
+    pub on_disk: bool,
+    pub scalar_quantization: bool,
+}
+
+impl QdrantConfig {
+    pub fn from_env() -> Result<Self, InvalidEnvVar> {
+        Ok(Self {
+            hnsw_m: env_opt("QDRANT_HNSW_M")?,
+            hnsw_ef_construct: env_opt("QDRANT_HNSW_EF_CONSTRUCT")?,
+            on_disk: env_or("QDRANT_ON_DISK", false)?,
+            scalar_quantization: env_or("QDRANT_SCALAR_QUANTIZATION", false)?,
+        })
+    }
+
+    fn create_collection(&self, name: &str, spec: &VectorSpec) -> CreateCollection {
+        let on_disk = self.on_disk.then_some(true);
+
+        CreateCollection {
+            collection_name: name.to_string(),
+            vectors_config: Some(VectorsConfig {
+                config: Some(Config::Params(VectorParams {
+                    size: spec.dimension,
+                    distance: QdrantDistance::Cosine.into(),
+                    on_disk,
+                    ..Default::default()
+                })),
+            }),
+            hnsw_config: Some(HnswConfigDiff {
+                m: self.hnsw_m,
+                ef_construct: self.hnsw_ef_construct,
+                on_disk,
+                ..Default::default()
+            }),
+            on_disk_payload: on_disk,
+            // 원본 벡터가 디스크에 있어도 양자화된 벡터는 메모리에 두고 검색한다
+            quantization_config: self.scalar_quantization.then(|| QuantizationConfig {
+                quantization: Some(Quantization::Scalar(ScalarQuantization {
+                    r#type: QuantizationType::Int8.into(),
+                    quantile: None,
+                    always_ram: Some(true),
+                })),
+            }),
+            metadata: HashMap::from([(
+                MODEL_METADATA_KEY.to_string(),
+                spec.model_id.as_str().into(),
+            )]),
+            ..Default::default()
+        }
+    }
+
+    fn payload_index_params(&self, field_type: FieldType) -> Option<PayloadIndexParams> {
+        let on_disk = self.on_disk.then_some(true);
+        let index_params = match field_type {
+            // 정확히 일치하는지만 비교하므로 범위 검색용 인덱스는 만들지 않는다
+            FieldType::Integer => IndexParams::IntegerIndexParams(IntegerIndexParams {
+                lookup: Some(true),
+                range: Some(false),
+                on_disk,
+                ..Default::default()
+            }),
+            _ => return None,
+        };
+
+        Some(PayloadIndexParams {
+            index_params: Some(index_params),
+        })
+    }
+}
+
+/// 페이로드 인덱스 생성 요청의 필드 타입과 컬렉션 정보에 나오는 스키마 타입은 번호가 다르다
+fn schema_type(field_type: FieldType) -> PayloadSchemaType {
+    match field_type {
+        FieldType::Keyword => PayloadSchemaType::Keyword,
+        FieldType::Integer => PayloadSchemaType::Integer,
+        FieldType::Float => PayloadSchemaType::Float,
+        FieldType::Geo => PayloadSchemaType::Geo,
+        FieldType::Text => PayloadSchemaType::Text,
+        FieldType::Bool => PayloadSchemaType::Bool,
+        FieldType::Datetime => PayloadSchemaType::Datetime,
+        FieldType::Uuid => PayloadSchemaType::Uuid,
+    }
+}
+
+/// `PAYLOAD_INDEXES` 중 컬렉션에 아직 없는 인덱스.
+/// 같은 필드에 다른 타입의 인덱스가 있으면 경고만 남기고 그대로 둔다
+fn missing_payload_indexes(
+    collection: &str,
+    schema: &HashMap<String, PayloadSchemaInfo>,
+) -> Vec<(&'static str, FieldType)> {
+    PAYLOAD_INDEXES
+        .iter()
+        .copied()
+        .filter(|(field, field_type)| match schema.get(*field) {
+            None => true,
+            Some(info) => {
+                if info.data_type != i32::from(schema_type(*field_type)) {
+                    warn!(
+                        "Payload index on '{}' in collection '{}' is not a {} index; \
+                         filtering on it may be slow",
+                        field,
+                        collection,
+                        schema_type(*field_type).as_str_name()
+                    );
+                }
+                false
+            }
+        })
+        .collect()
+}
+
+/// 컬렉션이 벡터를 비교하는 거리 함수. 모든 백엔드가 공유하는 형식이며
+/// Qdrant 클라이언트 타입과는 Qdrant 저장소 안에서만 변환한다
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distance {
+    Cosine,
+    Euclid,
+    Dot,
+    Manhattan,
+    Unknown,
+}
+
+impl Distance {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cosine => "Cosine",
+            Self::Euclid => "Euclid",
+            Self::Dot => "Dot",
+            Self::Manhattan => "Manhattan",
+            Self::Unknown => "Unknown",
+        }
+    }
+}
+
+impl From<QdrantDistance> for Distance {
+    fn from(distance: QdrantDistance) -> Self {
+        match distance {
+            QdrantDistance::Cosine => Self::Cosine,
+            QdrantDistance::Euclid => Self::Euclid,
+            QdrantDistance::Dot => Self::Dot,
+            QdrantDistance::Manhattan => Self::Manhattan,
+            QdrantDistance::UnknownDistance => Self::Unknown,
+        }
+    }
+}
+
+/// 컬렉션에 저장할 벡터 형식. 설정된 임베더의 모델과 차원으로 만든다
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VectorSpec {
+    pub model_id: String,
+    pub dimension: u64,
+}
+
+impl VectorSpec {
+    pub fn new(model_id: impl Into<String>, dimension: usize) -> Self {
+        Self {
+            model_id: model_id.into(),
+            dimension: dimension as u64,
+        }
+    }
+
+    /// 기존 컬렉션의 벡터 크기와 거리 함수가 이 형식과 맞는지 확인
+    pub(crate) fn check(
+        &self,
+        collection: &str,
+        size: u64,
+        distance: Distance,
+    ) -> Result<(), String> {
+        if size == self.dimension && distance == Distance::Cosine {
+            return Ok(());
+        }
+
+        Err(format!(
+            "Vector collection '{}' stores {}-dimensional {} vectors, but embedding model '{}' \
+             produces {}-dimensional Cosine vectors. Rebuild the index for the new model with \
+             `just reindex`, or configure the embedding provider the collection was built with",
+            collection,
+            size,
+            distance.as_str(),
+            self.model_id,
+            self.dimension
+        ))
+    }
+
+    /// 같은 차원의 다른 모델로 만든 컬렉션은 저장과 검색은 되지만 서로 다른 모델의 벡터를 비교하게 되므로 경고만 남긴다
+    pub(crate) fn warn_if_other_model(&self, collection: &str, model_id: &str) {
+        if model_id != self.model_id {
+            warn!(
+                "Vector collection '{}' was built with '{}' but the embedder is '{}'; \
+                 run `just reindex` so search compares vectors from the same model",
+                collection, model_id, self.model_id
+            );
+        }
+    }
+}
+
+/// 버전별 컬렉션에 하나씩 저장하는 메모 벡터
+#[derive(Debug, Clone)]
+pub struct MemoPoint {
+    pub memo_id: i32,
+    pub project_id: i32,
+    pub vector: Vec<f32>,
+}
+
+/// 버전별 컬렉션과 검색 별칭 관리 (무중단 재인덱싱용)
+#[async_trait]
+pub trait VectorCollections: Send + Sync {
+    /// 별칭이 현재 가리키는 컬렉션
+    async fn active_collection(&self) -> Result<Option<String>, DbErr>;
+
+    async fn list_collections(&self) -> Result<Vec<String>, DbErr>;
+
+    /// 컬렉션이 없으면 `spec` 형식으로 생성하고 메타데이터에 임베딩 모델을 기록. 이미 있으면 그대로 둔다.
+    /// 필터에 쓰는 필드의 인덱스가 필요한 백엔드는 없는 인덱스를 함께 만든다
+    async fn create_collection(&self, name: &str, spec: &VectorSpec) -> Result<(), DbErr>;
+
+    async fn upsert_into(&self, collection: &str, points: Vec<MemoPoint>) -> Result<(), DbErr>;
+
+    /// 별칭을 `collection`으로 원자적으로 옮긴다. 이전 컬렉션은 삭제하지 않는다
+    async fn switch_alias(&self, collection: &str) -> Result<(), DbErr>;
+
+    /// 검색 별칭이 가리키는 컬렉션의 벡터 형식이 임베더와 다르면 그 이유를 반환
+    async fn active_format_mismatch(&self) -> Result<Option<String>, DbErr> {
+        Ok(None)
+    }
+
+    /// 검색 별칭이 가리키는 컬렉션의 벡터 형식이 임베더와 맞는지 확인.
+    /// 맞지 않으면 모든 저장과 검색이 실패하므로 서버 시작 시 호출하여 시작을 거부한다
+    async fn validate_collection(&self) -> Result<(), DbErr> {
+        match self.active_format_mismatch().await? {
+            Some(reason) => Err(DbErr::Custom(reason)),
+            None => Ok(()),
+        }
+    }
 }
 
 #[derive(Clone)]
 pub struct QdrantRepository {
     client: Qdrant,
     collection_name: String,
+    spec: VectorSpec,
+    config: QdrantConfig,
 }
 
 impl QdrantRepository {
-    pub async fn new(qdrant_url: String) -> Result<Self, DbErr> {
+    /// 검색 별칭이 없으면 `spec` 형식의 컬렉션을 만들어 연결한다.
+    /// 기존 컬렉션이 `spec`과 맞는지는 [`VectorCollections::validate_collection`]으로 확인한다
+    pub async fn new(
+        qdrant_url: String,
+        spec: VectorSpec,
+        config: QdrantConfig,
+    ) -> Result<Self, DbErr> {
         let client = Qdrant::from_url(&qdrant_url)
             .build()
             .map_err(|e| DbErr::Custom(format!("Failed to create Qdrant client: {}", e)))?;
 
         let repo = Self {
             client,
-            collection_name: "memo_embeddings".to_string(),
+            collection_name: ACTIVE_ALIAS.to_string(),
+            spec,
+            config,
         };
 
         repo.ensure_collection().await?;
@@ -50,37 +343,190 @@ impl QdrantRepository {
         Ok(repo)
     }
 
+    /// 별칭이 없으면 기존 컬렉션(없으면 새로 생성)을 가리키는 별칭 생성.
+    /// 별칭이 가리키는 컬렉션에 없는 페이로드 인덱스도 만든다
     pub async fn ensure_collection(&self) -> Result<(), DbErr> {
+        match self.active_collection().await? {
+            Some(collection) => self.ensure_payload_indexes(&collection).await,
+            None => {
+                self.create_collection(DEFAULT_COLLECTION, &self.spec)
+                    .await?;
+                self.switch_alias(DEFAULT_COLLECTION).await
+            }
+        }
+    }
+
+    /// 필터에 쓰는 페이로드 필드 중 인덱스가 없는 필드만 인덱스 생성. 이미 있으면 아무것도 하지 않는다
+    async fn ensure_payload_indexes(&self, collection: &str) -> Result<(), DbErr> {
+        let info = self
+            .client
+            .collection_info(collection)
+            .await
+            .map_err(|e| DbErr::Custom(format!("Failed to get collection info: {}", e)))?;
+        let schema = info
+            .result
+            .map(|info| info.payload_schema)
+            .unwrap_or_default();
+
+        for (field, field_type) in missing_payload_indexes(collection, &schema) {
+            self.client
+                .create_field_index(CreateFieldIndexCollection {
+                    collection_name: collection.to_string(),
+                    wait: Some(true),
+                    field_name: field.to_string(),
+                    field_type: Some(field_type.into()),
+                    field_index_params: self.config.payload_index_params(field_type),
+                    ordering: None,
+                })
+                .await
+                .map_err(|e| DbErr::Custom(format!("Failed to create payload index: {}", e)))?;
+            info!(
+                "Created {} payload index on '{}' in collection '{}'",
+                field_type.as_str_name(),
+                field,
+                collection
+            );
+        }
+
+        Ok(())
+    }
+}
+
+fn memo_point(memo_id: i32, user_id: i32, vector: Vec<f32>) -> PointStruct {
+    let mut payload: HashMap<String, Value> = HashMap::new();
+    payload.insert("user_id".to_string(), (user_id as i64).into());
+    payload.insert("memo_id".to_string(), (memo_id as i64).into());
+
+    PointStruct::new(memo_id as u64, vector, payload)
+}
+
+#[async_trait]
+impl VectorCollections for QdrantRepository {
+    async fn active_collection(&self) -> Result<Option<String>, DbErr> {
+        let aliases = self
+            .client
+            .list_aliases()
+            .await
+            .map_err(|e| DbErr::Custom(format!("Failed to list aliases: {}", e)))?;
+
+        Ok(aliases
+            .aliases
+            .into_iter()
+            .find(|alias| alias.alias_name == self.collection_name)
+            .map(|alias| alias.collection_name))
+    }
+
+    async fn list_collections(&self) -> Result<Vec<String>, DbErr> {
         let collections = self
             .client
             .list_collections()
             .await
             .map_err(|e| DbErr::Custom(format!("Failed to list collections: {}", e)))?;
 
-        let collection_exists = collections
+        Ok(collections
             .collections
-            .iter()
-            .any(|c| c.name == self.collection_name);
+            .into_iter()
+            .map(|c| c.name)
+            .collect())
+    }
+
+    async fn create_collection(&self, name: &str, spec: &VectorSpec) -> Result<(), DbErr> {
+        let collection_exists = self
+            .client
+            .collection_exists(name)
+            .await
+            .map_err(|e| DbErr::Custom(format!("Failed to check collection: {}", e)))?;
 
         if !collection_exists {
             self.client
-                .create_collection(CreateCollection {
-                    collection_name: self.collection_name.clone(),
-                    vectors_config: Some(VectorsConfig {
-                        config: Some(Config::Params(VectorParams {
-                            size: 768,
-                            distance: Distance::Cosine.into(),
-                            ..Default::default()
-                        })),
-                    }),
-                    ..Default::default()
-                })
+                .create_collection(self.config.create_collection(name, spec))
                 .await
                 .map_err(|e| DbErr::Custom(format!("Failed to create collection: {}", e)))?;
         }
 
+        // 중단된 재인덱싱이 남긴 컬렉션에도 인덱스가 빠지지 않도록 매번 확인한다
+        self.ensure_payload_indexes(name).await
+    }
+
+    async fn upsert_into(&self, collection: &str, points: Vec<MemoPoint>) -> Result<(), DbErr> {
+        use qdrant_client::qdrant::UpsertPoints;
+
+        if points.is_empty() {
+            return Ok(());
+        }
+
+        let points = points
+            .into_iter()
+            .map(|p| memo_point(p.memo_id, p.project_id, p.vector))
+            .collect();
+
+        self.client
+            .upsert_points(UpsertPoints {
+                collection_name: collection.to_string(),
+                points,
+                wait: Some(true),
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| DbErr::Custom(format!("Failed to upsert memos: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn switch_alias(&self, collection: &str) -> Result<(), DbErr> {
+        use qdrant_client::qdrant::CreateAlias;
+
+        // 같은 이름의 별칭을 다시 만들면 하나의 연산으로 대상 컬렉션이 바뀐다
+        self.client
+            .create_alias(CreateAlias {
+                collection_name: collection.to_string(),
+                alias_name: self.collection_name.clone(),
+            })
+            .await
+            .map_err(|e| DbErr::Custom(format!("Failed to switch alias: {}", e)))?;
+
         Ok(())
     }
+
+    async fn active_format_mismatch(&self) -> Result<Option<String>, DbErr> {
+        let info = self
+            .client
+            .collection_info(&self.collection_name)
+            .await
+            .map_err(|e| DbErr::Custom(format!("Failed to get collection info: {}", e)))?;
+        let config = info.result.and_then(|info| info.config).unwrap_or_default();
+        let collection = self
+            .active_collection()
+            .await?
+            .unwrap_or_else(|| self.collection_name.clone());
+
+        let params = config
+            .params
+            .and_then(|params| params.vectors_config)
+            .and_then(|vectors| vectors.config);
+        let Some(Config::Params(params)) = params else {
+            return Ok(Some(format!(
+                "Vector collection '{}' does not use a single unnamed vector",
+                collection
+            )));
+        };
+        let distance = QdrantDistance::try_from(params.distance)
+            .map(Distance::from)
+            .unwrap_or(Distance::Unknown);
+        if let Err(reason) = self.spec.check(&collection, params.size, distance) {
+            return Ok(Some(reason));
+        }
+
+        if let Some(model_id) = config
+            .metadata
+            .get(MODEL_METADATA_KEY)
+            .and_then(|v| v.as_str())
+        {
+            self.spec.warn_if_other_model(&collection, model_id);
+        }
+
+        Ok(None)
+    }
 }
 
 #[async_trait]
@@ -93,16 +539,11 @@ impl QdrantRepo for QdrantRepository {
     ) -> Result<(), DbErr> {
         use qdrant_client::qdrant::UpsertPoints;
 
-        let mut payload: HashMap<String, Value> = HashMap::new();
-        payload.insert("user_id".to_string(), (user_id as i64).into());
-        payload.insert("memo_id".to_string(), (memo_id as i64).into());
-
-        let point = PointStruct::new(memo_id as u64, vector, payload);
-
         self.client
             .upsert_points(UpsertPoints {
                 collection_name: self.collection_name.clone(),
-                points: vec![point],
+                points: vec![memo_point(memo_id, user_id, vector)],
+                wait: Some(true),
                 ..Default::default()
             })
             .await
@@ -163,6 +604,7 @@ impl QdrantRepo for QdrantRepository {
                         ids: vec![(memo_id as u64).into()],
                     })),
                 }),
+                wait: Some(true),
                 ..Default::default()
             })
             .await
@@ -170,4 +612,157 @@ impl QdrantRepo for QdrantRepository {
 
         Ok(())
     }
+
+    async fn delete_by_project(&self, project_id: i32) -> Result<(), DbErr> {
+        use qdrant_client::qdrant::{
+            points_selector::PointsSelectorOneOf, Condition, DeletePoints, Filter, PointsSelector,
+        };
+
+        self.client
+            .delete_points(DeletePoints {
+                collection_name: self.collection_name.clone(),
+                points: Some(PointsSelector {
+                    points_selector_one_of: Some(PointsSelectorOneOf::Filter(Filter::must([
+                        Condition::matches("user_id", project_id as i64),
+                    ]))),
+                }),
+                wait: Some(true),
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| DbErr::Custom(format!("Failed to delete project memos: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn scroll_memo_ids(
+        &self,
+        user_id: Option<i32>,
+        after: Option<i32>,
+        limit: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        use qdrant_client::qdrant::{point_id::PointIdOptions, Condition, Filter, ScrollPoints};
+
+        let scroll_result = self
+            .client
+            .scroll(ScrollPoints {
+                collection_name: self.collection_name.clone(),
+                filter: user_id.map(|id| Filter::must([Condition::matches("user_id", id as i64)])),
+                // offset은 해당 ID부터 포함하므로 다음 ID부터 조회
+                offset: after.map(|id| (id as u64 + 1).into()),
+                limit: Some(limit as u32),
+                with_payload: Some(false.into()),
+                with_vectors: Some(false.into()),
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| DbErr::Custom(format!("Failed to scroll memo points: {}", e)))?;
+
+        let memo_ids = scroll_result
+            .result
+            .into_iter()
+            .filter_map(|point| match point.id?.point_id_options? {
+                PointIdOptions::Num(id) => i32::try_from(id).ok(),
+                PointIdOptions::Uuid(_) => None,
+            })
+            .collect();
+
+        Ok(memo_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_spec_rejects_mismatched_collection() {
+        let spec = VectorSpec::new("text-embedding-3-small", 1536);
+
+        assert!(spec
+            .check("memo_embeddings", 1536, Distance::Cosine)
+            .is_ok());
+
+        let message = spec
+            .check("memo_embeddings", 768, Distance::Cosine)
+            .unwrap_err();
+        assert!(message.contains("'memo_embeddings' stores 768-dimensional"));
+        assert!(message.contains("'text-embedding-3-small'"));
+        assert!(message.contains("just reindex"));
+
+        assert!(spec.check("memo_embeddings", 1536, Distance::Dot).is_err());
+    }
+
+    #[test]
+    fn test_qdrant_config_applies_to_new_collections() {
+        let spec = VectorSpec::new("text-embedding-3-small", 1536);
+
+        let request = QdrantConfig::default().create_collection("memo_embeddings_v2", &spec);
+        assert_eq!(request.collection_name, "memo_embeddings_v2");
+        assert_eq!(request.on_disk_payload, None);
+        assert!(request.quantization_config.is_none());
+        assert_eq!(
+            request
+                .metadata
+                .get(MODEL_METADATA_KEY)
+                .and_then(|v| v.as_str()),
+            Some(&spec.model_id)
+        );
+
+        let config = QdrantConfig {
+            hnsw_m: Some(32),
+            hnsw_ef_construct: Some(200),
+            on_disk: true,
+            scalar_quantization: true,
+        };
+        let request = config.create_collection("memo_embeddings_v2", &spec);
+        let hnsw = request.hnsw_config.unwrap();
+        assert_eq!(
+            (hnsw.m, hnsw.ef_construct, hnsw.on_disk),
+            (Some(32), Some(200), Some(true))
+        );
+        assert_eq!(request.on_disk_payload, Some(true));
+        let Some(Config::Params(params)) = request.vectors_config.and_then(|v| v.config) else {
+            panic!("expected a single unnamed vector");
+        };
+        assert_eq!((params.size, params.on_disk), (1536, Some(true)));
+        let Some(Quantization::Scalar(scalar)) =
+            request.quantization_config.and_then(|q| q.quantization)
+        else {
+            panic!("expected scalar quantization");
+        };
+        assert_eq!(scalar.r#type, i32::from(QuantizationType::Int8));
+        assert_eq!(scalar.always_ram, Some(true));
+    }
+
+    #[test]
+    fn test_payload_indexes_are_created_only_when_missing() {
+        let mut schema = HashMap::new();
+        assert_eq!(
+            missing_payload_indexes("memo_embeddings", &schema),
+            vec![("user_id", FieldType::Integer)]
+        );
+
+        schema.insert(
+            "user_id".to_string(),
+            PayloadSchemaInfo {
+                data_type: PayloadSchemaType::Integer.into(),
+                params: None,
+                points: Some(10),
+            },
+        );
+        assert!(missing_payload_indexes("memo_embeddings", &schema).is_empty());
+
+        // 다른 타입의 인덱스가 있어도 다시 만들지 않는다
+        schema.get_mut("user_id").unwrap().data_type = PayloadSchemaType::Keyword.into();
+        assert!(missing_payload_indexes("memo_embeddings", &schema).is_empty());
+
+        let params = QdrantConfig::default()
+            .payload_index_params(FieldType::Integer)
+            .and_then(|p| p.index_params);
+        let Some(IndexParams::IntegerIndexParams(params)) = params else {
+            panic!("expected integer index params");
+        };
+        assert_eq!((params.lookup, params.range), (Some(true), Some(false)));
+    }
 }
@@ -0,0 +1,100 @@
+use chrono::Utc;
+use sea_orm::*;
+use std::sync::Arc;
+
+use crate::entities::vector_reindex_run::{self, Entity as VectorReindexRun, ReindexStatus};
+
+#[derive(Clone)]
+pub struct ReindexRunRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl ReindexRunRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    /// 최근 실행부터
+    pub async fn find_all(&self) -> Result<Vec<vector_reindex_run::Model>, DbErr> {
+        VectorReindexRun::find()
+            .order_by_desc(vector_reindex_run::Column::Id)
+            .all(self.db.as_ref())
+            .await
+    }
+
+    /// 중단되어 이어서 처리할 실행
+    pub async fn find_running(&self) -> Result<Option<vector_reindex_run::Model>, DbErr> {
+        VectorReindexRun::find()
+            .filter(vector_reindex_run::Column::Status.eq(ReindexStatus::Running))
+            .order_by_desc(vector_reindex_run::Column::Id)
+            .one(self.db.as_ref())
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        collection_name: String,
+        model_id: String,
+        dimension: i32,
+        total: i32,
+    ) -> Result<vector_reindex_run::Model, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let active_model = vector_reindex_run::ActiveModel {
+            collection_name: Set(collection_name),
+            model_id: Set(model_id),
+            dimension: Set(dimension),
+            status: Set(ReindexStatus::Running),
+            last_memo_id: Set(0),
+            processed: Set(0),
+            total: Set(total),
+            started_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        active_model.insert(self.db.as_ref()).await
+    }
+
+    /// 배치 하나를 마칠 때마다 진행 위치 기록
+    pub async fn record_progress(
+        &self,
+        run: vector_reindex_run::Model,
+        last_memo_id: i32,
+        processed: i32,
+    ) -> Result<vector_reindex_run::Model, DbErr> {
+        let mut active_model: vector_reindex_run::ActiveModel = run.into();
+        active_model.last_memo_id = Set(last_memo_id);
+        active_model.processed = Set(processed);
+        active_model.updated_at = Set(Utc::now().naive_utc());
+
+        active_model.update(self.db.as_ref()).await
+    }
+
+    pub async fn set_total(
+        &self,
+        run: vector_reindex_run::Model,
+        total: i32,
+    ) -> Result<vector_reindex_run::Model, DbErr> {
+        let mut active_model: vector_reindex_run::ActiveModel = run.into();
+        active_model.total = Set(total);
+        active_model.updated_at = Set(Utc::now().naive_utc());
+
+        active_model.update(self.db.as_ref()).await
+    }
+
+    pub async fn finish(
+        &self,
+        run: vector_reindex_run::Model,
+        status: ReindexStatus,
+    ) -> Result<vector_reindex_run::Model, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let mut active_model: vector_reindex_run::ActiveModel = run.into();
+        active_model.status = Set(status);
+        active_model.updated_at = Set(now);
+        active_model.completed_at = Set(Some(now));
+
+        active_model.update(self.db.as_ref()).await
+    }
+}
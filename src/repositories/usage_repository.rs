@@ -0,0 +1,121 @@
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use sea_orm::*;
+use std::sync::Arc;
+
+use crate::{
+    clients::{UsageFeature, UsageOperation, UsageRecord},
+    entities::ai_usage::{self, Entity as AiUsage},
+};
+
+/// 하루 동안의 (작업, 모델)별 사용량 합계
+#[derive(Debug, Clone, PartialEq, FromQueryResult)]
+pub struct DailyUsageRow {
+    pub day: NaiveDate,
+    pub operation: String,
+    pub model: String,
+    pub calls: i64,
+    pub items: i64,
+    pub prompt_tokens: i64,
+    pub candidate_tokens: i64,
+    pub total_tokens: i64,
+}
+
+/// 할당량 계산용 기간 합계
+#[derive(Debug, Clone, Copy, Default, PartialEq, FromQueryResult)]
+pub struct UsageTotals {
+    pub calls: i64,
+    pub items: i64,
+    pub total_tokens: i64,
+}
+
+#[derive(Clone)]
+pub struct UsageRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl UsageRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn insert_records(&self, records: &[UsageRecord]) -> Result<(), DbErr> {
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        let now = Utc::now().naive_utc();
+        let models = records.iter().map(|record| ai_usage::ActiveModel {
+            user_id: Set(record.context.user_id),
+            project_id: Set(record.context.project_id),
+            feature: Set(record.context.feature.as_str().to_string()),
+            operation: Set(record.operation.as_str().to_string()),
+            model: Set(record.model.clone()),
+            prompt_tokens: Set(record.tokens.prompt_tokens),
+            candidate_tokens: Set(record.tokens.candidate_tokens),
+            total_tokens: Set(record.tokens.total_tokens),
+            item_count: Set(record.items),
+            created_at: Set(now),
+            ..Default::default()
+        });
+
+        AiUsage::insert_many(models).exec(self.db.as_ref()).await?;
+        Ok(())
+    }
+
+    /// `since` 이후 사용자의 사용량을 날짜, 작업, 모델별로 합산 (최근 날짜 우선)
+    pub async fn find_daily_by_user(
+        &self,
+        user_id: i32,
+        since: NaiveDateTime,
+    ) -> Result<Vec<DailyUsageRow>, DbErr> {
+        DailyUsageRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT DATE(created_at) AS day,
+                      operation,
+                      model,
+                      COUNT(*) AS calls,
+                      SUM(item_count)::BIGINT AS items,
+                      SUM(prompt_tokens)::BIGINT AS prompt_tokens,
+                      SUM(candidate_tokens)::BIGINT AS candidate_tokens,
+                      SUM(total_tokens)::BIGINT AS total_tokens
+               FROM ai_usage
+               WHERE user_id = $1 AND created_at >= $2
+               GROUP BY day, operation, model
+               ORDER BY day DESC, operation, model"#,
+            [user_id.into(), since.into()],
+        ))
+        .all(self.db.as_ref())
+        .await
+    }
+
+    /// `since` 이후 사용자의 사용량 합계. `feature`, `operation`이 None이면 전체를 합산한다
+    pub async fn totals_since(
+        &self,
+        user_id: i32,
+        since: NaiveDateTime,
+        feature: Option<UsageFeature>,
+        operation: Option<UsageOperation>,
+    ) -> Result<UsageTotals, DbErr> {
+        let row = UsageTotals::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT COUNT(*) AS calls,
+                      COALESCE(SUM(item_count), 0)::BIGINT AS items,
+                      COALESCE(SUM(total_tokens), 0)::BIGINT AS total_tokens
+               FROM ai_usage
+               WHERE user_id = $1
+                 AND created_at >= $2
+                 AND ($3::TEXT IS NULL OR feature = $3)
+                 AND ($4::TEXT IS NULL OR operation = $4)"#,
+            [
+                user_id.into(),
+                since.into(),
+                feature.map(|f| f.as_str()).into(),
+                operation.map(|o| o.as_str()).into(),
+            ],
+        ))
+        .one(self.db.as_ref())
+        .await?;
+
+        Ok(row.unwrap_or_default())
+    }
+}
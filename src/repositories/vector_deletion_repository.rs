@@ -0,0 +1,118 @@
+use chrono::{Duration, NaiveDateTime, Utc};
+use sea_orm::{sea_query::Expr, *};
+use std::sync::Arc;
+
+use crate::entities::project_vector_deletion::{self, Entity as ProjectVectorDeletion};
+
+#[derive(Clone)]
+pub struct VectorDeletionRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl VectorDeletionRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn find_by_project_id(
+        &self,
+        project_id: i32,
+    ) -> Result<Option<project_vector_deletion::Model>, DbErr> {
+        ProjectVectorDeletion::find()
+            .filter(project_vector_deletion::Column::ProjectId.eq(project_id))
+            .one(self.db.as_ref())
+            .await
+    }
+
+    /// 프로젝트 벡터 삭제 작업 등록. 프로젝트 삭제와 같은 트랜잭션에서 호출한다
+    pub async fn enqueue<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        project_id: i32,
+    ) -> Result<(), DbErr> {
+        let now = Utc::now().naive_utc();
+
+        conn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"
+            INSERT INTO project_vector_deletions (project_id, attempts, run_after, enqueued_at)
+            VALUES ($1, 0, $2, $2)
+            ON CONFLICT (project_id) DO NOTHING
+            "#,
+            [project_id.into(), now.into()],
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    /// 실행할 때가 된 작업을 최대 `limit`개 가져와 `lease` 뒤로 미룬다.
+    /// 완료하지 못한 작업은 임대가 끝나면 다시 가져간다.
+    /// `project_ids`를 주면 해당 프로젝트의 작업만 가져온다
+    pub async fn claim(
+        &self,
+        project_ids: Option<&[i32]>,
+        limit: u64,
+        lease: Duration,
+    ) -> Result<Vec<project_vector_deletion::Model>, DbErr> {
+        let now = Utc::now().naive_utc();
+        let project_ids: Option<Vec<i32>> = project_ids.map(|ids| ids.to_vec());
+
+        project_vector_deletion::Model::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"
+            UPDATE project_vector_deletions SET
+                attempts = attempts + 1,
+                run_after = $2
+            WHERE id IN (
+                SELECT id FROM project_vector_deletions
+                WHERE run_after <= $1
+                  AND ($4::int[] IS NULL OR project_id = ANY($4))
+                ORDER BY run_after
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING *
+            "#,
+            [
+                now.into(),
+                (now + lease).into(),
+                (limit as i64).into(),
+                project_ids.into(),
+            ],
+        ))
+        .all(self.db.as_ref())
+        .await
+    }
+
+    pub async fn complete(&self, deletion: &project_vector_deletion::Model) -> Result<(), DbErr> {
+        ProjectVectorDeletion::delete_by_id(deletion.id)
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+
+    /// 실패한 작업을 `run_after` 이후에 다시 처리
+    pub async fn reschedule(
+        &self,
+        deletion: &project_vector_deletion::Model,
+        error: &str,
+        run_after: NaiveDateTime,
+    ) -> Result<(), DbErr> {
+        ProjectVectorDeletion::update_many()
+            .col_expr(
+                project_vector_deletion::Column::LastError,
+                Expr::value(error),
+            )
+            .col_expr(
+                project_vector_deletion::Column::RunAfter,
+                Expr::value(run_after),
+            )
+            .filter(project_vector_deletion::Column::Id.eq(deletion.id))
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+}
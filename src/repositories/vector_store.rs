@@ -0,0 +1,440 @@
+use async_trait::async_trait;
+use sea_orm::{DatabaseConnection, DbErr};
+use std::{
+    env::var,
+    fmt,
+    path::PathBuf,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use super::{
+    memory_vector_repository::InMemoryVectorRepository,
+    pgvector_repository::PgVectorRepository,
+    qdrant_repository::{
+        QdrantConfig, QdrantRepo, QdrantRepository, VectorCollections, VectorSpec,
+    },
+};
+use crate::utils::env::{env_or, InvalidEnvVar};
+
+/// 메모 벡터를 저장하는 백엔드
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorBackend {
+    Qdrant,
+    /// Postgres의 pgvector 확장. 별도 벡터 DB 없이 운영하는 소규모 배포용
+    PgVector,
+    /// 프로세스 내 메모리 저장소. 개발용이나 메모가 많지 않은 소규모 설치용
+    Memory,
+}
+
+impl fmt::Display for VectorBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Qdrant => write!(f, "qdrant"),
+            Self::PgVector => write!(f, "pgvector"),
+            Self::Memory => write!(f, "memory"),
+        }
+    }
+}
+
+impl FromStr for VectorBackend {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "qdrant" => Ok(Self::Qdrant),
+            "pgvector" => Ok(Self::PgVector),
+            "memory" => Ok(Self::Memory),
+            _ => Err(()),
+        }
+    }
+}
+
+/// 벡터 저장소 설정
+///
+/// - `VECTOR_STORE`: `qdrant`(기본값), `pgvector` 또는 `memory`
+/// - `QDRANT_URL`: Qdrant를 사용할 때 필수. 컬렉션 저장 방식은 [`QdrantConfig`] 참고
+/// - `VECTOR_SNAPSHOT_PATH`: 메모리 저장소의 스냅샷 파일. 없으면 재시작 시 벡터를 다시 만든다
+#[derive(Debug, Clone)]
+pub struct VectorStoreConfig {
+    pub backend: VectorBackend,
+    pub qdrant_url: Option<String>,
+    pub qdrant: QdrantConfig,
+    pub snapshot_path: Option<PathBuf>,
+}
+
+impl VectorStoreConfig {
+    pub fn from_env() -> Result<Self, InvalidEnvVar> {
+        Ok(Self {
+            backend: env_or("VECTOR_STORE", VectorBackend::Qdrant)?,
+            qdrant_url: var("QDRANT_URL").ok().filter(|url| !url.trim().is_empty()),
+            qdrant: QdrantConfig::from_env()?,
+            snapshot_path: var("VECTOR_SNAPSHOT_PATH")
+                .ok()
+                .filter(|path| !path.trim().is_empty())
+                .map(PathBuf::from),
+        })
+    }
+
+    /// 스냅샷 없는 메모리 저장소처럼 재시작하면 빈 상태로 시작하는 백엔드인지
+    pub fn loses_vectors_on_restart(&self) -> bool {
+        self.backend == VectorBackend::Memory && self.snapshot_path.is_none()
+    }
+}
+
+/// 설정된 백엔드의 벡터 저장소. 같은 구현을 검색/저장과 컬렉션 관리 두 인터페이스로 제공한다
+#[derive(Clone)]
+pub struct VectorStore {
+    pub repo: Arc<dyn QdrantRepo>,
+    pub collections: Arc<dyn VectorCollections>,
+}
+
+impl VectorStore {
+    /// 백엔드에 연결하고 활성 컬렉션이 없으면 `spec` 형식으로 만든다
+    pub async fn connect(
+        config: &VectorStoreConfig,
+        db: Arc<DatabaseConnection>,
+        spec: VectorSpec,
+    ) -> Result<Self, DbErr> {
+        match config.backend {
+            VectorBackend::Qdrant => {
+                let qdrant_url = config.qdrant_url.clone().ok_or_else(|| {
+                    DbErr::Custom("QDRANT_URL must be set when VECTOR_STORE=qdrant".to_string())
+                })?;
+                Ok(Self::from_repo(
+                    QdrantRepository::new(qdrant_url, spec, config.qdrant.clone()).await?,
+                ))
+            }
+            VectorBackend::PgVector => {
+                Ok(Self::from_repo(PgVectorRepository::new(db, spec).await?))
+            }
+            VectorBackend::Memory => match &config.snapshot_path {
+                Some(path) => Ok(Self::from_repo(
+                    InMemoryVectorRepository::open(path, spec).await?,
+                )),
+                None => Ok(Self::from_repo(InMemoryVectorRepository::new(spec))),
+            },
+        }
+    }
+
+    pub fn from_repo<T>(repo: T) -> Self
+    where
+        T: QdrantRepo + VectorCollections + 'static,
+    {
+        let repo = Arc::new(repo);
+        Self {
+            repo: Arc::new(FormatChecked::new(repo.clone())),
+            collections: repo,
+        }
+    }
+}
+
+/// 다른 서버가 재인덱싱으로 별칭을 옮긴 것을 알아차릴 때까지 걸리는 최대 시간
+const FORMAT_CHECK_TTL: Duration = Duration::from_secs(30);
+
+/// 활성 컬렉션의 형식 확인 결과를 잠시 보관하는 저장소.
+/// 쓰기나 검색이 실패하면 별칭이 옮겨졌을 수 있으므로 보관한 결과를 버린다
+struct FormatChecked<T> {
+    inner: Arc<T>,
+    checked: Mutex<Option<(Instant, Option<String>)>>,
+}
+
+impl<T> FormatChecked<T> {
+    fn new(inner: Arc<T>) -> Self {
+        Self {
+            inner,
+            checked: Mutex::new(None),
+        }
+    }
+
+    fn invalidate_on_error<R>(&self, result: Result<R, DbErr>) -> Result<R, DbErr> {
+        if result.is_err() {
+            *self.checked.lock().unwrap() = None;
+        }
+        result
+    }
+}
+
+#[async_trait]
+impl<T> QdrantRepo for FormatChecked<T>
+where
+    T: QdrantRepo + VectorCollections + 'static,
+{
+    async fn upsert_memo(&self, memo_id: i32, user_id: i32, vector: Vec<f32>) -> Result<(), DbErr> {
+        let result = self.inner.upsert_memo(memo_id, user_id, vector).await;
+        self.invalidate_on_error(result)
+    }
+
+    async fn search_similar(
+        &self,
+        user_id: i32,
+        query_vector: Vec<f32>,
+        limit: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        let result = self
+            .inner
+            .search_similar(user_id, query_vector, limit)
+            .await;
+        self.invalidate_on_error(result)
+    }
+
+    async fn delete_memo(&self, memo_id: i32) -> Result<(), DbErr> {
+        self.inner.delete_memo(memo_id).await
+    }
+
+    async fn delete_by_project(&self, project_id: i32) -> Result<(), DbErr> {
+        self.inner.delete_by_project(project_id).await
+    }
+
+    async fn scroll_memo_ids(
+        &self,
+        user_id: Option<i32>,
+        after: Option<i32>,
+        limit: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        self.inner.scroll_memo_ids(user_id, after, limit).await
+    }
+
+    async fn format_mismatch(&self) -> Result<Option<String>, DbErr> {
+        if let Some((at, mismatch)) = self.checked.lock().unwrap().as_ref() {
+            if at.elapsed() < FORMAT_CHECK_TTL {
+                return Ok(mismatch.clone());
+            }
+        }
+
+        let mismatch = self.inner.active_format_mismatch().await?;
+        *self.checked.lock().unwrap() = Some((Instant::now(), mismatch.clone()));
+        Ok(mismatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db;
+    use rand::Rng;
+
+    const DIMENSION: usize = 4;
+
+    /// 모든 백엔드가 만족해야 하는 `QdrantRepo` 동작
+    async fn assert_vector_store_contract(store: &dyn QdrantRepo) {
+        // 다른 테스트나 기존 데이터와 겹치지 않는 메모/프로젝트 ID
+        let base = rand::thread_rng().gen_range(1_000_000..1_000_000_000);
+        let (project, other_project) = (base, base + 1);
+        let (close, closer, far, elsewhere) = (base, base + 1, base + 2, base + 3);
+
+        store
+            .upsert_memo(close, project, vec![0.8, 0.6, 0.0, 0.0])
+            .await
+            .unwrap();
+        store
+            .upsert_memo(closer, project, vec![1.0, 0.1, 0.0, 0.0])
+            .await
+            .unwrap();
+        store
+            .upsert_memo(far, project, vec![0.0, 0.0, 1.0, 0.0])
+            .await
+            .unwrap();
+        store
+            .upsert_memo(elsewhere, other_project, vec![1.0, 0.0, 0.0, 0.0])
+            .await
+            .unwrap();
+
+        // 코사인 유사도 순으로, 같은 프로젝트의 메모만
+        let query = vec![1.0, 0.0, 0.0, 0.0];
+        let results = store
+            .search_similar(project, query.clone(), 2)
+            .await
+            .unwrap();
+        assert_eq!(results, vec![closer, close]);
+        let results = store
+            .search_similar(other_project, query.clone(), 10)
+            .await
+            .unwrap();
+        assert_eq!(results, vec![elsewhere]);
+
+        // 같은 메모를 다시 저장하면 벡터를 덮어쓴다
+        store
+            .upsert_memo(closer, project, vec![0.0, 0.0, 0.0, 1.0])
+            .await
+            .unwrap();
+        let results = store
+            .search_similar(project, query.clone(), 1)
+            .await
+            .unwrap();
+        assert_eq!(results, vec![close]);
+
+        store.delete_memo(far).await.unwrap();
+        let mut results = store
+            .search_similar(project, query.clone(), 10)
+            .await
+            .unwrap();
+        results.sort();
+        assert_eq!(results, vec![close, closer]);
+
+        // 프로젝트별 ID 순 페이지 조회
+        let page = store.scroll_memo_ids(Some(project), None, 1).await.unwrap();
+        assert_eq!(page, vec![close]);
+        let page = store
+            .scroll_memo_ids(Some(project), Some(close), 10)
+            .await
+            .unwrap();
+        assert_eq!(page, vec![closer]);
+        let all = store
+            .scroll_memo_ids(None, Some(close - 1), 3)
+            .await
+            .unwrap();
+        assert_eq!(all, vec![close, closer, elsewhere]);
+
+        // 프로젝트 단위 삭제는 다른 프로젝트의 포인트를 남긴다
+        store.delete_by_project(project).await.unwrap();
+        let page = store
+            .scroll_memo_ids(Some(project), None, 10)
+            .await
+            .unwrap();
+        assert!(page.is_empty());
+        let page = store
+            .scroll_memo_ids(Some(other_project), None, 10)
+            .await
+            .unwrap();
+        assert_eq!(page, vec![elsewhere]);
+
+        store.delete_memo(elsewhere).await.unwrap();
+        let page = store
+            .scroll_memo_ids(Some(other_project), None, 10)
+            .await
+            .unwrap();
+        assert!(page.is_empty());
+    }
+
+    /// 다른 프로젝트에 더 가까운 벡터가 많아도 프로젝트 안에서 `limit`개를 모두 찾아야 한다
+    async fn assert_filtered_search_recall(store: &dyn QdrantRepo) {
+        let base = rand::thread_rng().gen_range(1_000_000..1_000_000_000);
+        let (project, crowd) = (base, base + 1);
+
+        for i in 0..500 {
+            let jitter = i as f32 / 1000.0;
+            store
+                .upsert_memo(base + 10 + i, crowd, vec![1.0, jitter, 0.0, 0.0])
+                .await
+                .unwrap();
+        }
+        let own = [base + 1, base + 2, base + 3];
+        store
+            .upsert_memo(own[0], project, vec![0.5, 0.5, 0.0, 0.0])
+            .await
+            .unwrap();
+        store
+            .upsert_memo(own[1], project, vec![0.2, 0.0, 1.0, 0.0])
+            .await
+            .unwrap();
+        store
+            .upsert_memo(own[2], project, vec![0.0, 0.0, 0.0, 1.0])
+            .await
+            .unwrap();
+
+        let results = store
+            .search_similar(project, vec![1.0, 0.0, 0.0, 0.0], 3)
+            .await
+            .unwrap();
+        assert_eq!(results, own.to_vec());
+
+        store.delete_by_project(project).await.unwrap();
+        store.delete_by_project(crowd).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_format_mismatch_notices_switched_alias() {
+        let store = VectorStore::from_repo(InMemoryVectorRepository::new(VectorSpec::new(
+            "test-embedding",
+            DIMENSION,
+        )));
+        assert_eq!(store.repo.format_mismatch().await.unwrap(), None);
+
+        let next = VectorSpec::new("next-embedding", DIMENSION + 1);
+        store
+            .collections
+            .create_collection("memo_embeddings_v_next", &next)
+            .await
+            .unwrap();
+        store
+            .collections
+            .switch_alias("memo_embeddings_v_next")
+            .await
+            .unwrap();
+
+        // 보관한 결과는 쓰기가 실패하면 버리고 다시 확인한다
+        assert_eq!(store.repo.format_mismatch().await.unwrap(), None);
+        assert!(store
+            .repo
+            .upsert_memo(1, 1, vec![1.0, 0.0, 0.0, 0.0])
+            .await
+            .is_err());
+        assert!(store.repo.format_mismatch().await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_memory_vector_store_contract() {
+        let repo = InMemoryVectorRepository::new(VectorSpec::new("contract-test", DIMENSION));
+        assert_vector_store_contract(&repo).await;
+        assert_filtered_search_recall(&repo).await;
+    }
+
+    #[tokio::test]
+    async fn test_qdrant_vector_store_contract() {
+        dotenv::dotenv().ok();
+        let Ok(qdrant_url) = var("QDRANT_URL_TEST") else {
+            eprintln!("QDRANT_URL_TEST is not set; skipping Qdrant vector store tests");
+            return;
+        };
+
+        let repo = QdrantRepository::new(
+            qdrant_url,
+            VectorSpec::new("contract-test", DIMENSION),
+            QdrantConfig::default(),
+        )
+        .await
+        .unwrap();
+        assert_vector_store_contract(&repo).await;
+        assert_filtered_search_recall(&repo).await;
+    }
+
+    #[tokio::test]
+    async fn test_pgvector_vector_store_contract() {
+        dotenv::dotenv().ok();
+        let database_url = var("DATABASE_URL_TEST")
+            .expect("DATABASE_URL_TEST must be set. Run: just setup-test-db");
+        let db = Arc::new(db::create_connection(&database_url).await.unwrap());
+
+        // 테스트 DB에 pgvector가 없으면 건너뛰지 않고 실패한다 (`just setup-test-db`는 pgvector 이미지를 쓴다)
+        let repo = PgVectorRepository::new(db, VectorSpec::new("contract-test", DIMENSION))
+            .await
+            .expect("pgvector must be available in the test database");
+        assert_vector_store_contract(&repo).await;
+        assert_filtered_search_recall(&repo).await;
+    }
+
+    #[test]
+    fn test_vector_backend_parses_config_values() {
+        assert_eq!("qdrant".parse(), Ok(VectorBackend::Qdrant));
+        assert_eq!(" PgVector ".parse(), Ok(VectorBackend::PgVector));
+        assert_eq!("memory".parse(), Ok(VectorBackend::Memory));
+        assert_eq!("chroma".parse::<VectorBackend>(), Err(()));
+    }
+
+    #[test]
+    fn test_only_memory_store_without_snapshot_loses_vectors_on_restart() {
+        let config = |backend, snapshot_path: Option<&str>| VectorStoreConfig {
+            backend,
+            qdrant_url: None,
+            qdrant: QdrantConfig::default(),
+            snapshot_path: snapshot_path.map(PathBuf::from),
+        };
+
+        assert!(config(VectorBackend::Memory, None).loses_vectors_on_restart());
+        assert!(!config(VectorBackend::Memory, Some("vectors.json")).loses_vectors_on_restart());
+        assert!(!config(VectorBackend::Qdrant, None).loses_vectors_on_restart());
+        assert!(!config(VectorBackend::PgVector, None).loses_vectors_on_restart());
+    }
+}
@@ -2,10 +2,11 @@ use sea_orm::DatabaseConnection;
 use std::sync::Arc;
 
 use crate::{
-    clients::{Embedder, TextGenerator},
+    clients::{Embedder, EmbeddingPurpose, TextGenerator, UsageContext, UsageFeature},
     errors::ServiceError,
     models::assist_dto::{AssistRequest, AssistResponse, SimilarMemo},
     repositories::{MemoRepository, ProjectRepository, QdrantRepo},
+    services::{QuotaConfig, QuotaService, UsageService},
 };
 
 #[derive(Clone)]
@@ -15,6 +16,8 @@ pub struct AssistService {
     qdrant_repo: Arc<dyn QdrantRepo>,
     embedder: Arc<dyn Embedder>,
     text_generator: Arc<dyn TextGenerator>,
+    usage: UsageService,
+    quota: QuotaService,
 }
 
 impl AssistService {
@@ -26,13 +29,22 @@ impl AssistService {
     ) -> Self {
         Self {
             memo_repo: MemoRepository::new(db.clone()),
-            project_repo: ProjectRepository::new(db),
+            project_repo: ProjectRepository::new(db.clone()),
+            usage: UsageService::new(db.clone()),
+            quota: QuotaService::new(db, QuotaConfig::default()),
             qdrant_repo,
             embedder,
             text_generator,
         }
     }
 
+    pub fn with_quota_config(self, config: QuotaConfig) -> Self {
+        Self {
+            quota: self.quota.with_config(config),
+            ..self
+        }
+    }
+
     pub async fn get_assistance(
         &self,
         user_id: i32,
@@ -50,7 +62,27 @@ impl AssistService {
             return Err(ServiceError::Unauthorized);
         }
 
-        let query_vector = self.embedder.embed(&req.prompt).await?;
+        self.quota.check_assist(user_id).await?;
+
+        let usage_context = UsageContext {
+            user_id,
+            project_id: Some(project_id),
+            feature: UsageFeature::Assist,
+        };
+
+        // 다른 모델로 만든 컬렉션을 검색하지 않도록 쿼리를 임베딩하기 전에 확인
+        if let Some(reason) = self.qdrant_repo.format_mismatch().await? {
+            return Err(ServiceError::VectorFormatMismatch(reason));
+        }
+
+        let query_vector = self
+            .usage
+            .track(
+                usage_context,
+                self.embedder
+                    .embed(&req.prompt, EmbeddingPurpose::RetrievalQuery),
+            )
+            .await?;
 
         let similar_memo_ids = self
             .qdrant_repo
@@ -73,7 +105,14 @@ impl AssistService {
             }
         }
 
-        let suggestion = self.text_generator.generate(&req.prompt, context).await?;
+        let suggestion = self
+            .usage
+            .track(
+                usage_context,
+                self.text_generator
+                    .generate(&req.prompt, context, &req.generation_options()),
+            )
+            .await?;
 
         Ok(AssistResponse {
             suggestion,
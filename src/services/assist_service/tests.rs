@@ -1,10 +1,12 @@
 use super::*;
 use crate::{
+    clients::{ClientError, GenerationOptions, LexicalHashEmbedder},
     db,
     entities::user,
     models::{memo_dto::CreateMemoRequest, project_dto::CreateProjectRequest},
+    repositories::{InMemoryVectorRepository, VectorSpec, VectorStore},
     services::{memo_service::MemoService, ProjectService},
-    test_utils::{MockGeminiClient, MockQdrantRepository},
+    test_utils::{mock_qdrant_repository, MockGeminiClient},
 };
 use chrono::Utc;
 use rand::Rng;
@@ -55,7 +57,7 @@ async fn setup_test_db_with_project() -> (Arc<DatabaseConnection>, i32, i32) {
 #[tokio::test]
 async fn test_get_assistance() {
     let (db, user_id, project_id) = setup_test_db_with_project().await;
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let text_generator = Arc::new(MockGeminiClient::new());
 
@@ -65,7 +67,7 @@ async fn test_get_assistance() {
         embedder.clone() as Arc<dyn Embedder>,
     );
 
-    memo_service
+    let first = memo_service
         .create_memo(
             user_id,
             CreateMemoRequest {
@@ -76,7 +78,7 @@ async fn test_get_assistance() {
         .await
         .unwrap();
 
-    memo_service
+    let second = memo_service
         .create_memo(
             user_id,
             CreateMemoRequest {
@@ -86,6 +88,10 @@ async fn test_get_assistance() {
         )
         .await
         .unwrap();
+    memo_service
+        .process_index_jobs_for(&[first.id, second.id])
+        .await
+        .unwrap();
 
     let assist_service = AssistService::new(
         db.clone(),
@@ -98,6 +104,9 @@ async fn test_get_assistance() {
         project_id,
         prompt: "Tell me about Rust programming".to_string(),
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let result = assist_service
@@ -112,7 +121,7 @@ async fn test_get_assistance() {
 #[tokio::test]
 async fn test_get_assistance_no_similar_memos() {
     let (db, user_id, project_id) = setup_test_db_with_project().await;
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let text_generator = Arc::new(MockGeminiClient::new());
 
@@ -127,6 +136,9 @@ async fn test_get_assistance_no_similar_memos() {
         project_id,
         prompt: "Tell me about Python".to_string(),
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let result = assist_service
@@ -138,6 +150,151 @@ async fn test_get_assistance_no_similar_memos() {
     assert_eq!(result.similar_memos.len(), 0);
 }
 
+#[tokio::test]
+async fn test_get_assistance_unavailable_while_active_collection_has_another_format() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let store = VectorStore::from_repo(InMemoryVectorRepository::new(VectorSpec::new(
+        "mock-embedding",
+        768,
+    )));
+    store
+        .collections
+        .create_collection(
+            "memo_embeddings_v_next",
+            &VectorSpec::new("next-embedding", 1024),
+        )
+        .await
+        .unwrap();
+    store
+        .collections
+        .switch_alias("memo_embeddings_v_next")
+        .await
+        .unwrap();
+
+    let assist_service = AssistService::new(
+        db,
+        store.repo,
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+        Arc::new(MockGeminiClient::new()) as Arc<dyn TextGenerator>,
+    );
+
+    let req = AssistRequest {
+        project_id,
+        prompt: "Tell me about Rust".to_string(),
+        limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
+    };
+
+    let result = assist_service
+        .get_assistance(user_id, project_id, req)
+        .await;
+    assert!(matches!(result, Err(ServiceError::VectorFormatMismatch(_))));
+}
+
+/// 외부 API 없이 어휘 임베더와 메모리 저장소만으로 관련 메모를 찾는다
+#[tokio::test]
+async fn test_get_assistance_with_lexical_embedder_ranks_related_memo_first() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let embedder = Arc::new(LexicalHashEmbedder::default());
+    let store = Arc::new(InMemoryVectorRepository::new(VectorSpec::new(
+        embedder.model_id(),
+        embedder.dimension(),
+    )));
+
+    let memo_service = MemoService::new(
+        db.clone(),
+        store.clone(),
+        embedder.clone() as Arc<dyn Embedder>,
+    );
+    let mut memo_ids = Vec::new();
+    for content in [
+        "고양이 사료는 아침과 저녁에 나눠서 준다",
+        "주말 아침에는 핸드드립으로 커피를 내리고 원두는 산미 있는 것을 고른다",
+        "여름 휴가로 바닷가 캠핑을 계획 중이다",
+    ] {
+        let memo = memo_service
+            .create_memo(
+                user_id,
+                CreateMemoRequest {
+                    project_id,
+                    content: content.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        memo_ids.push(memo.id);
+    }
+    memo_service
+        .process_index_jobs_for(&memo_ids)
+        .await
+        .unwrap();
+
+    let assist_service = AssistService::new(
+        db,
+        store as Arc<dyn QdrantRepo>,
+        embedder as Arc<dyn Embedder>,
+        Arc::new(MockGeminiClient::new()) as Arc<dyn TextGenerator>,
+    );
+    let req = AssistRequest {
+        project_id,
+        prompt: "커피 원두 고르는 법을 정리해줘".to_string(),
+        limit: 3,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
+    };
+
+    let result = assist_service
+        .get_assistance(user_id, project_id, req)
+        .await
+        .unwrap();
+
+    assert_eq!(result.similar_memos.len(), 3);
+    assert_eq!(result.similar_memos[0].id, memo_ids[1]);
+}
+
+#[tokio::test]
+async fn test_get_assistance_records_usage() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let mock = Arc::new(MockGeminiClient::new());
+
+    let assist_service = AssistService::new(
+        db.clone(),
+        Arc::new(mock_qdrant_repository()) as Arc<dyn QdrantRepo>,
+        mock.clone() as Arc<dyn Embedder>,
+        mock as Arc<dyn TextGenerator>,
+    );
+
+    let req = AssistRequest {
+        project_id,
+        prompt: "Tell me about Rust".to_string(),
+        limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
+    };
+
+    assist_service
+        .get_assistance(user_id, project_id, req)
+        .await
+        .unwrap();
+
+    let usage = UsageService::new(db).daily_usage(user_id, 1).await.unwrap();
+    let operations: Vec<&str> = usage.iter().map(|u| u.operation.as_str()).collect();
+    assert_eq!(operations, vec!["embed", "generate"]);
+
+    let generate = &usage[1];
+    assert_eq!(generate.model, "mock-generation");
+    assert_eq!(generate.calls, 1);
+    assert!(generate.prompt_tokens > 0);
+    assert_eq!(
+        generate.total_tokens,
+        generate.prompt_tokens + generate.candidate_tokens
+    );
+}
+
 #[tokio::test]
 async fn test_get_assistance_user_isolation() {
     let (db, user1_id, project1_id) = setup_test_db_with_project().await;
@@ -168,7 +325,7 @@ async fn test_get_assistance_user_isolation() {
         .await
         .unwrap();
 
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let text_generator = Arc::new(MockGeminiClient::new());
 
@@ -178,7 +335,7 @@ async fn test_get_assistance_user_isolation() {
         embedder.clone() as Arc<dyn Embedder>,
     );
 
-    memo_service
+    let first = memo_service
         .create_memo(
             user1_id,
             CreateMemoRequest {
@@ -189,7 +346,7 @@ async fn test_get_assistance_user_isolation() {
         .await
         .unwrap();
 
-    memo_service
+    let second = memo_service
         .create_memo(
             user2_id,
             CreateMemoRequest {
@@ -199,6 +356,10 @@ async fn test_get_assistance_user_isolation() {
         )
         .await
         .unwrap();
+    memo_service
+        .process_index_jobs_for(&[first.id, second.id])
+        .await
+        .unwrap();
 
     let assist_service = AssistService::new(
         db,
@@ -211,6 +372,9 @@ async fn test_get_assistance_user_isolation() {
         project_id: project1_id,
         prompt: "Tell me about Rust".to_string(),
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let result = assist_service
@@ -231,7 +395,7 @@ async fn test_get_assistance_user_isolation() {
 #[tokio::test]
 async fn test_get_assistance_project_isolation() {
     let (db, user_id, project1_id) = setup_test_db_with_project().await;
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let text_generator = Arc::new(MockGeminiClient::new());
 
@@ -253,7 +417,7 @@ async fn test_get_assistance_project_isolation() {
         embedder.clone() as Arc<dyn Embedder>,
     );
 
-    memo_service
+    let first = memo_service
         .create_memo(
             user_id,
             CreateMemoRequest {
@@ -264,7 +428,7 @@ async fn test_get_assistance_project_isolation() {
         .await
         .unwrap();
 
-    memo_service
+    let second = memo_service
         .create_memo(
             user_id,
             CreateMemoRequest {
@@ -274,6 +438,10 @@ async fn test_get_assistance_project_isolation() {
         )
         .await
         .unwrap();
+    memo_service
+        .process_index_jobs_for(&[first.id, second.id])
+        .await
+        .unwrap();
 
     let assist_service = AssistService::new(
         db.clone(),
@@ -286,6 +454,9 @@ async fn test_get_assistance_project_isolation() {
         project_id: project1_id,
         prompt: "Tell me about Rust".to_string(),
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let result1 = assist_service
@@ -305,6 +476,9 @@ async fn test_get_assistance_project_isolation() {
         project_id: project2.id,
         prompt: "Tell me about Python".to_string(),
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let result2 = assist_service
@@ -320,3 +494,132 @@ async fn test_get_assistance_project_isolation() {
         .iter()
         .any(|m| m.content.contains("Rust")));
 }
+
+/// 항상 안전 필터 차단을 반환하는 생성기
+struct BlockingGenerator;
+
+#[async_trait::async_trait]
+impl TextGenerator for BlockingGenerator {
+    async fn generate(
+        &self,
+        _prompt: &str,
+        _context: Vec<String>,
+        _options: &GenerationOptions,
+    ) -> Result<String, ClientError> {
+        Err(ClientError::ContentBlocked {
+            reason: "SAFETY".to_string(),
+            categories: vec!["HARM_CATEGORY_HARASSMENT".to_string()],
+            partial: Some("부분 응답".to_string()),
+        })
+    }
+
+    async fn complete(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
+    ) -> Result<String, ClientError> {
+        self.generate(prompt, Vec::new(), options).await
+    }
+}
+
+#[tokio::test]
+async fn test_get_assistance_blocked_content_returns_actionable_422() {
+    use axum::response::IntoResponse;
+    use http_body_util::BodyExt;
+
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let assist_service = AssistService::new(
+        db,
+        Arc::new(mock_qdrant_repository()) as Arc<dyn QdrantRepo>,
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+        Arc::new(BlockingGenerator) as Arc<dyn TextGenerator>,
+    );
+
+    let req = AssistRequest {
+        project_id,
+        prompt: "차단될 프롬프트".to_string(),
+        limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
+    };
+
+    let err = assist_service
+        .get_assistance(user_id, project_id, req)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ServiceError::ContentBlocked { .. }));
+
+    let response = err.into_response();
+    assert_eq!(
+        response.status(),
+        axum::http::StatusCode::UNPROCESSABLE_ENTITY
+    );
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(body["error"].as_str().unwrap().contains("rephrase"));
+    assert_eq!(body["reason"], "SAFETY");
+    assert_eq!(body["categories"][0], "HARM_CATEGORY_HARASSMENT");
+    assert_eq!(body["partial_text"], "부분 응답");
+}
+
+/// 전달받은 생성 옵션을 기록하는 생성기
+#[derive(Default)]
+struct CapturingGenerator {
+    options: std::sync::Mutex<Option<GenerationOptions>>,
+}
+
+#[async_trait::async_trait]
+impl TextGenerator for CapturingGenerator {
+    async fn generate(
+        &self,
+        prompt: &str,
+        _context: Vec<String>,
+        options: &GenerationOptions,
+    ) -> Result<String, ClientError> {
+        self.complete(prompt, options).await
+    }
+
+    async fn complete(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
+    ) -> Result<String, ClientError> {
+        *self.options.lock().unwrap() = Some(options.clone());
+        Ok(prompt.to_string())
+    }
+}
+
+#[tokio::test]
+async fn test_get_assistance_clamps_generation_options() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let generator = Arc::new(CapturingGenerator::default());
+    let assist_service = AssistService::new(
+        db,
+        Arc::new(mock_qdrant_repository()) as Arc<dyn QdrantRepo>,
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+        generator.clone() as Arc<dyn TextGenerator>,
+    );
+
+    let req = AssistRequest {
+        project_id,
+        prompt: "글쓰기 도와줘".to_string(),
+        limit: 5,
+        temperature: Some(1.8),
+        max_output_tokens: Some(100_000),
+        response_language: Some("en".to_string()),
+    };
+
+    assist_service
+        .get_assistance(user_id, project_id, req)
+        .await
+        .unwrap();
+
+    let options = generator.options.lock().unwrap().clone().unwrap();
+    assert_eq!(options.temperature, Some(1.0));
+    assert_eq!(options.max_output_tokens, Some(2048));
+    assert_eq!(options.response_language.as_deref(), Some("en"));
+    assert_eq!(options.system_instruction, None);
+    assert!(options.stop_sequences.is_empty());
+}
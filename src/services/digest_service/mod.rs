@@ -0,0 +1,277 @@
+use chrono::{Duration, NaiveDateTime, Utc};
+use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::{
+    clients::{GenerationOptions, TextGenerator, UsageContext, UsageFeature},
+    entities::memo,
+    errors::ServiceError,
+    models::digest_dto::DigestResponse,
+    repositories::{DigestRepository, MemoRepository, ProjectRepository},
+    services::{QuotaConfig, QuotaService, UsageService},
+    utils::env::{env_or, InvalidEnvVar},
+};
+
+/// 다이제스트 프롬프트에 포함할 최대 메모 수 (최근 메모 우선)
+const MAX_DIGEST_MEMOS: usize = 100;
+
+#[derive(Debug, Clone)]
+pub struct DigestConfig {
+    /// 스케줄러 실행 여부
+    pub enabled: bool,
+    /// 같은 프로젝트의 다이제스트 생성 간격
+    pub interval: Duration,
+    /// 이전 다이제스트가 없거나 오래된 경우 거슬러 올라갈 최대 기간
+    pub lookback: Duration,
+    /// 스케줄러가 생성할 다이제스트가 있는지 확인하는 주기
+    pub poll_interval: std::time::Duration,
+}
+
+impl Default for DigestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval: Duration::days(7),
+            lookback: Duration::days(7),
+            poll_interval: std::time::Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+impl DigestConfig {
+    /// DIGEST_ENABLED, DIGEST_INTERVAL_HOURS, DIGEST_LOOKBACK_DAYS,
+    /// DIGEST_POLL_INTERVAL_MINUTES 환경 변수로 기본값을 덮어쓴다
+    pub fn from_env() -> Result<Self, InvalidEnvVar> {
+        let default = Self::default();
+
+        let interval_hours: i64 = env_or("DIGEST_INTERVAL_HOURS", default.interval.num_hours())?;
+        let lookback_days: i64 = env_or("DIGEST_LOOKBACK_DAYS", default.lookback.num_days())?;
+        let poll_minutes: u64 = env_or(
+            "DIGEST_POLL_INTERVAL_MINUTES",
+            default.poll_interval.as_secs() / 60,
+        )?;
+
+        Ok(Self {
+            enabled: env_or("DIGEST_ENABLED", default.enabled)?,
+            interval: Duration::hours(interval_hours.max(1)),
+            lookback: Duration::days(lookback_days.max(1)),
+            poll_interval: std::time::Duration::from_secs(poll_minutes.max(1) * 60),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct DigestService {
+    db: Arc<DatabaseConnection>,
+    digest_repo: DigestRepository,
+    memo_repo: MemoRepository,
+    project_repo: ProjectRepository,
+    text_generator: Arc<dyn TextGenerator>,
+    usage: UsageService,
+    quota: QuotaService,
+    config: DigestConfig,
+}
+
+impl DigestService {
+    pub fn new(
+        db: Arc<DatabaseConnection>,
+        text_generator: Arc<dyn TextGenerator>,
+        config: DigestConfig,
+    ) -> Self {
+        Self {
+            digest_repo: DigestRepository::new(db.clone()),
+            memo_repo: MemoRepository::new(db.clone()),
+            project_repo: ProjectRepository::new(db.clone()),
+            usage: UsageService::new(db.clone()),
+            quota: QuotaService::new(db.clone(), QuotaConfig::default()),
+            db,
+            text_generator,
+            config,
+        }
+    }
+
+    pub fn with_quota_config(self, config: QuotaConfig) -> Self {
+        Self {
+            quota: self.quota.with_config(config),
+            ..self
+        }
+    }
+
+    pub fn config(&self) -> &DigestConfig {
+        &self.config
+    }
+
+    pub async fn list_digests(
+        &self,
+        user_id: i32,
+        project_id: i32,
+    ) -> Result<Vec<DigestResponse>, ServiceError> {
+        // Project 권한 검증
+        let project = self
+            .project_repo
+            .find_by_id(project_id)
+            .await?
+            .ok_or(ServiceError::ProjectNotFound)?;
+
+        if project.user_id != user_id {
+            return Err(ServiceError::Unauthorized);
+        }
+
+        let digests = self.digest_repo.find_by_project_id(project_id).await?;
+        Ok(digests.into_iter().map(DigestResponse::from).collect())
+    }
+
+    /// 최근 메모가 있는 모든 프로젝트에 대해 다이제스트 생성 시도.
+    /// 한 프로젝트의 실패가 나머지 프로젝트 처리를 막지 않도록 에러는 로그만 남긴다
+    pub async fn run_due_digests(&self) -> Result<usize, ServiceError> {
+        let since = Utc::now().naive_utc() - self.config.lookback;
+        let project_ids = self
+            .memo_repo
+            .find_project_ids_with_memos_since(since)
+            .await?;
+
+        let mut created = 0;
+        for project_id in project_ids {
+            match self.create_digest_if_due(project_id).await {
+                Ok(Some(_)) => created += 1,
+                Ok(None) => {}
+                Err(ServiceError::QuotaExceeded { .. }) => info!(
+                    "Skipping digest for project {}: daily token quota exceeded",
+                    project_id
+                ),
+                Err(e) => warn!("Failed to create digest for project {}: {}", project_id, e),
+            }
+        }
+
+        Ok(created)
+    }
+
+    /// 프로젝트의 다이제스트 주기가 돌아왔으면 새 다이제스트를 생성.
+    /// LLM 호출 동안 커넥션을 트랜잭션에 묶어 두지 않도록 주기 확인과 저장을 각각 짧은 트랜잭션에서 한다.
+    /// 여러 레플리카가 동시에 실행해도 프로젝트 단위 advisory lock과
+    /// 저장 직전 락 안에서의 주기 재확인으로 한 번만 저장된다
+    pub async fn create_digest_if_due(
+        &self,
+        project_id: i32,
+    ) -> Result<Option<DigestResponse>, ServiceError> {
+        let now = Utc::now().naive_utc();
+
+        let txn = self.db.begin().await?;
+        let due = self.due_period_start(&txn, project_id, now).await?;
+        txn.commit().await?;
+        let Some(period_start) = due else {
+            return Ok(None);
+        };
+
+        let memos = self
+            .memo_repo
+            .find_created_between(project_id, period_start, now)
+            .await?;
+
+        if memos.is_empty() {
+            return Ok(None);
+        }
+
+        let project = self
+            .project_repo
+            .find_by_id(project_id)
+            .await?
+            .ok_or(ServiceError::ProjectNotFound)?;
+
+        self.quota.check_daily_tokens(project.user_id).await?;
+
+        let usage_context = UsageContext {
+            user_id: project.user_id,
+            project_id: Some(project_id),
+            feature: UsageFeature::Digest,
+        };
+
+        let prompt = Self::build_prompt(&memos, period_start, now);
+        let summary = self
+            .usage
+            .track(
+                usage_context,
+                self.text_generator
+                    .complete(&prompt, &GenerationOptions::default()),
+            )
+            .await?;
+
+        // 생성하는 동안 다른 레플리카가 먼저 저장했으면 주기가 바뀌므로 저장하지 않는다
+        let txn = self.db.begin().await?;
+        if self.due_period_start(&txn, project_id, now).await? != Some(period_start) {
+            return Ok(None);
+        }
+
+        let digest = self
+            .digest_repo
+            .create(
+                &txn,
+                project_id,
+                summary.trim().to_string(),
+                memos.len() as i32,
+                period_start,
+                now,
+            )
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(Some(DigestResponse::from(digest)))
+    }
+
+    /// 트랜잭션 안에서 프로젝트 락을 잡고, 주기가 돌아왔으면 새 다이제스트가 다룰 기간의 시작 시각을 반환.
+    /// 다른 레플리카가 락을 잡고 있거나 주기가 아직이면 None
+    async fn due_period_start(
+        &self,
+        txn: &DatabaseTransaction,
+        project_id: i32,
+        now: NaiveDateTime,
+    ) -> Result<Option<NaiveDateTime>, ServiceError> {
+        if !self.digest_repo.try_lock_project(txn, project_id).await? {
+            return Ok(None);
+        }
+
+        let latest = self
+            .digest_repo
+            .find_latest_by_project_id(txn, project_id)
+            .await?;
+
+        if let Some(ref digest) = latest {
+            if now - digest.period_end < self.config.interval {
+                return Ok(None);
+            }
+        }
+
+        let window_start = now - self.config.lookback;
+        Ok(Some(
+            latest
+                .map(|digest| digest.period_end.max(window_start))
+                .unwrap_or(window_start),
+        ))
+    }
+
+    fn build_prompt(memos: &[memo::Model], since: NaiveDateTime, until: NaiveDateTime) -> String {
+        let mut prompt = format!(
+            "다음은 사용자가 {}부터 {}까지 작성한 메모들입니다:\n\n",
+            since.format("%Y-%m-%d"),
+            until.format("%Y-%m-%d")
+        );
+
+        let skip = memos.len().saturating_sub(MAX_DIGEST_MEMOS);
+        for (i, memo) in memos.iter().skip(skip).enumerate() {
+            prompt.push_str(&format!("메모 {}:\n{}\n\n", i + 1, memo.content));
+        }
+
+        prompt.push_str(
+            "위 메모들을 정리해 주세요. 다음 두 섹션을 마크다운으로 작성하세요:\n\
+             ## 주요 주제\n반복해서 등장하는 주제와 생각의 흐름을 항목별로 요약\n\n\
+             ## 열린 질문\n아직 답을 찾지 못했거나 더 탐구해볼 만한 질문 목록",
+        );
+
+        prompt
+    }
+}
+
+#[cfg(test)]
+mod tests;
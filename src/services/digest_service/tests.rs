@@ -0,0 +1,181 @@
+use super::*;
+use crate::{
+    models::project_dto::CreateProjectRequest,
+    repositories::MemoRepository,
+    services::ProjectService,
+    test_support::{setup_test_db, setup_test_db_with_project},
+    test_utils::MockGeminiClient,
+};
+
+fn digest_service(db: Arc<DatabaseConnection>) -> DigestService {
+    DigestService::new(
+        db,
+        Arc::new(MockGeminiClient::new()) as Arc<dyn TextGenerator>,
+        DigestConfig::default(),
+    )
+}
+
+#[tokio::test]
+async fn test_create_digest_summarizes_new_memos() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let memo_repo = MemoRepository::new(db.clone());
+    memo_repo
+        .create(db.as_ref(), project_id, "Rust 소유권 개념 정리".to_string())
+        .await
+        .unwrap();
+    memo_repo
+        .create(
+            db.as_ref(),
+            project_id,
+            "tokio 런타임은 언제 블로킹될까?".to_string(),
+        )
+        .await
+        .unwrap();
+
+    let service = digest_service(db);
+
+    let digest = service
+        .create_digest_if_due(project_id)
+        .await
+        .unwrap()
+        .expect("digest should be created");
+
+    assert_eq!(digest.project_id, project_id);
+    assert_eq!(digest.memo_count, 2);
+    assert!(digest.summary.contains("Rust 소유권 개념 정리"));
+    assert!(digest.summary.contains("열린 질문"));
+    assert!(digest.period_start < digest.period_end);
+
+    let digests = service.list_digests(user_id, project_id).await.unwrap();
+    assert_eq!(digests.len(), 1);
+    assert_eq!(digests[0].id, digest.id);
+}
+
+#[tokio::test]
+async fn test_create_digest_skips_project_without_new_memos() {
+    let (db, _user_id, project_id) = setup_test_db_with_project().await;
+    let service = digest_service(db);
+
+    let result = service.create_digest_if_due(project_id).await.unwrap();
+    assert!(result.is_none());
+}
+
+#[tokio::test]
+async fn test_create_digest_respects_interval() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let memo_repo = MemoRepository::new(db.clone());
+    memo_repo
+        .create(db.as_ref(), project_id, "첫 번째 메모".to_string())
+        .await
+        .unwrap();
+
+    let service = digest_service(db.clone());
+    assert!(service
+        .create_digest_if_due(project_id)
+        .await
+        .unwrap()
+        .is_some());
+
+    memo_repo
+        .create(db.as_ref(), project_id, "두 번째 메모".to_string())
+        .await
+        .unwrap();
+
+    // 주기가 돌아오기 전에는 새 메모가 있어도 생성하지 않음
+    assert!(service
+        .create_digest_if_due(project_id)
+        .await
+        .unwrap()
+        .is_none());
+
+    let digests = service.list_digests(user_id, project_id).await.unwrap();
+    assert_eq!(digests.len(), 1);
+}
+
+#[tokio::test]
+async fn test_concurrent_runs_create_single_digest() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    MemoRepository::new(db.clone())
+        .create(db.as_ref(), project_id, "동시 실행 테스트 메모".to_string())
+        .await
+        .unwrap();
+
+    // 서로 다른 레플리카를 흉내 내기 위해 각자 서비스 인스턴스 사용
+    let replica_a = digest_service(db.clone());
+    let replica_b = digest_service(db.clone());
+
+    let (a, b) = tokio::join!(
+        replica_a.create_digest_if_due(project_id),
+        replica_b.create_digest_if_due(project_id)
+    );
+
+    let created = [a.unwrap(), b.unwrap()]
+        .iter()
+        .filter(|result| result.is_some())
+        .count();
+    assert_eq!(created, 1);
+
+    let digests = replica_a.list_digests(user_id, project_id).await.unwrap();
+    assert_eq!(digests.len(), 1);
+}
+
+#[tokio::test]
+async fn test_create_digest_enforces_daily_token_quota() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let other_project_id = ProjectService::new(db.clone())
+        .create_project(
+            user_id,
+            CreateProjectRequest {
+                name: format!("Other Project {}", Utc::now().timestamp_micros()),
+                description: None,
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+    let memo_repo = MemoRepository::new(db.clone());
+    for id in [project_id, other_project_id] {
+        memo_repo
+            .create(db.as_ref(), id, "할당량 테스트 메모".to_string())
+            .await
+            .unwrap();
+    }
+
+    let service = digest_service(db).with_quota_config(QuotaConfig {
+        daily_tokens: 1,
+        ..QuotaConfig::unlimited()
+    });
+
+    assert!(service
+        .create_digest_if_due(project_id)
+        .await
+        .unwrap()
+        .is_some());
+
+    let result = service.create_digest_if_due(other_project_id).await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::QuotaExceeded {
+            quota: "daily_tokens",
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_list_digests_unauthorized() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = digest_service(db);
+
+    let result = service.list_digests(user_id + 999, project_id).await;
+    assert!(matches!(result, Err(ServiceError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_list_digests_project_not_found() {
+    let (db, user_id) = setup_test_db().await;
+    let service = digest_service(db);
+
+    let result = service.list_digests(user_id, 999999).await;
+    assert!(matches!(result, Err(ServiceError::ProjectNotFound)));
+}
@@ -1,6 +1,8 @@
 use crate::errors::ServiceError;
 use crate::models::essay_dto::{CreateEssayRequest, EssayResponse, UpdateEssayRequest};
-use crate::repositories::{EssayRepository, ProjectRepository};
+use crate::models::{Page, PageParams};
+use crate::repositories::{EssayRepository, PinnedOrderKey, ProjectRepository};
+use crate::services::pagination::{decode_cursor, into_page};
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
 
@@ -70,11 +72,28 @@ impl EssayService {
         Ok(EssayResponse::from(essay))
     }
 
+    /// 사용자의 모든 프로젝트의 에세이를 고정 여부, 수정 시각 순으로 한 페이지 조회
+    pub async fn list_essays(
+        &self,
+        user_id: i32,
+        page: &PageParams,
+    ) -> Result<Page<EssayResponse>, ServiceError> {
+        let after = page.cursor.as_deref().map(decode_cursor).transpose()?;
+        let limit = page.limit();
+
+        let essays = self
+            .essay_repo
+            .find_page_by_user_id(user_id, after, limit + 1)
+            .await?;
+        Ok(into_page::<PinnedOrderKey, _, _>(essays, limit))
+    }
+
     pub async fn list_essays_by_project(
         &self,
         user_id: i32,
         project_id: i32,
-    ) -> Result<Vec<EssayResponse>, ServiceError> {
+        page: &PageParams,
+    ) -> Result<Page<EssayResponse>, ServiceError> {
         // Project 권한 검증
         let project = self
             .project_repo
@@ -86,8 +105,14 @@ impl EssayService {
             return Err(ServiceError::Unauthorized);
         }
 
-        let essays = self.essay_repo.find_by_project_id(project_id).await?;
-        Ok(essays.into_iter().map(EssayResponse::from).collect())
+        let after = page.cursor.as_deref().map(decode_cursor).transpose()?;
+        let limit = page.limit();
+
+        let essays = self
+            .essay_repo
+            .find_page_by_project_id(project_id, after, limit + 1)
+            .await?;
+        Ok(into_page::<PinnedOrderKey, _, _>(essays, limit))
     }
 
     pub async fn update_essay(
@@ -113,11 +138,17 @@ impl EssayService {
             return Err(ServiceError::Unauthorized);
         }
 
+        let changed = essay.title != req.title || essay.content != req.content;
+
         let updated_essay = self
             .essay_repo
             .update(essay_id, req.title.clone(), req.content.clone())
             .await?;
 
+        if changed {
+            self.essay_repo.mark_translations_stale(essay_id).await?;
+        }
+
         Ok(EssayResponse::from(updated_essay))
     }
 
@@ -175,9 +175,10 @@ async fn test_list_essays_ordering() {
     essay1_active.update(&*db).await.unwrap();
 
     let essays = service
-        .list_essays_by_project(user_id, project_id)
+        .list_essays_by_project(user_id, project_id, &PageParams::default())
         .await
-        .unwrap();
+        .unwrap()
+        .items;
 
     assert!(essays[0].is_pinned);
     assert_eq!(essays[0].id, essay1.id);
@@ -239,25 +240,66 @@ async fn test_essay_project_isolation() {
         .unwrap();
 
     let project1_essays = service
-        .list_essays_by_project(user_id, project1_id)
+        .list_essays_by_project(user_id, project1_id, &PageParams::default())
         .await
-        .unwrap();
+        .unwrap()
+        .items;
     assert_eq!(project1_essays.len(), 2);
     assert!(project1_essays
         .iter()
         .all(|e| e.title.contains("Project 1")));
 
     let project2_essays = service
-        .list_essays_by_project(user_id, project2.id)
+        .list_essays_by_project(user_id, project2.id, &PageParams::default())
         .await
-        .unwrap();
+        .unwrap()
+        .items;
     assert_eq!(project2_essays.len(), 1);
     assert!(project2_essays
         .iter()
         .all(|e| e.title.contains("Project 2")));
 
+    // 프로젝트를 지정하지 않으면 모든 프로젝트의 에세이를 한 목록으로 조회
+    let first_page = service
+        .list_essays(
+            user_id,
+            &PageParams {
+                limit: Some(2),
+                cursor: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(first_page.items.len(), 2);
+    let second_page = service
+        .list_essays(
+            user_id,
+            &PageParams {
+                limit: Some(2),
+                cursor: first_page.next_cursor,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(second_page.items.len(), 1);
+    assert!(second_page.next_cursor.is_none());
+    let titles: Vec<_> = first_page
+        .items
+        .iter()
+        .chain(&second_page.items)
+        .map(|e| e.title.as_str())
+        .collect();
+    assert_eq!(
+        titles,
+        vec![
+            "Project 2 - Essay 1",
+            "Project 1 - Essay 2",
+            "Project 1 - Essay 1"
+        ]
+    );
+
     let result = service
-        .list_essays_by_project(user_id + 999, project1_id)
+        .list_essays_by_project(user_id + 999, project1_id, &PageParams::default())
         .await;
     assert!(matches!(result, Err(ServiceError::Unauthorized)));
 }
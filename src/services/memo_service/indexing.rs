@@ -0,0 +1,388 @@
+use chrono::Utc;
+use sea_orm::{DbErr, TransactionTrait};
+use std::{sync::Arc, time::Duration};
+use tokio::{sync::Semaphore, task::JoinSet};
+use tracing::{error, warn};
+
+use super::MemoService;
+use crate::{
+    clients::{ClientError, EmbeddingPurpose, UsageContext, UsageFeature},
+    entities::{memo::IndexStatus, memo_index_job},
+    errors::ServiceError,
+    utils::{
+        content_hash::content_hash,
+        env::{env_or, InvalidEnvVar},
+    },
+};
+
+/// 인덱싱 워커 설정
+#[derive(Debug, Clone)]
+pub struct IndexingConfig {
+    /// 동시에 처리하는 작업 수
+    pub concurrency: usize,
+    /// 한 번에 가져오는 작업 수
+    pub batch_size: u64,
+    /// 이 횟수만큼 시도해도 실패하면 dead 상태로 둔다.
+    /// 프로바이더 장애(회로 열림, 5xx, 429)로 실패한 시도는 세지 않는다
+    pub max_attempts: i32,
+    /// 재시도 대기 시간. 시도마다 두 배로 늘어나며 `max_backoff`를 넘지 않는다
+    pub base_backoff: Duration,
+    pub max_backoff: Duration,
+    /// 프로바이더 장애로 실패한 작업의 재시도 대기 시간. 장애가 끝날 때까지 이 간격으로 계속 시도한다
+    pub outage_backoff: Duration,
+    /// 워커가 작업을 가져간 뒤 다른 워커가 다시 가져갈 수 있을 때까지의 시간
+    pub lease: Duration,
+    /// 대기 중인 작업이 있는지 확인하는 주기
+    pub poll_interval: Duration,
+}
+
+impl Default for IndexingConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            batch_size: 32,
+            max_attempts: 6,
+            base_backoff: Duration::from_secs(10),
+            max_backoff: Duration::from_secs(30 * 60),
+            outage_backoff: Duration::from_secs(60),
+            lease: Duration::from_secs(5 * 60),
+            poll_interval: Duration::from_secs(2),
+        }
+    }
+}
+
+impl IndexingConfig {
+    /// INDEXING_CONCURRENCY, INDEXING_BATCH_SIZE, INDEXING_MAX_ATTEMPTS,
+    /// INDEXING_POLL_INTERVAL_SECS 환경 변수로 기본값을 덮어쓴다
+    pub fn from_env() -> Result<Self, InvalidEnvVar> {
+        let default = Self::default();
+        let poll_secs: u64 = env_or(
+            "INDEXING_POLL_INTERVAL_SECS",
+            default.poll_interval.as_secs(),
+        )?;
+
+        Ok(Self {
+            concurrency: env_or("INDEXING_CONCURRENCY", default.concurrency)?.max(1),
+            batch_size: env_or("INDEXING_BATCH_SIZE", default.batch_size)?.max(1),
+            max_attempts: env_or("INDEXING_MAX_ATTEMPTS", default.max_attempts)?.max(1),
+            poll_interval: Duration::from_secs(poll_secs.max(1)),
+            ..default
+        })
+    }
+
+    pub(super) fn backoff(&self, attempts: i32) -> Duration {
+        let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+        self.base_backoff
+            .saturating_mul(2u32.pow(exponent))
+            .min(self.max_backoff)
+    }
+}
+
+/// 워커 한 번 실행 결과
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IndexingReport {
+    pub claimed: usize,
+    pub indexed: usize,
+    pub retried: usize,
+    pub dead: usize,
+}
+
+enum Outcome {
+    Indexed,
+    Retried,
+    Dead,
+    /// 메모가 삭제되었거나 처리 중 다시 수정됨
+    Superseded,
+}
+
+/// 작업 실패 원인. 재시도 가능 여부에 따라 백오프하거나 dead 상태로 둔다
+enum Failure {
+    Retryable {
+        message: String,
+        retry_after: Option<Duration>,
+    },
+    /// 메모와 무관한 프로바이더 장애나 벡터 형식 불일치.
+    /// 장애가 길어져도 작업을 포기하지 않도록 시도 횟수에 넣지 않는다
+    Outage {
+        message: String,
+        retry_after: Option<Duration>,
+    },
+    Permanent(String),
+}
+
+impl From<ClientError> for Failure {
+    fn from(e: ClientError) -> Self {
+        if matches!(
+            e,
+            ClientError::CircuitOpen(_)
+                | ClientError::Unavailable { .. }
+                | ClientError::RateLimited { .. }
+        ) {
+            Failure::Outage {
+                retry_after: e.retry_after(),
+                message: e.to_string(),
+            }
+        } else if e.is_retryable() {
+            Failure::Retryable {
+                retry_after: e.retry_after(),
+                message: e.to_string(),
+            }
+        } else {
+            Failure::Permanent(e.to_string())
+        }
+    }
+}
+
+impl From<DbErr> for Failure {
+    fn from(e: DbErr) -> Self {
+        Failure::Retryable {
+            message: e.to_string(),
+            retry_after: None,
+        }
+    }
+}
+
+impl MemoService {
+    pub fn with_indexing_config(self, config: IndexingConfig) -> Self {
+        Self {
+            indexing: config,
+            ..self
+        }
+    }
+
+    pub fn indexing_config(&self) -> &IndexingConfig {
+        &self.indexing
+    }
+
+    /// 실행할 때가 된 인덱싱 작업을 가져와 최대 `concurrency`개씩 동시에 처리.
+    /// 이미 저장된 메모를 반영하는 작업이므로 메모 임베딩 할당량은 적용하지 않는다.
+    /// 활성 컬렉션이 임베더와 다른 형식이면 작업을 가져오지 않고
+    /// [`ServiceError::VectorFormatMismatch`]를 반환한다
+    pub async fn process_index_jobs(&self) -> Result<IndexingReport, ServiceError> {
+        if let Some(reason) = self.qdrant_repo.format_mismatch().await? {
+            return Err(ServiceError::VectorFormatMismatch(reason));
+        }
+
+        let jobs = self
+            .job_repo
+            .claim(None, self.indexing.batch_size, self.lease())
+            .await?;
+        Ok(self.run_jobs(jobs).await)
+    }
+
+    /// 지정한 메모의 대기 중인 인덱싱 작업만 즉시 처리
+    pub async fn process_index_jobs_for(
+        &self,
+        memo_ids: &[i32],
+    ) -> Result<IndexingReport, ServiceError> {
+        let jobs = self
+            .job_repo
+            .claim(Some(memo_ids), memo_ids.len() as u64, self.lease())
+            .await?;
+        Ok(self.run_jobs(jobs).await)
+    }
+
+    /// dead 상태의 인덱싱 작업을 모두 다시 대기 상태로 돌리고 메모를 인덱싱 대기로 표시.
+    /// 원인을 해결한 뒤 실행하며, 다시 등록한 작업 수를 반환한다
+    pub async fn requeue_failed_index_jobs(&self) -> Result<u64, ServiceError> {
+        self.requeue_dead_jobs(None).await
+    }
+
+    /// 지정한 메모의 dead 작업만 다시 등록
+    pub async fn requeue_failed_index_jobs_for(
+        &self,
+        memo_ids: &[i32],
+    ) -> Result<u64, ServiceError> {
+        self.requeue_dead_jobs(Some(memo_ids)).await
+    }
+
+    async fn requeue_dead_jobs(&self, memo_ids: Option<&[i32]>) -> Result<u64, ServiceError> {
+        let txn = self.db.begin().await?;
+        let memo_ids = self.job_repo.requeue_dead(&txn, memo_ids).await?;
+        self.memo_repo
+            .set_index_status_many(&txn, &memo_ids, IndexStatus::Pending)
+            .await?;
+        txn.commit().await?;
+
+        Ok(memo_ids.len() as u64)
+    }
+
+    pub(super) fn lease(&self) -> chrono::Duration {
+        chrono::Duration::from_std(self.indexing.lease).unwrap_or(chrono::Duration::minutes(5))
+    }
+
+    async fn run_jobs(&self, jobs: Vec<memo_index_job::Model>) -> IndexingReport {
+        let mut report = IndexingReport {
+            claimed: jobs.len(),
+            ..IndexingReport::default()
+        };
+
+        let semaphore = Arc::new(Semaphore::new(self.indexing.concurrency));
+        let mut tasks = JoinSet::new();
+        for job in jobs {
+            let service = self.clone();
+            let semaphore = semaphore.clone();
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                service.process_job(job).await
+            });
+        }
+
+        while let Some(result) = tasks.join_next().await {
+            match result {
+                Ok(Some(Outcome::Indexed)) => report.indexed += 1,
+                Ok(Some(Outcome::Retried)) => report.retried += 1,
+                Ok(Some(Outcome::Dead)) => report.dead += 1,
+                Ok(Some(Outcome::Superseded)) => {}
+                // 상태를 기록하지 못한 작업은 임대가 만료된 뒤 다시 처리된다
+                Ok(None) => {}
+                Err(e) => error!("Memo indexing task panicked: {}", e),
+            }
+        }
+
+        report
+    }
+
+    async fn process_job(&self, job: memo_index_job::Model) -> Option<Outcome> {
+        let result = match self.index(&job).await {
+            Ok(outcome) => Ok(outcome),
+            Err(failure) => self.record_failure(&job, failure).await,
+        };
+
+        result
+            .map_err(|e| warn!("Failed to record index job {}: {}", job.id, e))
+            .ok()
+    }
+
+    async fn index(&self, job: &memo_index_job::Model) -> Result<Outcome, Failure> {
+        let Some(memo) = self.memo_repo.find_by_id(job.memo_id).await? else {
+            return Ok(Outcome::Superseded);
+        };
+        let Some(project) = self.project_repo.find_by_id(memo.project_id).await? else {
+            return Ok(Outcome::Superseded);
+        };
+
+        Self::ensure_format(self.qdrant_repo.format_mismatch().await?)?;
+
+        let context = UsageContext {
+            user_id: project.user_id,
+            project_id: Some(memo.project_id),
+            feature: UsageFeature::MemoIndexing,
+        };
+        let vector = self
+            .usage
+            .track(
+                context,
+                self.embedder
+                    .embed(&memo.content, EmbeddingPurpose::RetrievalDocument),
+            )
+            .await?;
+
+        if let Err(e) = self
+            .qdrant_repo
+            .upsert_memo(memo.id, memo.project_id, vector)
+            .await
+        {
+            // 처리 중 별칭이 다른 형식의 컬렉션으로 옮겨졌으면 메모 문제가 아니다
+            Self::ensure_format(self.qdrant_repo.format_mismatch().await?)?;
+            return Err(e.into());
+        }
+
+        let txn = self.db.begin().await?;
+        if !self.job_repo.complete(&txn, job).await? {
+            txn.rollback().await?;
+            self.job_repo.release(job).await?;
+            return Ok(Outcome::Superseded);
+        }
+        self.memo_repo
+            .mark_indexed(&txn, memo.id, content_hash(&memo.content))
+            .await?;
+        txn.commit().await?;
+
+        Ok(Outcome::Indexed)
+    }
+
+    /// 형식이 다른 컬렉션에는 쓰지 않고, 서버가 새 설정으로 재시작될 때까지 시도 횟수를 늘리지 않고 미룬다
+    fn ensure_format(mismatch: Option<String>) -> Result<(), Failure> {
+        match mismatch {
+            Some(message) => Err(Failure::Outage {
+                message,
+                retry_after: None,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    async fn record_failure(
+        &self,
+        job: &memo_index_job::Model,
+        failure: Failure,
+    ) -> Result<Outcome, DbErr> {
+        let (message, retry_after, attempts, backoff) = match failure {
+            Failure::Outage {
+                message,
+                retry_after,
+            } => (
+                message,
+                retry_after,
+                job.attempts - 1,
+                self.indexing.outage_backoff,
+            ),
+            Failure::Retryable {
+                message,
+                retry_after,
+            } if job.attempts < self.indexing.max_attempts => (
+                message,
+                retry_after,
+                job.attempts,
+                self.indexing.backoff(job.attempts),
+            ),
+            Failure::Retryable { message, .. } | Failure::Permanent(message) => {
+                return self.mark_dead(job, &message).await;
+            }
+        };
+
+        let wait = backoff.max(retry_after.unwrap_or_default());
+        let run_after = Utc::now().naive_utc()
+            + chrono::Duration::from_std(wait).unwrap_or(chrono::Duration::zero());
+
+        warn!(
+            "Indexing memo {} failed (attempt {}), retrying in {:?}: {}",
+            job.memo_id, job.attempts, wait, message
+        );
+        if self
+            .job_repo
+            .reschedule(job, &message, run_after, attempts)
+            .await?
+        {
+            Ok(Outcome::Retried)
+        } else {
+            self.job_repo.release(job).await?;
+            Ok(Outcome::Superseded)
+        }
+    }
+
+    async fn mark_dead(
+        &self,
+        job: &memo_index_job::Model,
+        message: &str,
+    ) -> Result<Outcome, DbErr> {
+        error!(
+            "Giving up indexing memo {} after {} attempt(s): {}",
+            job.memo_id, job.attempts, message
+        );
+
+        let txn = self.db.begin().await?;
+        if !self.job_repo.mark_dead(&txn, job, message).await? {
+            txn.rollback().await?;
+            self.job_repo.release(job).await?;
+            return Ok(Outcome::Superseded);
+        }
+        self.memo_repo
+            .set_index_status(&txn, job.memo_id, IndexStatus::Failed)
+            .await?;
+        txn.commit().await?;
+
+        Ok(Outcome::Dead)
+    }
+}
@@ -1,19 +1,46 @@
-use sea_orm::DatabaseConnection;
+use sea_orm::{DatabaseConnection, TransactionTrait};
 use std::sync::Arc;
 
 use crate::{
     clients::Embedder,
+    entities::memo::IndexStatus,
     errors::ServiceError,
-    models::{CreateMemoRequest, MemoResponse, UpdateMemoRequest},
-    repositories::{MemoRepository, ProjectRepository, QdrantRepo},
+    models::{CreateMemoRequest, MemoResponse, Page, PageParams, UpdateMemoRequest},
+    repositories::{
+        IndexJobRepository, MemoRepository, PinnedOrderKey, ProjectRepository, QdrantRepo,
+        VectorDeletionRepository,
+    },
+    services::{
+        pagination::{decode_cursor, into_page},
+        QuotaConfig, QuotaService, UsageService,
+    },
+    utils::content_hash::content_hash,
 };
 
+mod indexing;
+mod reconcile;
+mod vector_cleanup;
+
+pub use indexing::{IndexingConfig, IndexingReport};
+pub use reconcile::{ReconcileConfig, ReconcileReport};
+pub use vector_cleanup::VectorCleanupReport;
+
+/// 메모 CRUD와 벡터 인덱싱.
+/// 메모 쓰기는 인덱싱 작업(outbox)만 같은 트랜잭션에 기록하고,
+/// 임베딩과 벡터 DB 저장은 [`MemoService::process_index_jobs`]를 실행하는 워커가 처리한다
 #[derive(Clone)]
 pub struct MemoService {
+    db: Arc<DatabaseConnection>,
     memo_repo: MemoRepository,
     project_repo: ProjectRepository,
+    job_repo: IndexJobRepository,
+    deletion_repo: VectorDeletionRepository,
     qdrant_repo: Arc<dyn QdrantRepo>,
     embedder: Arc<dyn Embedder>,
+    usage: UsageService,
+    quota: QuotaService,
+    indexing: IndexingConfig,
+    reconcile: ReconcileConfig,
 }
 
 impl MemoService {
@@ -24,9 +51,23 @@ impl MemoService {
     ) -> Self {
         Self {
             memo_repo: MemoRepository::new(db.clone()),
-            project_repo: ProjectRepository::new(db),
+            project_repo: ProjectRepository::new(db.clone()),
+            job_repo: IndexJobRepository::new(db.clone()),
+            deletion_repo: VectorDeletionRepository::new(db.clone()),
+            usage: UsageService::new(db.clone()),
+            quota: QuotaService::new(db.clone(), QuotaConfig::default()),
+            db,
             qdrant_repo,
             embedder,
+            indexing: IndexingConfig::default(),
+            reconcile: ReconcileConfig::default(),
+        }
+    }
+
+    pub fn with_quota_config(self, config: QuotaConfig) -> Self {
+        Self {
+            quota: self.quota.with_config(config),
+            ..self
         }
     }
 
@@ -46,15 +87,15 @@ impl MemoService {
             return Err(ServiceError::Unauthorized);
         }
 
+        self.quota.check_memo_embedding(user_id).await?;
+
+        let txn = self.db.begin().await?;
         let memo = self
             .memo_repo
-            .create(req.project_id, req.content.clone())
-            .await?;
-
-        let vector = self.embedder.embed(&req.content).await?;
-        self.qdrant_repo
-            .upsert_memo(memo.id, req.project_id, vector)
+            .create(&txn, req.project_id, req.content.clone())
             .await?;
+        self.job_repo.enqueue(&txn, memo.id).await?;
+        txn.commit().await?;
 
         Ok(MemoResponse::from(memo))
     }
@@ -80,11 +121,28 @@ impl MemoService {
         Ok(MemoResponse::from(memo))
     }
 
+    /// 사용자의 모든 프로젝트의 메모를 고정 여부, 수정 시각 순으로 한 페이지 조회
+    pub async fn list_memos(
+        &self,
+        user_id: i32,
+        page: &PageParams,
+    ) -> Result<Page<MemoResponse>, ServiceError> {
+        let after = page.cursor.as_deref().map(decode_cursor).transpose()?;
+        let limit = page.limit();
+
+        let memos = self
+            .memo_repo
+            .find_page_by_user_id(user_id, after, limit + 1)
+            .await?;
+        Ok(into_page::<PinnedOrderKey, _, _>(memos, limit))
+    }
+
     pub async fn list_memos_by_project(
         &self,
         user_id: i32,
         project_id: i32,
-    ) -> Result<Vec<MemoResponse>, ServiceError> {
+        page: &PageParams,
+    ) -> Result<Page<MemoResponse>, ServiceError> {
         // Project 권한 검증
         let project = self
             .project_repo
@@ -96,8 +154,14 @@ impl MemoService {
             return Err(ServiceError::Unauthorized);
         }
 
-        let memos = self.memo_repo.find_by_project_id(project_id).await?;
-        Ok(memos.into_iter().map(MemoResponse::from).collect())
+        let after = page.cursor.as_deref().map(decode_cursor).transpose()?;
+        let limit = page.limit();
+
+        let memos = self
+            .memo_repo
+            .find_page_by_project_id(project_id, after, limit + 1)
+            .await?;
+        Ok(into_page::<PinnedOrderKey, _, _>(memos, limit))
     }
 
     pub async fn update_memo(
@@ -123,12 +187,26 @@ impl MemoService {
             return Err(ServiceError::Unauthorized);
         }
 
-        let updated_memo = self.memo_repo.update(memo_id, req.content.clone()).await?;
+        // 마지막으로 인덱싱한 내용과 같으면 다시 임베딩하지 않는다
+        let needs_indexing = memo.index_status != IndexStatus::Indexed
+            || memo.content_hash.as_deref() != Some(&content_hash(&req.content));
+
+        let index_status = if needs_indexing {
+            self.quota.check_memo_embedding(user_id).await?;
+            IndexStatus::Pending
+        } else {
+            memo.index_status
+        };
 
-        let vector = self.embedder.embed(&req.content).await?;
-        self.qdrant_repo
-            .upsert_memo(memo_id, memo.project_id, vector)
+        let txn = self.db.begin().await?;
+        let updated_memo = self
+            .memo_repo
+            .update(&txn, memo, req.content.clone(), index_status)
             .await?;
+        if needs_indexing {
+            self.job_repo.enqueue(&txn, memo_id).await?;
+        }
+        txn.commit().await?;
 
         Ok(MemoResponse::from(updated_memo))
     }
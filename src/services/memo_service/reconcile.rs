@@ -0,0 +1,164 @@
+use chrono::Utc;
+use sea_orm::TransactionTrait;
+use std::{collections::HashSet, time::Duration};
+use tracing::warn;
+
+use super::MemoService;
+use crate::{
+    entities::memo::IndexStatus,
+    errors::ServiceError,
+    utils::env::{env_or, InvalidEnvVar},
+};
+
+/// Postgres 메모와 벡터 DB 포인트 대조 작업 설정
+#[derive(Debug, Clone)]
+pub struct ReconcileConfig {
+    /// 스케줄러 실행 여부
+    pub enabled: bool,
+    /// 대조 작업 실행 주기
+    pub interval: Duration,
+    /// 벡터 DB와 메모 테이블을 한 번에 읽는 개수
+    pub batch_size: u64,
+}
+
+impl Default for ReconcileConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval: Duration::from_secs(6 * 60 * 60),
+            batch_size: 500,
+        }
+    }
+}
+
+impl ReconcileConfig {
+    /// RECONCILE_ENABLED, RECONCILE_INTERVAL_MINUTES, RECONCILE_BATCH_SIZE 환경 변수로 기본값을 덮어쓴다
+    pub fn from_env() -> Result<Self, InvalidEnvVar> {
+        let default = Self::default();
+        let interval_minutes: u64 = env_or(
+            "RECONCILE_INTERVAL_MINUTES",
+            default.interval.as_secs() / 60,
+        )?;
+
+        Ok(Self {
+            enabled: env_or("RECONCILE_ENABLED", default.enabled)?,
+            interval: Duration::from_secs(interval_minutes.max(1) * 60),
+            batch_size: env_or("RECONCILE_BATCH_SIZE", default.batch_size)?.max(1),
+        })
+    }
+}
+
+/// 대조 작업 결과
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReconcileReport {
+    /// 확인한 벡터 DB 포인트 수
+    pub points_scanned: usize,
+    /// 메모가 없어 삭제한 포인트 수
+    pub orphans_deleted: usize,
+    /// 인덱싱된 것으로 기록되어 있지만 포인트가 없어 다시 인덱싱 작업을 등록한 메모 수
+    pub missing_requeued: usize,
+}
+
+impl MemoService {
+    pub fn with_reconcile_config(self, config: ReconcileConfig) -> Self {
+        Self {
+            reconcile: config,
+            ..self
+        }
+    }
+
+    pub fn reconcile_config(&self) -> &ReconcileConfig {
+        &self.reconcile
+    }
+
+    /// 벡터 DB 전체를 메모 테이블과 대조하여 고아 포인트를 삭제하고 누락된 메모를 다시 인덱싱.
+    /// 재인덱싱은 인덱싱 작업으로 등록되어 워커가 처리한다
+    pub async fn reconcile_index(&self) -> Result<ReconcileReport, ServiceError> {
+        self.reconcile(None).await
+    }
+
+    /// 한 프로젝트의 메모와 포인트만 대조
+    pub async fn reconcile_project_index(
+        &self,
+        project_id: i32,
+    ) -> Result<ReconcileReport, ServiceError> {
+        self.reconcile(Some(project_id)).await
+    }
+
+    async fn reconcile(&self, project_id: Option<i32>) -> Result<ReconcileReport, ServiceError> {
+        // 대조 중에 인덱싱된 메모를 누락으로 오인하지 않도록 시작 전에 인덱싱된 메모만 확인
+        let started_at = Utc::now().naive_utc();
+        let batch_size = self.reconcile.batch_size.max(1);
+        let mut report = ReconcileReport::default();
+
+        // 1. 포인트를 순회하며 메모가 없는 포인트 삭제 (삭제된 메모, 삭제된 프로젝트)
+        let mut point_ids = HashSet::new();
+        let mut after = None;
+        loop {
+            let page = self
+                .qdrant_repo
+                .scroll_memo_ids(project_id, after, batch_size)
+                .await?;
+            let Some(&last) = page.last() else {
+                break;
+            };
+            after = Some(last);
+            report.points_scanned += page.len();
+
+            let existing: HashSet<i32> = self
+                .memo_repo
+                .find_existing_ids(&page, project_id)
+                .await?
+                .into_iter()
+                .collect();
+            for memo_id in page {
+                if existing.contains(&memo_id) {
+                    point_ids.insert(memo_id);
+                    continue;
+                }
+                self.qdrant_repo.delete_memo(memo_id).await?;
+                report.orphans_deleted += 1;
+            }
+        }
+
+        // 2. 인덱싱된 메모 중 포인트가 없는 메모를 다시 인덱싱 대기 상태로
+        let mut after = 0;
+        loop {
+            let page = self
+                .memo_repo
+                .find_indexed_ids(project_id, started_at, after, batch_size)
+                .await?;
+            let Some(&last) = page.last() else {
+                break;
+            };
+            after = last;
+
+            let missing: Vec<i32> = page
+                .into_iter()
+                .filter(|memo_id| !point_ids.contains(memo_id))
+                .collect();
+            if missing.is_empty() {
+                continue;
+            }
+
+            let txn = self.db.begin().await?;
+            for &memo_id in &missing {
+                self.memo_repo
+                    .set_index_status(&txn, memo_id, IndexStatus::Pending)
+                    .await?;
+                self.job_repo.enqueue(&txn, memo_id).await?;
+            }
+            txn.commit().await?;
+            report.missing_requeued += missing.len();
+        }
+
+        if report.orphans_deleted > 0 || report.missing_requeued > 0 {
+            warn!(
+                "Vector index drift: deleted {} orphan point(s), requeued {} memo(s)",
+                report.orphans_deleted, report.missing_requeued
+            );
+        }
+
+        Ok(report)
+    }
+}
@@ -1,10 +1,15 @@
 use super::*;
 use crate::{
+    clients::{ClientError, EmbeddingPurpose},
     db,
-    entities::user,
+    entities::{
+        memo_index_job::{self, JobStatus},
+        user,
+    },
     models::project_dto::CreateProjectRequest,
+    repositories::{InMemoryVectorRepository, VectorDeletionRepository, VectorSpec, VectorStore},
     services::ProjectService,
-    test_utils::{MockGeminiClient, MockQdrantRepository},
+    test_utils::{mock_qdrant_repository, MockGeminiClient},
 };
 use chrono::Utc;
 use rand::Rng;
@@ -55,7 +60,7 @@ async fn setup_test_db_with_project() -> (Arc<DatabaseConnection>, i32, i32) {
 #[tokio::test]
 async fn test_create_and_get_memo() {
     let (db, user_id, project_id) = setup_test_db_with_project().await;
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let service = MemoService::new(db, qdrant_repo, embedder as Arc<dyn Embedder>);
 
@@ -77,7 +82,7 @@ async fn test_create_and_get_memo() {
 #[tokio::test]
 async fn test_get_memo_unauthorized() {
     let (db, user_id, project_id) = setup_test_db_with_project().await;
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let service = MemoService::new(db, qdrant_repo, embedder as Arc<dyn Embedder>);
 
@@ -95,7 +100,7 @@ async fn test_get_memo_unauthorized() {
 #[tokio::test]
 async fn test_update_memo() {
     let (db, user_id, project_id) = setup_test_db_with_project().await;
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let service = MemoService::new(db, qdrant_repo, embedder as Arc<dyn Embedder>);
 
@@ -120,7 +125,7 @@ async fn test_update_memo() {
 #[tokio::test]
 async fn test_toggle_pin() {
     let (db, user_id, project_id) = setup_test_db_with_project().await;
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let service = MemoService::new(db, qdrant_repo, embedder as Arc<dyn Embedder>);
 
@@ -141,7 +146,7 @@ async fn test_toggle_pin() {
 #[tokio::test]
 async fn test_list_memos_ordering() {
     let (db, user_id, project_id) = setup_test_db_with_project().await;
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let service = MemoService::new(db, qdrant_repo, embedder as Arc<dyn Embedder>);
 
@@ -170,9 +175,10 @@ async fn test_list_memos_ordering() {
     service.toggle_pin(user_id, memo1.id).await.unwrap();
 
     let memos = service
-        .list_memos_by_project(user_id, project_id)
+        .list_memos_by_project(user_id, project_id, &PageParams::default())
         .await
-        .unwrap();
+        .unwrap()
+        .items;
 
     assert!(memos[0].is_pinned);
     assert_eq!(memos[0].id, memo1.id);
@@ -183,7 +189,7 @@ async fn test_list_memos_ordering() {
 #[tokio::test]
 async fn test_delete_memo() {
     let (db, user_id, project_id) = setup_test_db_with_project().await;
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let service = MemoService::new(db, qdrant_repo, embedder as Arc<dyn Embedder>);
 
@@ -202,7 +208,7 @@ async fn test_delete_memo() {
 #[tokio::test]
 async fn test_memo_project_isolation() {
     let (db, user_id, project1_id) = setup_test_db_with_project().await;
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let service = MemoService::new(db.clone(), qdrant_repo, embedder as Arc<dyn Embedder>);
 
@@ -252,25 +258,1095 @@ async fn test_memo_project_isolation() {
         .unwrap();
 
     let project1_memos = service
-        .list_memos_by_project(user_id, project1_id)
+        .list_memos_by_project(user_id, project1_id, &PageParams::default())
         .await
-        .unwrap();
+        .unwrap()
+        .items;
     assert_eq!(project1_memos.len(), 2);
     assert!(project1_memos
         .iter()
         .all(|m| m.content.contains("Project 1")));
 
     let project2_memos = service
-        .list_memos_by_project(user_id, project2.id)
+        .list_memos_by_project(user_id, project2.id, &PageParams::default())
         .await
-        .unwrap();
+        .unwrap()
+        .items;
     assert_eq!(project2_memos.len(), 1);
     assert!(project2_memos
         .iter()
         .all(|m| m.content.contains("Project 2")));
 
     let result = service
-        .list_memos_by_project(user_id + 999, project1_id)
+        .list_memos_by_project(user_id + 999, project1_id, &PageParams::default())
         .await;
     assert!(matches!(result, Err(ServiceError::Unauthorized)));
 }
+
+/// 서킷 브레이커가 열린 상태를 흉내 내는 임베더
+struct OpenCircuitEmbedder;
+
+#[async_trait::async_trait]
+impl Embedder for OpenCircuitEmbedder {
+    async fn embed(
+        &self,
+        _text: &str,
+        _purpose: EmbeddingPurpose,
+    ) -> Result<Vec<f32>, ClientError> {
+        Err(ClientError::CircuitOpen(
+            "embedding provider is failing".to_string(),
+        ))
+    }
+
+    fn dimension(&self) -> usize {
+        768
+    }
+
+    fn model_id(&self) -> &str {
+        "mock-embedding"
+    }
+}
+
+/// 재시도해도 성공할 수 없는 오류를 반환하는 임베더
+struct RejectingEmbedder;
+
+#[async_trait::async_trait]
+impl Embedder for RejectingEmbedder {
+    async fn embed(
+        &self,
+        _text: &str,
+        _purpose: EmbeddingPurpose,
+    ) -> Result<Vec<f32>, ClientError> {
+        Err(ClientError::GeminiApi(
+            "API request failed with status 400 Bad Request".to_string(),
+        ))
+    }
+
+    fn dimension(&self) -> usize {
+        768
+    }
+
+    fn model_id(&self) -> &str {
+        "mock-embedding"
+    }
+}
+
+/// 요청마다 시간 초과로 실패하는 임베더
+struct TimeoutEmbedder;
+
+#[async_trait::async_trait]
+impl Embedder for TimeoutEmbedder {
+    async fn embed(
+        &self,
+        _text: &str,
+        _purpose: EmbeddingPurpose,
+    ) -> Result<Vec<f32>, ClientError> {
+        Err(ClientError::Timeout(
+            "embedding request timed out".to_string(),
+        ))
+    }
+
+    fn dimension(&self) -> usize {
+        768
+    }
+
+    fn model_id(&self) -> &str {
+        "mock-embedding"
+    }
+}
+
+async fn create(service: &MemoService, user_id: i32, project_id: i32, content: &str) -> i32 {
+    service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                project_id,
+                content: content.to_string(),
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+/// `next_cursor`를 따라 모든 페이지를 조회해 메모 ID를 순서대로 모은다
+async fn list_all_pages(
+    service: &MemoService,
+    user_id: i32,
+    project_id: Option<i32>,
+    limit: u64,
+) -> Vec<i32> {
+    let mut ids = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = PageParams {
+            limit: Some(limit),
+            cursor,
+        };
+        let page = match project_id {
+            Some(project_id) => {
+                service
+                    .list_memos_by_project(user_id, project_id, &page)
+                    .await
+            }
+            None => service.list_memos(user_id, &page).await,
+        }
+        .unwrap();
+        assert!(page.items.len() <= limit as usize);
+        ids.extend(page.items.iter().map(|m| m.id));
+
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => return ids,
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_list_memos_by_project_pages_with_cursor() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = MemoService::new(
+        db,
+        Arc::new(mock_qdrant_repository()),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    );
+
+    let mut ids = Vec::new();
+    for i in 0..5 {
+        ids.push(create(&service, user_id, project_id, &format!("메모 {}", i)).await);
+    }
+    service.toggle_pin(user_id, ids[1]).await.unwrap();
+
+    // 고정된 메모가 먼저, 나머지는 최근 수정 순. 페이지 경계에서 빠지거나 겹치는 메모가 없다
+    let expected = vec![ids[1], ids[4], ids[3], ids[2], ids[0]];
+    assert_eq!(
+        list_all_pages(&service, user_id, Some(project_id), 2).await,
+        expected
+    );
+    assert_eq!(
+        list_all_pages(&service, user_id, Some(project_id), 5).await,
+        expected
+    );
+}
+
+#[tokio::test]
+async fn test_list_memos_across_projects() {
+    let (db, user_id, project1_id) = setup_test_db_with_project().await;
+    let service = MemoService::new(
+        db.clone(),
+        Arc::new(mock_qdrant_repository()),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    );
+    let project2_id = ProjectService::new(db.clone())
+        .create_project(
+            user_id,
+            CreateProjectRequest {
+                name: format!("Project 2 {}", Utc::now().timestamp_micros()),
+                description: None,
+            },
+        )
+        .await
+        .unwrap()
+        .id;
+    let (_, other_user_id, other_project_id) = setup_test_db_with_project().await;
+
+    let first = create(&service, user_id, project1_id, "프로젝트 1의 메모").await;
+    let second = create(&service, user_id, project2_id, "프로젝트 2의 메모").await;
+    let third = create(&service, user_id, project1_id, "프로젝트 1의 두 번째 메모").await;
+    create(
+        &service,
+        other_user_id,
+        other_project_id,
+        "다른 사용자의 메모",
+    )
+    .await;
+
+    assert_eq!(
+        list_all_pages(&service, user_id, None, 2).await,
+        vec![third, second, first]
+    );
+}
+
+#[tokio::test]
+async fn test_list_memos_rejects_invalid_cursor() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = MemoService::new(
+        db,
+        Arc::new(mock_qdrant_repository()),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    );
+
+    let page = PageParams {
+        limit: None,
+        cursor: Some("not-a-cursor".to_string()),
+    };
+    let result = service
+        .list_memos_by_project(user_id, project_id, &page)
+        .await;
+    assert!(matches!(result, Err(ServiceError::InvalidCursor)));
+    let result = service.list_memos(user_id, &page).await;
+    assert!(matches!(result, Err(ServiceError::InvalidCursor)));
+}
+
+#[tokio::test]
+async fn test_create_memo_enqueues_index_job() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
+    let service = MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    );
+
+    let created = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                project_id,
+                content: "나중에 인덱싱될 메모".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(created.index_status, IndexStatus::Pending);
+
+    let job_repo = IndexJobRepository::new(db.clone());
+    assert!(job_repo
+        .find_by_memo_id(created.id)
+        .await
+        .unwrap()
+        .is_some());
+    let indexed = qdrant_repo
+        .scroll_memo_ids(Some(project_id), None, 10)
+        .await
+        .unwrap();
+    assert!(!indexed.contains(&created.id));
+
+    let report = service.process_index_jobs_for(&[created.id]).await.unwrap();
+    assert_eq!(report.indexed, 1);
+
+    let fetched = service.get_memo(user_id, created.id).await.unwrap();
+    assert_eq!(fetched.index_status, IndexStatus::Indexed);
+    assert!(job_repo
+        .find_by_memo_id(created.id)
+        .await
+        .unwrap()
+        .is_none());
+    let indexed = qdrant_repo
+        .scroll_memo_ids(Some(project_id), None, 10)
+        .await
+        .unwrap();
+    assert!(indexed.contains(&created.id));
+}
+
+#[tokio::test]
+async fn test_open_circuit_does_not_fail_save_and_retries_later() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
+    let degraded = MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        Arc::new(OpenCircuitEmbedder) as Arc<dyn Embedder>,
+    );
+
+    let memo_id = create(&degraded, user_id, project_id, "장애 중에 작성한 메모").await;
+
+    let report = degraded.process_index_jobs_for(&[memo_id]).await.unwrap();
+    assert_eq!(report.retried, 1);
+
+    let job_repo = IndexJobRepository::new(db.clone());
+    let job = job_repo.find_by_memo_id(memo_id).await.unwrap().unwrap();
+    assert_eq!(job.status, JobStatus::Pending);
+    // 프로바이더 장애는 시도 횟수에 넣지 않는다
+    assert_eq!(job.attempts, 0);
+    assert!(job.last_error.unwrap().contains("circuit is open"));
+    assert!(job.run_after > Utc::now().naive_utc());
+
+    // 백오프가 끝나기 전에는 다시 가져가지 않는다
+    let report = degraded.process_index_jobs_for(&[memo_id]).await.unwrap();
+    assert_eq!(report.claimed, 0);
+
+    // 프로바이더가 복구되고 재시도 시각이 지나면 인덱싱된다
+    let mut job: memo_index_job::ActiveModel = job_repo
+        .find_by_memo_id(memo_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .into();
+    job.run_after = Set(Utc::now().naive_utc());
+    job.update(db.as_ref()).await.unwrap();
+
+    let recovered = MemoService::new(
+        db,
+        qdrant_repo.clone(),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    );
+    let report = recovered.process_index_jobs_for(&[memo_id]).await.unwrap();
+    assert_eq!(report.indexed, 1);
+
+    let memo = recovered.get_memo(user_id, memo_id).await.unwrap();
+    assert_eq!(memo.index_status, IndexStatus::Indexed);
+    let indexed = qdrant_repo
+        .scroll_memo_ids(Some(project_id), None, 10)
+        .await
+        .unwrap();
+    assert!(indexed.contains(&memo_id));
+}
+
+#[tokio::test]
+async fn test_permanent_failure_moves_job_to_dead_letter() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = MemoService::new(
+        db.clone(),
+        Arc::new(mock_qdrant_repository()),
+        Arc::new(RejectingEmbedder) as Arc<dyn Embedder>,
+    );
+
+    let memo_id = create(&service, user_id, project_id, "인덱싱할 수 없는 메모").await;
+
+    let report = service.process_index_jobs_for(&[memo_id]).await.unwrap();
+    assert_eq!(report.dead, 1);
+
+    let job_repo = IndexJobRepository::new(db.clone());
+    let job = job_repo.find_by_memo_id(memo_id).await.unwrap().unwrap();
+    assert_eq!(job.status, JobStatus::Dead);
+    assert!(job.last_error.unwrap().contains("400"));
+    let memo = service.get_memo(user_id, memo_id).await.unwrap();
+    assert_eq!(memo.index_status, IndexStatus::Failed);
+
+    // dead 작업은 워커가 다시 가져가지 않는다
+    let report = service.process_index_jobs_for(&[memo_id]).await.unwrap();
+    assert_eq!(report.claimed, 0);
+
+    // 메모를 수정하면 같은 내용이어도 작업이 다시 등록된다
+    let updated = service
+        .update_memo(
+            user_id,
+            memo_id,
+            UpdateMemoRequest {
+                content: "인덱싱할 수 없는 메모".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.index_status, IndexStatus::Pending);
+    let job = job_repo.find_by_memo_id(memo_id).await.unwrap().unwrap();
+    assert_eq!(job.status, JobStatus::Pending);
+    assert_eq!(job.attempts, 0);
+}
+
+#[tokio::test]
+async fn test_retryable_failures_are_dead_lettered_after_max_attempts() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = MemoService::new(
+        db.clone(),
+        Arc::new(mock_qdrant_repository()),
+        Arc::new(TimeoutEmbedder) as Arc<dyn Embedder>,
+    )
+    .with_indexing_config(IndexingConfig {
+        max_attempts: 2,
+        base_backoff: std::time::Duration::ZERO,
+        ..IndexingConfig::default()
+    });
+
+    let memo_id = create(&service, user_id, project_id, "계속 실패하는 메모").await;
+
+    let first = service.process_index_jobs_for(&[memo_id]).await.unwrap();
+    assert_eq!(first.retried, 1);
+    let second = service.process_index_jobs_for(&[memo_id]).await.unwrap();
+    assert_eq!(second.dead, 1);
+
+    let memo = service.get_memo(user_id, memo_id).await.unwrap();
+    assert_eq!(memo.index_status, IndexStatus::Failed);
+}
+
+#[tokio::test]
+async fn test_provider_outage_is_retried_without_dead_lettering() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = MemoService::new(
+        db.clone(),
+        Arc::new(mock_qdrant_repository()),
+        Arc::new(OpenCircuitEmbedder) as Arc<dyn Embedder>,
+    )
+    .with_indexing_config(IndexingConfig {
+        max_attempts: 2,
+        outage_backoff: std::time::Duration::ZERO,
+        ..IndexingConfig::default()
+    });
+
+    let memo_id = create(&service, user_id, project_id, "긴 장애 중에 작성한 메모").await;
+
+    for _ in 0..5 {
+        let report = service.process_index_jobs_for(&[memo_id]).await.unwrap();
+        assert_eq!(report.retried, 1);
+    }
+
+    let job = IndexJobRepository::new(db)
+        .find_by_memo_id(memo_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(job.status, JobStatus::Pending);
+    assert_eq!(job.attempts, 0);
+    let memo = service.get_memo(user_id, memo_id).await.unwrap();
+    assert_eq!(memo.index_status, IndexStatus::Pending);
+}
+
+#[tokio::test]
+async fn test_indexing_waits_while_active_collection_has_another_format() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let store = VectorStore::from_repo(InMemoryVectorRepository::new(VectorSpec::new(
+        "mock-embedding",
+        768,
+    )));
+    let service = MemoService::new(
+        db.clone(),
+        store.repo.clone(),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    )
+    .with_indexing_config(IndexingConfig {
+        max_attempts: 1,
+        outage_backoff: std::time::Duration::ZERO,
+        ..IndexingConfig::default()
+    });
+
+    let memo_id = create(&service, user_id, project_id, "재인덱싱 직후에 작성한 메모").await;
+
+    // 다른 서버가 새 모델로 재인덱싱을 마치고 별칭을 옮김
+    let next = VectorSpec::new("next-embedding", 1024);
+    store
+        .collections
+        .create_collection("memo_embeddings_v_next", &next)
+        .await
+        .unwrap();
+    store
+        .collections
+        .switch_alias("memo_embeddings_v_next")
+        .await
+        .unwrap();
+
+    for _ in 0..3 {
+        let report = service.process_index_jobs_for(&[memo_id]).await.unwrap();
+        assert_eq!(report.retried, 1);
+    }
+    assert!(matches!(
+        service.process_index_jobs().await,
+        Err(ServiceError::VectorFormatMismatch(_))
+    ));
+
+    let job = IndexJobRepository::new(db)
+        .find_by_memo_id(memo_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(job.status, JobStatus::Pending);
+    assert_eq!(job.attempts, 0);
+    assert!(store
+        .repo
+        .scroll_memo_ids(None, None, 10)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_requeue_failed_index_jobs() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
+    let rejecting = MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        Arc::new(RejectingEmbedder) as Arc<dyn Embedder>,
+    );
+
+    let memo_id = create(&rejecting, user_id, project_id, "다시 인덱싱할 메모").await;
+    let report = rejecting.process_index_jobs_for(&[memo_id]).await.unwrap();
+    assert_eq!(report.dead, 1);
+
+    let service = MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    );
+    let requeued = service
+        .requeue_failed_index_jobs_for(&[memo_id])
+        .await
+        .unwrap();
+    assert_eq!(requeued, 1);
+
+    let job = IndexJobRepository::new(db)
+        .find_by_memo_id(memo_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(job.status, JobStatus::Pending);
+    assert_eq!(job.attempts, 0);
+    let memo = service.get_memo(user_id, memo_id).await.unwrap();
+    assert_eq!(memo.index_status, IndexStatus::Pending);
+
+    let report = service.process_index_jobs_for(&[memo_id]).await.unwrap();
+    assert_eq!(report.indexed, 1);
+    assert!(qdrant_repo
+        .scroll_memo_ids(Some(project_id), None, 10)
+        .await
+        .unwrap()
+        .contains(&memo_id));
+}
+
+#[tokio::test]
+async fn test_edit_during_processing_is_not_lost() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = MemoService::new(
+        db.clone(),
+        Arc::new(mock_qdrant_repository()),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    );
+    let memo_id = create(&service, user_id, project_id, "처음 내용").await;
+
+    // 워커가 작업을 가져간 뒤 메모가 수정되면 완료 처리가 무시되고 작업이 다시 대기 상태가 된다
+    let job_repo = IndexJobRepository::new(db.clone());
+    let claimed = job_repo
+        .claim(Some(&[memo_id]), 1, chrono::Duration::minutes(5))
+        .await
+        .unwrap();
+    assert_eq!(claimed.len(), 1);
+
+    service
+        .update_memo(
+            user_id,
+            memo_id,
+            UpdateMemoRequest {
+                content: "수정된 내용".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    let job = job_repo.find_by_memo_id(memo_id).await.unwrap().unwrap();
+    assert_eq!(job.status, JobStatus::Processing);
+
+    assert!(!job_repo.complete(db.as_ref(), &claimed[0]).await.unwrap());
+    job_repo.release(&claimed[0]).await.unwrap();
+
+    let report = service.process_index_jobs_for(&[memo_id]).await.unwrap();
+    assert_eq!(report.indexed, 1);
+    let saved = MemoRepository::new(db)
+        .find_by_id(memo_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(saved.content_hash, Some(content_hash("수정된 내용")));
+}
+
+/// 동시에 진행 중인 임베딩 수의 최댓값을 기록하는 임베더
+#[derive(Default)]
+struct ConcurrencyProbe {
+    inner: MockGeminiClient,
+    in_flight: std::sync::atomic::AtomicUsize,
+    peak: std::sync::atomic::AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl Embedder for ConcurrencyProbe {
+    async fn embed(&self, text: &str, purpose: EmbeddingPurpose) -> Result<Vec<f32>, ClientError> {
+        use std::sync::atomic::Ordering;
+
+        let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(current, Ordering::SeqCst);
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        self.inner.embed(text, purpose).await
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension()
+    }
+
+    fn model_id(&self) -> &str {
+        self.inner.model_id()
+    }
+}
+
+#[tokio::test]
+async fn test_index_jobs_run_with_bounded_concurrency() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let probe = Arc::new(ConcurrencyProbe::default());
+    let service = MemoService::new(
+        db,
+        Arc::new(mock_qdrant_repository()),
+        probe.clone() as Arc<dyn Embedder>,
+    )
+    .with_indexing_config(IndexingConfig {
+        concurrency: 2,
+        ..IndexingConfig::default()
+    });
+
+    let mut memo_ids = Vec::new();
+    for i in 0..6 {
+        memo_ids.push(create(&service, user_id, project_id, &format!("메모 {}", i)).await);
+    }
+
+    let report = service.process_index_jobs_for(&memo_ids).await.unwrap();
+    assert_eq!(report.claimed, 6);
+    assert_eq!(report.indexed, 6);
+    assert_eq!(probe.peak.load(std::sync::atomic::Ordering::SeqCst), 2);
+}
+
+/// 임베딩 호출 횟수를 세는 임베더
+#[derive(Default)]
+struct CountingEmbedder {
+    inner: MockGeminiClient,
+    calls: std::sync::atomic::AtomicUsize,
+}
+
+impl CountingEmbedder {
+    fn calls(&self) -> usize {
+        self.calls.load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+#[async_trait::async_trait]
+impl Embedder for CountingEmbedder {
+    async fn embed(&self, text: &str, purpose: EmbeddingPurpose) -> Result<Vec<f32>, ClientError> {
+        self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        self.inner.embed(text, purpose).await
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension()
+    }
+
+    fn model_id(&self) -> &str {
+        self.inner.model_id()
+    }
+}
+
+#[tokio::test]
+async fn test_indexing_records_content_hash() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = MemoService::new(
+        db.clone(),
+        Arc::new(mock_qdrant_repository()),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    );
+
+    let memo_id = create(&service, user_id, project_id, "해시를 기록할 메모").await;
+    service.process_index_jobs_for(&[memo_id]).await.unwrap();
+
+    let saved = MemoRepository::new(db)
+        .find_by_id(memo_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(saved.content_hash, Some(content_hash("해시를 기록할 메모")));
+    assert!(saved.embedded_at.is_some());
+    assert_eq!(saved.index_status, IndexStatus::Indexed);
+}
+
+#[tokio::test]
+async fn test_update_memo_skips_embedding_when_content_unchanged() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let embedder = Arc::new(CountingEmbedder::default());
+    let service = MemoService::new(
+        db.clone(),
+        Arc::new(mock_qdrant_repository()),
+        embedder.clone() as Arc<dyn Embedder>,
+    );
+    let job_repo = IndexJobRepository::new(db);
+
+    let memo_id = create(&service, user_id, project_id, "같은 내용의 메모").await;
+    service.process_index_jobs_for(&[memo_id]).await.unwrap();
+    assert_eq!(embedder.calls(), 1);
+
+    // 클라이언트 재시도와 공백만 바뀐 수정은 다시 임베딩하지 않는다
+    for content in ["같은 내용의 메모", "같은  내용의 메모\n"] {
+        let updated = service
+            .update_memo(
+                user_id,
+                memo_id,
+                UpdateMemoRequest {
+                    content: content.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.content, content);
+        assert_eq!(updated.index_status, IndexStatus::Indexed);
+    }
+    assert!(job_repo.find_by_memo_id(memo_id).await.unwrap().is_none());
+
+    let updated = service
+        .update_memo(
+            user_id,
+            memo_id,
+            UpdateMemoRequest {
+                content: "달라진 내용의 메모".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.index_status, IndexStatus::Pending);
+    service.process_index_jobs_for(&[memo_id]).await.unwrap();
+    assert_eq!(embedder.calls(), 2);
+}
+
+#[tokio::test]
+async fn test_create_memo_enforces_hourly_embedding_quota() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = MemoService::new(
+        db.clone(),
+        Arc::new(mock_qdrant_repository()),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    )
+    .with_quota_config(QuotaConfig {
+        hourly_memo_embeds: 1,
+        ..QuotaConfig::unlimited()
+    });
+
+    let first = create(&service, user_id, project_id, "첫 번째 메모").await;
+    service.process_index_jobs_for(&[first]).await.unwrap();
+
+    let result = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                project_id,
+                content: "두 번째 메모".to_string(),
+            },
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::QuotaExceeded {
+            quota: "hourly_memo_embeds",
+            limit: 1,
+            ..
+        })
+    ));
+
+    // 초과한 요청의 메모는 저장되지 않는다
+    let memos = service
+        .list_memos_by_project(user_id, project_id, &PageParams::default())
+        .await
+        .unwrap()
+        .items;
+    assert_eq!(memos.len(), 1);
+
+    // 내용이 같아 다시 임베딩하지 않는 수정은 허용된다
+    service
+        .update_memo(
+            user_id,
+            first,
+            UpdateMemoRequest {
+                content: "첫 번째 메모".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_reconcile_deletes_orphans_and_requeues_missing_points() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
+    let service = MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    );
+
+    let kept = create(&service, user_id, project_id, "그대로 남는 메모").await;
+    let lost = create(&service, user_id, project_id, "포인트가 사라진 메모").await;
+    let deleted = create(&service, user_id, project_id, "DB에서만 삭제된 메모").await;
+    service
+        .process_index_jobs_for(&[kept, lost, deleted])
+        .await
+        .unwrap();
+
+    // 벡터 DB 저장 실패와 포인트 삭제 전 중단을 흉내 낸다
+    qdrant_repo.delete_memo(lost).await.unwrap();
+    MemoRepository::new(db.clone())
+        .delete(deleted)
+        .await
+        .unwrap();
+
+    let report = service.reconcile_project_index(project_id).await.unwrap();
+    assert_eq!(
+        report,
+        ReconcileReport {
+            points_scanned: 2,
+            orphans_deleted: 1,
+            missing_requeued: 1,
+        }
+    );
+
+    let memo = service.get_memo(user_id, lost).await.unwrap();
+    assert_eq!(memo.index_status, IndexStatus::Pending);
+    let report = service.process_index_jobs_for(&[lost]).await.unwrap();
+    assert_eq!(report.indexed, 1);
+
+    let mut points = qdrant_repo
+        .scroll_memo_ids(Some(project_id), None, 10)
+        .await
+        .unwrap();
+    points.sort_unstable();
+    assert_eq!(points, vec![kept, lost]);
+
+    // 차이가 없으면 아무것도 바꾸지 않는다
+    let report = service.reconcile_project_index(project_id).await.unwrap();
+    assert_eq!(report.orphans_deleted, 0);
+    assert_eq!(report.missing_requeued, 0);
+}
+
+#[tokio::test]
+async fn test_reconcile_requeues_memos_indexed_before_embedded_at_was_recorded() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
+    let service = MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    );
+
+    let legacy = create(
+        &service,
+        user_id,
+        project_id,
+        "마이그레이션 전에 인덱싱된 메모",
+    )
+    .await;
+    service.process_index_jobs_for(&[legacy]).await.unwrap();
+
+    // 마이그레이션으로 indexed 상태만 채워지고 포인트는 없는 메모를 흉내 낸다
+    crate::entities::memo::Entity::update_many()
+        .col_expr(
+            crate::entities::memo::Column::EmbeddedAt,
+            sea_query::Expr::value(Option::<chrono::NaiveDateTime>::None),
+        )
+        .filter(crate::entities::memo::Column::Id.eq(legacy))
+        .exec(db.as_ref())
+        .await
+        .unwrap();
+    qdrant_repo.delete_memo(legacy).await.unwrap();
+
+    let report = service.reconcile_project_index(project_id).await.unwrap();
+    assert_eq!(report.missing_requeued, 1);
+
+    let memo = service.get_memo(user_id, legacy).await.unwrap();
+    assert_eq!(memo.index_status, IndexStatus::Pending);
+}
+
+#[tokio::test]
+async fn test_reconcile_skips_memos_waiting_for_indexing() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = MemoService::new(
+        db,
+        Arc::new(mock_qdrant_repository()),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    )
+    .with_reconcile_config(ReconcileConfig {
+        batch_size: 1,
+        ..ReconcileConfig::default()
+    });
+
+    create(&service, user_id, project_id, "아직 인덱싱되지 않은 메모").await;
+
+    let report = service.reconcile_project_index(project_id).await.unwrap();
+    assert_eq!(report, ReconcileReport::default());
+}
+
+/// 연결할 수 없는 벡터 DB를 흉내 내는 저장소
+struct UnavailableVectorStore;
+
+#[async_trait::async_trait]
+impl QdrantRepo for UnavailableVectorStore {
+    async fn upsert_memo(
+        &self,
+        _memo_id: i32,
+        _user_id: i32,
+        _vector: Vec<f32>,
+    ) -> Result<(), DbErr> {
+        Err(DbErr::Custom("vector store is unavailable".to_string()))
+    }
+
+    async fn search_similar(
+        &self,
+        _user_id: i32,
+        _query_vector: Vec<f32>,
+        _limit: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        Err(DbErr::Custom("vector store is unavailable".to_string()))
+    }
+
+    async fn delete_memo(&self, _memo_id: i32) -> Result<(), DbErr> {
+        Err(DbErr::Custom("vector store is unavailable".to_string()))
+    }
+
+    async fn delete_by_project(&self, _project_id: i32) -> Result<(), DbErr> {
+        Err(DbErr::Custom("vector store is unavailable".to_string()))
+    }
+
+    async fn scroll_memo_ids(
+        &self,
+        _user_id: Option<i32>,
+        _after: Option<i32>,
+        _limit: u64,
+    ) -> Result<Vec<i32>, DbErr> {
+        Err(DbErr::Custom("vector store is unavailable".to_string()))
+    }
+}
+
+#[tokio::test]
+async fn test_delete_project_removes_vectors() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
+    let service = MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    );
+
+    let first = create(&service, user_id, project_id, "삭제될 프로젝트의 메모").await;
+    let second = create(&service, user_id, project_id, "함께 삭제될 메모").await;
+    service
+        .process_index_jobs_for(&[first, second])
+        .await
+        .unwrap();
+
+    ProjectService::new(db.clone())
+        .delete_project(user_id, project_id)
+        .await
+        .unwrap();
+
+    let report = service
+        .process_project_deletions_for(&[project_id])
+        .await
+        .unwrap();
+    assert_eq!(report.deleted, 1);
+    assert!(qdrant_repo
+        .scroll_memo_ids(Some(project_id), None, 10)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(VectorDeletionRepository::new(db)
+        .find_by_project_id(project_id)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_project_vector_deletion_retries_while_store_is_unavailable() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
+    let config = IndexingConfig {
+        base_backoff: std::time::Duration::ZERO,
+        ..IndexingConfig::default()
+    };
+    let service = MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    )
+    .with_indexing_config(config.clone());
+
+    let memo_id = create(&service, user_id, project_id, "벡터 DB 장애 중 삭제된 메모").await;
+    service.process_index_jobs_for(&[memo_id]).await.unwrap();
+    ProjectService::new(db.clone())
+        .delete_project(user_id, project_id)
+        .await
+        .unwrap();
+
+    let unavailable = MemoService::new(
+        db.clone(),
+        Arc::new(UnavailableVectorStore),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    )
+    .with_indexing_config(config);
+    let report = unavailable
+        .process_project_deletions_for(&[project_id])
+        .await
+        .unwrap();
+    assert_eq!(report.retried, 1);
+
+    let deletion_repo = VectorDeletionRepository::new(db);
+    let deletion = deletion_repo
+        .find_by_project_id(project_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(deletion.attempts, 1);
+    assert!(deletion
+        .last_error
+        .unwrap()
+        .contains("vector store is unavailable"));
+
+    // 벡터 DB가 복구되면 다음 실행에서 삭제된다
+    let report = service
+        .process_project_deletions_for(&[project_id])
+        .await
+        .unwrap();
+    assert_eq!(report.deleted, 1);
+    assert!(qdrant_repo
+        .scroll_memo_ids(Some(project_id), None, 10)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(deletion_repo
+        .find_by_project_id(project_id)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_hourly_embedding_quota_counts_queued_jobs() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = MemoService::new(
+        db.clone(),
+        Arc::new(mock_qdrant_repository()),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+    )
+    .with_quota_config(QuotaConfig {
+        hourly_memo_embeds: 2,
+        ..QuotaConfig::unlimited()
+    });
+
+    // 워커가 처리하기 전에 몰아서 만든 메모도 할당량에 포함된다
+    let first = create(&service, user_id, project_id, "첫 번째 메모").await;
+    create(&service, user_id, project_id, "두 번째 메모").await;
+
+    let result = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                project_id,
+                content: "세 번째 메모".to_string(),
+            },
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::QuotaExceeded {
+            quota: "hourly_memo_embeds",
+            ..
+        })
+    ));
+
+    let result = service
+        .update_memo(
+            user_id,
+            first,
+            UpdateMemoRequest {
+                content: "고친 첫 번째 메모".to_string(),
+            },
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::QuotaExceeded {
+            quota: "hourly_memo_embeds",
+            ..
+        })
+    ));
+}
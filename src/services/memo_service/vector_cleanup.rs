@@ -0,0 +1,75 @@
+use chrono::Utc;
+use tracing::warn;
+
+use super::MemoService;
+use crate::{entities::project_vector_deletion, errors::ServiceError};
+
+/// 프로젝트 벡터 삭제 작업 처리 결과
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VectorCleanupReport {
+    pub claimed: usize,
+    pub deleted: usize,
+    pub retried: usize,
+}
+
+impl MemoService {
+    /// 삭제된 프로젝트의 벡터를 벡터 DB에서 지운다.
+    /// 벡터 DB에 연결할 수 없으면 인덱싱 작업과 같은 백오프로 성공할 때까지 다시 시도한다
+    pub async fn process_project_deletions(&self) -> Result<VectorCleanupReport, ServiceError> {
+        let deletions = self
+            .deletion_repo
+            .claim(None, self.indexing.batch_size, self.lease())
+            .await?;
+        self.run_deletions(deletions).await
+    }
+
+    /// 지정한 프로젝트의 벡터 삭제 작업만 즉시 처리
+    pub async fn process_project_deletions_for(
+        &self,
+        project_ids: &[i32],
+    ) -> Result<VectorCleanupReport, ServiceError> {
+        let deletions = self
+            .deletion_repo
+            .claim(Some(project_ids), project_ids.len() as u64, self.lease())
+            .await?;
+        self.run_deletions(deletions).await
+    }
+
+    async fn run_deletions(
+        &self,
+        deletions: Vec<project_vector_deletion::Model>,
+    ) -> Result<VectorCleanupReport, ServiceError> {
+        let mut report = VectorCleanupReport {
+            claimed: deletions.len(),
+            ..VectorCleanupReport::default()
+        };
+
+        for deletion in deletions {
+            match self
+                .qdrant_repo
+                .delete_by_project(deletion.project_id)
+                .await
+            {
+                Ok(()) => {
+                    self.deletion_repo.complete(&deletion).await?;
+                    report.deleted += 1;
+                }
+                Err(e) => {
+                    let wait = self.indexing.backoff(deletion.attempts);
+                    warn!(
+                        "Deleting vectors of project {} failed (attempt {}), retrying in {:?}: {}",
+                        deletion.project_id, deletion.attempts, wait, e
+                    );
+                    let run_after = Utc::now().naive_utc()
+                        + chrono::Duration::from_std(wait).unwrap_or(chrono::Duration::zero());
+                    self.deletion_repo
+                        .reschedule(&deletion, &e.to_string(), run_after)
+                        .await?;
+                    report.retried += 1;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
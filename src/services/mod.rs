@@ -1,13 +1,27 @@
 pub mod assist_service;
+pub mod digest_service;
 pub mod essay_service;
 pub mod memo_service;
+mod pagination;
 pub mod project_service;
+pub mod quota_service;
+pub mod reindex_service;
 pub mod token_service;
+pub mod translation_service;
+pub mod usage_service;
 pub mod user_service;
 
 pub use assist_service::AssistService;
+pub use digest_service::{DigestConfig, DigestService};
 pub use essay_service::EssayService;
-pub use memo_service::MemoService;
+pub use memo_service::{
+    IndexingConfig, IndexingReport, MemoService, ReconcileConfig, ReconcileReport,
+    VectorCleanupReport,
+};
 pub use project_service::ProjectService;
+pub use quota_service::{QuotaConfig, QuotaService};
+pub use reindex_service::{ReindexOverview, ReindexReport, ReindexService};
 pub use token_service::TokenService;
+pub use translation_service::TranslationService;
+pub use usage_service::UsageService;
 pub use user_service::UserService;
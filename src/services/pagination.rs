@@ -0,0 +1,88 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{errors::ServiceError, models::Page};
+
+/// 목록에서 마지막으로 반환한 항목의 정렬 키를 클라이언트가 해석하지 않는 문자열로 인코딩
+pub(crate) fn encode_cursor<K: Serialize>(key: &K) -> String {
+    URL_SAFE_NO_PAD.encode(serde_json::to_vec(key).expect("sort keys serialize to JSON"))
+}
+
+pub(crate) fn decode_cursor<K: DeserializeOwned>(cursor: &str) -> Result<K, ServiceError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(cursor)
+        .map_err(|_| ServiceError::InvalidCursor)?;
+    serde_json::from_slice(&bytes).map_err(|_| ServiceError::InvalidCursor)
+}
+
+/// `limit`보다 하나 더 조회한 `rows`로 페이지 구성.
+/// 남는 행이 있으면 마지막 항목의 정렬 키 `K`를 커서로 돌려준다
+pub(crate) fn into_page<K, M, T>(mut rows: Vec<M>, limit: u64) -> Page<T>
+where
+    K: for<'a> From<&'a M> + Serialize,
+    T: From<M>,
+{
+    let limit = limit as usize;
+    let next_cursor = if rows.len() > limit {
+        rows.truncate(limit);
+        rows.last().map(|row| encode_cursor(&K::from(row)))
+    } else {
+        None
+    };
+
+    Page {
+        items: rows.into_iter().map(T::from).collect(),
+        next_cursor,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::PinnedOrderKey;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let key = PinnedOrderKey {
+            is_pinned: true,
+            updated_at: NaiveDate::from_ymd_opt(2024, 1, 15)
+                .unwrap()
+                .and_hms_micro_opt(10, 30, 0, 123_456)
+                .unwrap(),
+            id: 42,
+        };
+
+        let cursor = encode_cursor(&key);
+        assert!(cursor
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(decode_cursor::<PinnedOrderKey>(&cursor).unwrap(), key);
+    }
+
+    #[test]
+    fn test_invalid_cursor_is_rejected() {
+        for cursor in ["", "not a cursor", &URL_SAFE_NO_PAD.encode(b"{\"id\":1}")] {
+            assert!(matches!(
+                decode_cursor::<PinnedOrderKey>(cursor),
+                Err(ServiceError::InvalidCursor)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_into_page_returns_cursor_only_when_more_rows_remain() {
+        let rows = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+
+        let page: Page<String> = into_page::<String, _, _>(rows(&["c", "b", "a"]), 2);
+        assert_eq!(page.items, rows(&["c", "b"]));
+        assert_eq!(
+            decode_cursor::<String>(&page.next_cursor.unwrap()).unwrap(),
+            "b"
+        );
+
+        let page: Page<String> = into_page::<String, _, _>(rows(&["c", "b"]), 2);
+        assert_eq!(page.items, rows(&["c", "b"]));
+        assert!(page.next_cursor.is_none());
+    }
+}
@@ -1,7 +1,9 @@
 use crate::errors::ServiceError;
 use crate::models::project_dto::{CreateProjectRequest, ProjectResponse, UpdateProjectRequest};
-use crate::repositories::ProjectRepository;
-use sea_orm::DatabaseConnection;
+use crate::models::{Page, PageParams};
+use crate::repositories::{CreatedOrderKey, ProjectRepository, VectorDeletionRepository};
+use crate::services::pagination::{decode_cursor, into_page};
+use sea_orm::{DatabaseConnection, TransactionTrait};
 use std::sync::Arc;
 
 #[cfg(test)]
@@ -9,13 +11,17 @@ mod tests;
 
 #[derive(Clone)]
 pub struct ProjectService {
+    db: Arc<DatabaseConnection>,
     project_repo: ProjectRepository,
+    deletion_repo: VectorDeletionRepository,
 }
 
 impl ProjectService {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
-            project_repo: ProjectRepository::new(db),
+            project_repo: ProjectRepository::new(db.clone()),
+            deletion_repo: VectorDeletionRepository::new(db.clone()),
+            db,
         }
     }
 
@@ -42,9 +48,20 @@ impl ProjectService {
         Ok(ProjectResponse::from(project))
     }
 
-    pub async fn list_projects(&self, user_id: i32) -> Result<Vec<ProjectResponse>, ServiceError> {
-        let projects = self.project_repo.find_by_user_id(user_id).await?;
-        Ok(projects.into_iter().map(ProjectResponse::from).collect())
+    /// 사용자의 프로젝트를 최근 생성 순으로 한 페이지 조회
+    pub async fn list_projects(
+        &self,
+        user_id: i32,
+        page: &PageParams,
+    ) -> Result<Page<ProjectResponse>, ServiceError> {
+        let after = page.cursor.as_deref().map(decode_cursor).transpose()?;
+        let limit = page.limit();
+
+        let projects = self
+            .project_repo
+            .find_page_by_user_id(user_id, after, limit + 1)
+            .await?;
+        Ok(into_page::<CreatedOrderKey, _, _>(projects, limit))
     }
 
     pub async fn get_project(
@@ -116,7 +133,11 @@ impl ProjectService {
             return Err(ServiceError::Unauthorized);
         }
 
-        self.project_repo.delete(project_id).await?;
+        // 메모는 DB에서 함께 삭제되고, 벡터는 같은 트랜잭션에 등록한 작업으로 워커가 삭제한다
+        let txn = self.db.begin().await?;
+        self.project_repo.delete(&txn, project_id).await?;
+        self.deletion_repo.enqueue(&txn, project_id).await?;
+        txn.commit().await?;
         Ok(())
     }
 }
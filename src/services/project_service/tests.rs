@@ -5,8 +5,10 @@ mod tests {
     use crate::errors::ServiceError;
     use crate::models::memo_dto::CreateMemoRequest;
     use crate::models::project_dto::{CreateProjectRequest, UpdateProjectRequest};
+    use crate::models::PageParams;
+    use crate::repositories::VectorDeletionRepository;
     use crate::services::{MemoService, ProjectService};
-    use crate::test_utils::{MockGeminiClient, MockQdrantRepository};
+    use crate::test_utils::{mock_qdrant_repository, MockGeminiClient};
     use chrono::Utc;
     use sea_orm::{ActiveModelTrait, DatabaseConnection, Set};
     use std::sync::Arc;
@@ -119,12 +121,56 @@ mod tests {
             .await
             .unwrap();
 
-        let projects = service.list_projects(user_id).await.unwrap();
+        let projects = service
+            .list_projects(user_id, &PageParams::default())
+            .await
+            .unwrap()
+            .items;
         assert_eq!(projects.len(), 2);
         assert_eq!(projects[0].name, "Project 2");
         assert_eq!(projects[1].name, "Project 1");
     }
 
+    #[tokio::test]
+    async fn test_list_projects_pages_with_cursor() {
+        let (db, user_id) = setup_test_db().await;
+        let service = ProjectService::new(db);
+
+        let mut ids = Vec::new();
+        for i in 0..3 {
+            let project = service
+                .create_project(
+                    user_id,
+                    CreateProjectRequest {
+                        name: format!("Project {}", i),
+                        description: None,
+                    },
+                )
+                .await
+                .unwrap();
+            ids.push(project.id);
+        }
+
+        let page = |cursor| PageParams {
+            limit: Some(2),
+            cursor,
+        };
+        let first = service.list_projects(user_id, &page(None)).await.unwrap();
+        let second = service
+            .list_projects(user_id, &page(first.next_cursor.clone()))
+            .await
+            .unwrap();
+        assert!(second.next_cursor.is_none());
+
+        let listed: Vec<i32> = first
+            .items
+            .iter()
+            .chain(&second.items)
+            .map(|p| p.id)
+            .collect();
+        assert_eq!(listed, vec![ids[2], ids[1], ids[0]]);
+    }
+
     #[tokio::test]
     async fn test_update_project_success() {
         let (db, user_id) = setup_test_db().await;
@@ -160,7 +206,7 @@ mod tests {
     #[tokio::test]
     async fn test_delete_project_success() {
         let (db, user_id) = setup_test_db().await;
-        let service = ProjectService::new(db);
+        let service = ProjectService::new(db.clone());
 
         let created = service
             .create_project(
@@ -178,6 +224,13 @@ mod tests {
 
         let get_result = service.get_project(user_id, created.id).await;
         assert!(matches!(get_result, Err(ServiceError::ProjectNotFound)));
+
+        // 벡터 삭제 작업이 같은 트랜잭션에 등록된다
+        let deletion = VectorDeletionRepository::new(db)
+            .find_by_project_id(created.id)
+            .await
+            .unwrap();
+        assert!(deletion.is_some());
     }
 
     #[tokio::test]
@@ -413,11 +466,19 @@ mod tests {
             .await
             .unwrap();
 
-        let user1_projects = service.list_projects(user1_id).await.unwrap();
+        let user1_projects = service
+            .list_projects(user1_id, &PageParams::default())
+            .await
+            .unwrap()
+            .items;
         assert_eq!(user1_projects.len(), 1);
         assert_eq!(user1_projects[0].id, project1.id);
 
-        let user2_projects = service.list_projects(user2_id).await.unwrap();
+        let user2_projects = service
+            .list_projects(user2_id, &PageParams::default())
+            .await
+            .unwrap()
+            .items;
         assert_eq!(user2_projects.len(), 1);
         assert_eq!(user2_projects[0].id, project2.id);
 
@@ -446,7 +507,7 @@ mod tests {
         let project_id = project.id;
 
         // Memo 생성
-        let qdrant_repo = Arc::new(MockQdrantRepository::new());
+        let qdrant_repo = Arc::new(mock_qdrant_repository());
         let embedder = Arc::new(MockGeminiClient::new());
         let memo_service = MemoService::new(db.clone(), qdrant_repo, embedder);
 
@@ -474,9 +535,10 @@ mod tests {
 
         // Memo가 있는지 확인
         let memos = memo_service
-            .list_memos_by_project(user_id, project_id)
+            .list_memos_by_project(user_id, project_id, &PageParams::default())
             .await
-            .unwrap();
+            .unwrap()
+            .items;
         assert_eq!(memos.len(), 2);
 
         // Project 삭제
@@ -487,7 +549,7 @@ mod tests {
 
         // Memo도 삭제되었는지 확인
         let result = memo_service
-            .list_memos_by_project(user_id, project_id)
+            .list_memos_by_project(user_id, project_id, &PageParams::default())
             .await;
 
         assert!(result.is_err());
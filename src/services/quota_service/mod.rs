@@ -0,0 +1,185 @@
+use chrono::{Duration, DurationRound, NaiveDateTime, Utc};
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+
+use crate::{
+    clients::{UsageFeature, UsageOperation},
+    errors::ServiceError,
+    repositories::{IndexJobRepository, UsageRepository},
+    utils::env::{env_or, InvalidEnvVar},
+};
+
+/// 사용자별 AI 할당량. 0이면 해당 할당량을 적용하지 않는다
+#[derive(Debug, Clone)]
+pub struct QuotaConfig {
+    /// 하루(UTC)에 허용하는 어시스트 요청 수
+    pub daily_assist_calls: u64,
+    /// 하루(UTC)에 허용하는 전체 AI 토큰 수
+    pub daily_tokens: u64,
+    /// 한 시간에 임베딩할 수 있는 메모 수
+    pub hourly_memo_embeds: u64,
+}
+
+impl Default for QuotaConfig {
+    fn default() -> Self {
+        Self {
+            daily_assist_calls: 100,
+            daily_tokens: 500_000,
+            hourly_memo_embeds: 300,
+        }
+    }
+}
+
+impl QuotaConfig {
+    /// QUOTA_DAILY_ASSIST_CALLS, QUOTA_DAILY_TOKENS, QUOTA_HOURLY_MEMO_EMBEDS
+    /// 환경 변수로 기본값을 덮어쓴다
+    pub fn from_env() -> Result<Self, InvalidEnvVar> {
+        let default = Self::default();
+
+        Ok(Self {
+            daily_assist_calls: env_or("QUOTA_DAILY_ASSIST_CALLS", default.daily_assist_calls)?,
+            daily_tokens: env_or("QUOTA_DAILY_TOKENS", default.daily_tokens)?,
+            hourly_memo_embeds: env_or("QUOTA_HOURLY_MEMO_EMBEDS", default.hourly_memo_embeds)?,
+        })
+    }
+
+    /// 모든 할당량을 끈 설정
+    pub fn unlimited() -> Self {
+        Self {
+            daily_assist_calls: 0,
+            daily_tokens: 0,
+            hourly_memo_embeds: 0,
+        }
+    }
+}
+
+/// `ai_usage` 기록을 기준으로 사용자별 할당량을 확인.
+/// 일일 할당량은 UTC 자정, 시간당 할당량은 매 정시에 초기화된다
+#[derive(Clone)]
+pub struct QuotaService {
+    usage_repo: UsageRepository,
+    job_repo: IndexJobRepository,
+    config: QuotaConfig,
+}
+
+impl QuotaService {
+    pub fn new(db: Arc<DatabaseConnection>, config: QuotaConfig) -> Self {
+        Self {
+            usage_repo: UsageRepository::new(db.clone()),
+            job_repo: IndexJobRepository::new(db),
+            config,
+        }
+    }
+
+    pub fn with_config(self, config: QuotaConfig) -> Self {
+        Self { config, ..self }
+    }
+
+    /// 어시스트 요청 전 일일 요청 수와 일일 토큰 할당량 확인
+    pub async fn check_assist(&self, user_id: i32) -> Result<(), ServiceError> {
+        let (day_start, day_end) = Self::day_window();
+
+        if self.config.daily_assist_calls > 0 {
+            let assist = self
+                .usage_repo
+                .totals_since(
+                    user_id,
+                    day_start,
+                    Some(UsageFeature::Assist),
+                    Some(UsageOperation::Generate),
+                )
+                .await?;
+
+            Self::ensure_within(
+                "daily_assist_calls",
+                assist.calls,
+                self.config.daily_assist_calls,
+                day_end,
+            )?;
+        }
+
+        self.check_daily_tokens(user_id).await
+    }
+
+    /// 메모 임베딩 전 시간당 메모 임베딩 수와 일일 토큰 할당량 확인.
+    /// 아직 처리되지 않은 인덱싱 작업도 곧 임베딩될 메모로 보고 함께 센다
+    pub async fn check_memo_embedding(&self, user_id: i32) -> Result<(), ServiceError> {
+        if self.config.hourly_memo_embeds > 0 {
+            let (hour_start, hour_end) = Self::hour_window();
+            let embedded = self
+                .usage_repo
+                .totals_since(
+                    user_id,
+                    hour_start,
+                    Some(UsageFeature::MemoIndexing),
+                    Some(UsageOperation::Embed),
+                )
+                .await?;
+            let queued = self.job_repo.count_queued_by_user(user_id).await?;
+
+            Self::ensure_within(
+                "hourly_memo_embeds",
+                embedded.items + queued,
+                self.config.hourly_memo_embeds,
+                hour_end,
+            )?;
+        }
+
+        self.check_daily_tokens(user_id).await
+    }
+
+    /// 번역, 다이제스트처럼 요청 수 제한 없이 토큰만 쓰는 기능의 일일 토큰 할당량 확인
+    pub async fn check_daily_tokens(&self, user_id: i32) -> Result<(), ServiceError> {
+        if self.config.daily_tokens == 0 {
+            return Ok(());
+        }
+
+        let (day_start, day_end) = Self::day_window();
+        let totals = self
+            .usage_repo
+            .totals_since(user_id, day_start, None, None)
+            .await?;
+
+        Self::ensure_within(
+            "daily_tokens",
+            totals.total_tokens,
+            self.config.daily_tokens,
+            day_end,
+        )
+    }
+
+    fn ensure_within(
+        quota: &'static str,
+        used: i64,
+        limit: u64,
+        reset_at: NaiveDateTime,
+    ) -> Result<(), ServiceError> {
+        if used.max(0) as u64 >= limit {
+            return Err(ServiceError::QuotaExceeded {
+                quota,
+                limit,
+                reset_at,
+            });
+        }
+        Ok(())
+    }
+
+    fn day_window() -> (NaiveDateTime, NaiveDateTime) {
+        let start = Utc::now()
+            .date_naive()
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is a valid time");
+        (start, start + Duration::days(1))
+    }
+
+    fn hour_window() -> (NaiveDateTime, NaiveDateTime) {
+        let start = Utc::now()
+            .naive_utc()
+            .duration_trunc(Duration::hours(1))
+            .expect("an hour fits in a timestamp");
+        (start, start + Duration::hours(1))
+    }
+}
+
+#[cfg(test)]
+mod tests;
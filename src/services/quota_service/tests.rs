@@ -0,0 +1,216 @@
+use super::*;
+use crate::{
+    clients::{
+        usage::{report, TokenUsage},
+        UsageContext,
+    },
+    services::UsageService,
+    test_support::setup_test_db,
+};
+use axum::response::IntoResponse;
+
+/// 프로바이더 호출 한 번을 사용량으로 기록
+async fn record(
+    db: &Arc<DatabaseConnection>,
+    user_id: i32,
+    feature: UsageFeature,
+    operation: UsageOperation,
+    total_tokens: i32,
+    items: usize,
+) {
+    let context = UsageContext {
+        user_id,
+        project_id: None,
+        feature,
+    };
+    UsageService::new(db.clone())
+        .track(context, async {
+            let tokens = TokenUsage {
+                total_tokens,
+                ..TokenUsage::default()
+            };
+            report(operation, "test-model", tokens, items);
+        })
+        .await;
+}
+
+fn config(daily_assist_calls: u64, daily_tokens: u64, hourly_memo_embeds: u64) -> QuotaConfig {
+    QuotaConfig {
+        daily_assist_calls,
+        daily_tokens,
+        hourly_memo_embeds,
+    }
+}
+
+#[tokio::test]
+async fn test_assist_calls_quota() {
+    let (db, user_id) = setup_test_db().await;
+    let quota = QuotaService::new(db.clone(), config(2, 0, 0));
+
+    for _ in 0..2 {
+        quota.check_assist(user_id).await.unwrap();
+        record(
+            &db,
+            user_id,
+            UsageFeature::Assist,
+            UsageOperation::Generate,
+            10,
+            1,
+        )
+        .await;
+    }
+    // 다른 기능의 생성 호출은 어시스트 요청 수에 포함되지 않는다
+    record(
+        &db,
+        user_id,
+        UsageFeature::Translation,
+        UsageOperation::Generate,
+        10,
+        1,
+    )
+    .await;
+
+    match quota.check_assist(user_id).await {
+        Err(ServiceError::QuotaExceeded {
+            quota,
+            limit,
+            reset_at,
+        }) => {
+            assert_eq!(quota, "daily_assist_calls");
+            assert_eq!(limit, 2);
+            assert_eq!(reset_at, QuotaService::day_window().1);
+        }
+        other => panic!("expected QuotaExceeded, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_daily_tokens_quota_counts_all_features() {
+    let (db, user_id) = setup_test_db().await;
+    let quota = QuotaService::new(db.clone(), config(0, 100, 0));
+
+    record(
+        &db,
+        user_id,
+        UsageFeature::Digest,
+        UsageOperation::Generate,
+        60,
+        1,
+    )
+    .await;
+    quota.check_assist(user_id).await.unwrap();
+
+    record(
+        &db,
+        user_id,
+        UsageFeature::Assist,
+        UsageOperation::Generate,
+        40,
+        1,
+    )
+    .await;
+
+    assert!(matches!(
+        quota.check_assist(user_id).await,
+        Err(ServiceError::QuotaExceeded {
+            quota: "daily_tokens",
+            ..
+        })
+    ));
+    assert!(matches!(
+        quota.check_memo_embedding(user_id).await,
+        Err(ServiceError::QuotaExceeded {
+            quota: "daily_tokens",
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_hourly_memo_embeds_counts_batch_items() {
+    let (db, user_id) = setup_test_db().await;
+    let quota = QuotaService::new(db.clone(), config(0, 0, 3));
+
+    record(
+        &db,
+        user_id,
+        UsageFeature::MemoIndexing,
+        UsageOperation::Embed,
+        0,
+        2,
+    )
+    .await;
+    // 어시스트 프롬프트 임베딩은 메모 임베딩 수에 포함되지 않는다
+    record(
+        &db,
+        user_id,
+        UsageFeature::Assist,
+        UsageOperation::Embed,
+        0,
+        5,
+    )
+    .await;
+    quota.check_memo_embedding(user_id).await.unwrap();
+
+    record(
+        &db,
+        user_id,
+        UsageFeature::MemoIndexing,
+        UsageOperation::Embed,
+        0,
+        1,
+    )
+    .await;
+
+    match quota.check_memo_embedding(user_id).await {
+        Err(ServiceError::QuotaExceeded {
+            quota, reset_at, ..
+        }) => {
+            assert_eq!(quota, "hourly_memo_embeds");
+            assert_eq!(reset_at, QuotaService::hour_window().1);
+        }
+        other => panic!("expected QuotaExceeded, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_zero_limits_disable_quotas() {
+    let (db, user_id) = setup_test_db().await;
+    let quota = QuotaService::new(db.clone(), QuotaConfig::unlimited());
+
+    record(
+        &db,
+        user_id,
+        UsageFeature::Assist,
+        UsageOperation::Generate,
+        1_000_000,
+        1,
+    )
+    .await;
+
+    quota.check_assist(user_id).await.unwrap();
+    quota.check_memo_embedding(user_id).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_quota_exceeded_response_has_reset_headers() {
+    let reset_at = Utc::now().naive_utc() + Duration::minutes(30);
+    let response = ServiceError::QuotaExceeded {
+        quota: "daily_assist_calls",
+        limit: 100,
+        reset_at,
+    }
+    .into_response();
+
+    assert_eq!(response.status(), axum::http::StatusCode::TOO_MANY_REQUESTS);
+
+    let headers = response.headers();
+    let retry_after: i64 = headers["retry-after"].to_str().unwrap().parse().unwrap();
+    assert!((1..=1800).contains(&retry_after));
+    assert_eq!(headers["x-ratelimit-limit"], "100");
+    assert_eq!(headers["x-ratelimit-remaining"], "0");
+    assert_eq!(
+        headers["x-ratelimit-reset"],
+        reset_at.and_utc().timestamp().to_string().as_str()
+    );
+}
@@ -0,0 +1,243 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::{
+    clients::{Embedder, EmbeddingPurpose},
+    entities::{
+        memo,
+        vector_reindex_run::{self, ReindexStatus},
+    },
+    errors::ServiceError,
+    repositories::{
+        MemoPoint, MemoRepository, ReindexRunRepository, VectorCollections, VectorSpec,
+    },
+};
+
+/// 버전별 컬렉션 이름 접두사. 뒤에 생성 시각을 붙인다
+const COLLECTION_PREFIX: &str = "memo_embeddings_v";
+
+const DEFAULT_BATCH_SIZE: u64 = 64;
+
+/// 재인덱싱 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReindexReport {
+    pub collection: String,
+    /// 중단된 실행을 이어서 처리했는지 여부
+    pub resumed: bool,
+    /// 이번 실행에서 임베딩한 메모 수 (재임베딩 포함)
+    pub embedded: usize,
+}
+
+/// 검색 별칭과 컬렉션, 재인덱싱 기록
+#[derive(Debug, Clone)]
+pub struct ReindexOverview {
+    pub active_collection: Option<String>,
+    pub collections: Vec<String>,
+    pub runs: Vec<vector_reindex_run::Model>,
+}
+
+/// 임베딩 모델이나 차원을 바꿀 때 서비스 중단 없이 벡터 인덱스를 다시 만든다.
+///
+/// 새 버전 컬렉션을 만들어 모든 메모를 배치로 임베딩하고, 끝나면 검색 별칭을 옮긴다.
+/// 진행 위치는 DB에 기록되어 중단되어도 이어서 처리하며, 이전 컬렉션은 롤백을 위해 남겨 둔다.
+/// 재인덱싱 중 수정된 메모는 별칭을 옮기기 전후에 한 번씩 다시 임베딩하고,
+/// 그 사이 삭제된 메모의 포인트는 대조 작업([`crate::services::MemoService::reconcile_index`])이 정리한다.
+///
+/// 별칭을 옮긴 뒤에도 이전 임베더로 실행 중인 서버는 활성 컬렉션의 벡터 형식이 다른 것을 보고
+/// 인덱싱을 멈추고 검색에 503을 반환하며, 새 임베더 설정으로 재시작하면 다시 처리한다.
+/// 차원이 같은 모델로 바꾼 경우에는 형식으로 구분할 수 없으므로 별칭을 옮긴 직후 모든 서버를 재시작한다
+#[derive(Clone)]
+pub struct ReindexService {
+    memo_repo: MemoRepository,
+    run_repo: ReindexRunRepository,
+    vectors: Arc<dyn VectorCollections>,
+    embedder: Arc<dyn Embedder>,
+    batch_size: u64,
+}
+
+impl ReindexService {
+    pub fn new(
+        db: Arc<DatabaseConnection>,
+        vectors: Arc<dyn VectorCollections>,
+        embedder: Arc<dyn Embedder>,
+    ) -> Self {
+        Self {
+            memo_repo: MemoRepository::new(db.clone()),
+            run_repo: ReindexRunRepository::new(db),
+            vectors,
+            embedder,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    pub fn with_batch_size(self, batch_size: u64) -> Self {
+        Self {
+            batch_size: batch_size.max(1),
+            ..self
+        }
+    }
+
+    /// 진행 중인 재인덱싱을 이어서 처리하거나 새로 시작하여, 완료되면 별칭을 새 컬렉션으로 옮긴다.
+    /// 시스템 작업이므로 사용자별 AI 사용량에는 기록하지 않는다
+    pub async fn reindex(&self) -> Result<ReindexReport, ServiceError> {
+        let model_id = self.embedder.model_id().to_string();
+        let dimension = self.embedder.dimension() as i32;
+
+        let (run, resumed) = match self.run_repo.find_running().await? {
+            Some(run) if run.model_id == model_id && run.dimension == dimension => (run, true),
+            Some(run) => {
+                warn!(
+                    "Abandoning reindex into {} ({}, {} dims): embedder is now {} ({} dims)",
+                    run.collection_name, run.model_id, run.dimension, model_id, dimension
+                );
+                self.run_repo.finish(run, ReindexStatus::Abandoned).await?;
+                (self.start_run(model_id, dimension).await?, false)
+            }
+            None => (self.start_run(model_id, dimension).await?, false),
+        };
+        let collection = run.collection_name.clone();
+
+        let spec = VectorSpec::new(self.embedder.model_id(), self.embedder.dimension());
+        self.vectors.create_collection(&collection, &spec).await?;
+
+        let total = self.memo_repo.count().await? as i32;
+        let mut run = self.run_repo.set_total(run, total).await?;
+        let mut embedded = 0;
+        info!(
+            "Reindexing into {} from memo {} ({}/{})",
+            collection, run.last_memo_id, run.processed, run.total
+        );
+
+        // 1. 마지막으로 기록한 위치부터 모든 메모를 임베딩
+        loop {
+            let memos = self
+                .memo_repo
+                .find_after_id(run.last_memo_id, None, self.batch_size)
+                .await?;
+            let Some(last_id) = memos.last().map(|m| m.id) else {
+                break;
+            };
+
+            self.embed_into(&collection, &memos).await?;
+            embedded += memos.len();
+            let processed = run.processed + memos.len() as i32;
+            run = self
+                .run_repo
+                .record_progress(run, last_id, processed)
+                .await?;
+            info!("Reindex {}: {}/{}", collection, run.processed, run.total);
+        }
+
+        // 2. 진행 중 수정된 메모를 다시 임베딩한 뒤 별칭을 옮기고,
+        //    그 사이 이전 컬렉션에 반영된 수정을 한 번 더 옮겨 온다
+        let catch_up_from = Utc::now().naive_utc();
+        embedded += self
+            .embed_updated_since(&collection, run.started_at)
+            .await?;
+        self.vectors.switch_alias(&collection).await?;
+        embedded += self.embed_updated_since(&collection, catch_up_from).await?;
+
+        self.run_repo.finish(run, ReindexStatus::Completed).await?;
+        info!("Reindex complete: search now uses {}", collection);
+
+        Ok(ReindexReport {
+            collection,
+            resumed,
+            embedded,
+        })
+    }
+
+    /// 별칭을 이전 컬렉션으로 되돌리는 등 특정 컬렉션으로 직접 옮긴다
+    pub async fn switch_collection(&self, collection: &str) -> Result<(), ServiceError> {
+        let collections = self.vectors.list_collections().await?;
+        if !collections.iter().any(|name| name == collection) {
+            return Err(ServiceError::Qdrant(format!(
+                "Collection {} does not exist",
+                collection
+            )));
+        }
+
+        self.vectors.switch_alias(collection).await?;
+        info!("Search now uses {}", collection);
+
+        Ok(())
+    }
+
+    pub async fn overview(&self) -> Result<ReindexOverview, ServiceError> {
+        Ok(ReindexOverview {
+            active_collection: self.vectors.active_collection().await?,
+            collections: self.vectors.list_collections().await?,
+            runs: self.run_repo.find_all().await?,
+        })
+    }
+
+    async fn start_run(
+        &self,
+        model_id: String,
+        dimension: i32,
+    ) -> Result<vector_reindex_run::Model, ServiceError> {
+        let collection = format!(
+            "{}{}",
+            COLLECTION_PREFIX,
+            Utc::now().format("%Y%m%d%H%M%S%3f")
+        );
+
+        Ok(self
+            .run_repo
+            .create(collection, model_id, dimension, 0)
+            .await?)
+    }
+
+    async fn embed_updated_since(
+        &self,
+        collection: &str,
+        since: NaiveDateTime,
+    ) -> Result<usize, ServiceError> {
+        let mut embedded = 0;
+        let mut after = 0;
+        loop {
+            let memos = self
+                .memo_repo
+                .find_after_id(after, Some(since), self.batch_size)
+                .await?;
+            let Some(last_id) = memos.last().map(|m| m.id) else {
+                break;
+            };
+            after = last_id;
+
+            self.embed_into(collection, &memos).await?;
+            embedded += memos.len();
+        }
+
+        Ok(embedded)
+    }
+
+    async fn embed_into(
+        &self,
+        collection: &str,
+        memos: &[memo::Model],
+    ) -> Result<(), ServiceError> {
+        let texts: Vec<&str> = memos.iter().map(|m| m.content.as_str()).collect();
+        let vectors = self
+            .embedder
+            .embed_batch(&texts, EmbeddingPurpose::RetrievalDocument)
+            .await?;
+
+        let points = memos
+            .iter()
+            .zip(vectors)
+            .map(|(memo, vector)| MemoPoint {
+                memo_id: memo.id,
+                project_id: memo.project_id,
+                vector,
+            })
+            .collect();
+
+        Ok(self.vectors.upsert_into(collection, points).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests;
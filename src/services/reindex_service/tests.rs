@@ -0,0 +1,107 @@
+use super::*;
+use crate::{
+    clients::ClientError,
+    test_support::setup_test_db_with_project,
+    test_utils::{mock_qdrant_repository, MockGeminiClient},
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// `fail_after`번 임베딩한 뒤부터 실패하는 임베더 (재인덱싱 중단 재현)
+struct InterruptingEmbedder {
+    inner: MockGeminiClient,
+    calls: AtomicUsize,
+    fail_after: usize,
+}
+
+#[async_trait::async_trait]
+impl Embedder for InterruptingEmbedder {
+    async fn embed(&self, text: &str, purpose: EmbeddingPurpose) -> Result<Vec<f32>, ClientError> {
+        if self.calls.fetch_add(1, Ordering::SeqCst) >= self.fail_after {
+            return Err(ClientError::Timeout("embedding timed out".to_string()));
+        }
+        self.inner.embed(text, purpose).await
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension()
+    }
+
+    fn model_id(&self) -> &str {
+        self.inner.model_id()
+    }
+}
+
+/// 진행 중인 재인덱싱 기록은 DB 전체에서 하나이므로 한 테스트에서 순서대로 검증한다
+#[tokio::test]
+async fn test_reindex_resumes_after_interruption_and_switches_alias() {
+    let (db, _user_id, project_id) = setup_test_db_with_project().await;
+    let memo_repo = MemoRepository::new(db.clone());
+    let mut memo_ids = Vec::new();
+    for content in ["재인덱싱할 첫 번째 메모", "재인덱싱할 두 번째 메모"] {
+        let memo = memo_repo
+            .create(db.as_ref(), project_id, content.to_string())
+            .await
+            .unwrap();
+        memo_ids.push(memo.id);
+    }
+    let vectors = Arc::new(mock_qdrant_repository());
+
+    // 첫 배치 이후 임베딩이 실패하면 진행 위치가 남는다
+    let interrupted = ReindexService::new(
+        db.clone(),
+        vectors.clone(),
+        Arc::new(InterruptingEmbedder {
+            inner: MockGeminiClient::new(),
+            calls: AtomicUsize::new(0),
+            fail_after: 1,
+        }),
+    )
+    .with_batch_size(1);
+    assert!(interrupted.reindex().await.is_err());
+
+    let run_repo = ReindexRunRepository::new(db.clone());
+    let run = run_repo.find_running().await.unwrap().unwrap();
+    assert!(run.processed >= 1);
+    assert!(run.last_memo_id > 0);
+    assert_eq!(
+        vectors.active_collection().await.unwrap().as_deref(),
+        Some("memo_embeddings")
+    );
+
+    // 같은 임베더로 다시 실행하면 같은 컬렉션에 이어서 처리하고 별칭을 옮긴다
+    let service = ReindexService::new(
+        db.clone(),
+        vectors.clone(),
+        Arc::new(MockGeminiClient::new()),
+    )
+    .with_batch_size(200);
+    let report = service.reindex().await.unwrap();
+    assert!(report.resumed);
+    assert_eq!(report.collection, run.collection_name);
+    assert_eq!(
+        vectors.active_collection().await.unwrap(),
+        Some(run.collection_name.clone())
+    );
+
+    let indexed = vectors.memo_ids_in(&run.collection_name);
+    assert!(memo_ids.iter().all(|id| indexed.contains(id)));
+
+    let overview = service.overview().await.unwrap();
+    let finished = overview.runs.iter().find(|r| r.id == run.id).unwrap();
+    assert_eq!(finished.status, ReindexStatus::Completed);
+    assert!(finished.processed >= run.processed);
+    assert!(overview
+        .collections
+        .contains(&"memo_embeddings".to_string()));
+
+    // 이전 컬렉션은 남아 있어 롤백할 수 있다
+    service.switch_collection("memo_embeddings").await.unwrap();
+    assert_eq!(
+        vectors.active_collection().await.unwrap().as_deref(),
+        Some("memo_embeddings")
+    );
+    assert!(service
+        .switch_collection("no_such_collection")
+        .await
+        .is_err());
+}
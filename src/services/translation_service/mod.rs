@@ -0,0 +1,129 @@
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+
+use crate::{
+    clients::{GenerationOptions, TextGenerator, UsageContext, UsageFeature},
+    errors::ServiceError,
+    models::essay_dto::EssayResponse,
+    repositories::{EssayRepository, ProjectRepository},
+    services::{QuotaConfig, QuotaService, UsageService},
+};
+
+const PARAGRAPH_SEPARATOR: &str = "\n\n";
+
+#[derive(Clone)]
+pub struct TranslationService {
+    essay_repo: EssayRepository,
+    project_repo: ProjectRepository,
+    text_generator: Arc<dyn TextGenerator>,
+    usage: UsageService,
+    quota: QuotaService,
+}
+
+impl TranslationService {
+    pub fn new(db: Arc<DatabaseConnection>, text_generator: Arc<dyn TextGenerator>) -> Self {
+        Self {
+            essay_repo: EssayRepository::new(db.clone()),
+            project_repo: ProjectRepository::new(db.clone()),
+            usage: UsageService::new(db.clone()),
+            quota: QuotaService::new(db, QuotaConfig::default()),
+            text_generator,
+        }
+    }
+
+    pub fn with_quota_config(self, config: QuotaConfig) -> Self {
+        Self {
+            quota: self.quota.with_config(config),
+            ..self
+        }
+    }
+
+    /// 에세이를 대상 언어로 번역하여 같은 프로젝트에 연결된 사본으로 저장.
+    /// 같은 언어의 번역본이 이미 있으면 새로 번역한 내용으로 갱신한다.
+    /// 번역 전에 사용자의 일일 토큰 할당량을 확인한다.
+    pub async fn translate_essay(
+        &self,
+        user_id: i32,
+        essay_id: i32,
+        language: &str,
+    ) -> Result<EssayResponse, ServiceError> {
+        let source = self
+            .essay_repo
+            .find_by_id(essay_id)
+            .await?
+            .ok_or(ServiceError::EssayNotFound)?;
+
+        // 권한 검증: essay → project → user
+        let project = self
+            .project_repo
+            .find_by_id(source.project_id)
+            .await?
+            .ok_or(ServiceError::ProjectNotFound)?;
+
+        if project.user_id != user_id {
+            return Err(ServiceError::Unauthorized);
+        }
+
+        self.quota.check_daily_tokens(user_id).await?;
+
+        let usage_context = UsageContext {
+            user_id,
+            project_id: Some(project.id),
+            feature: UsageFeature::Translation,
+        };
+
+        let (title, content) = self
+            .usage
+            .track(usage_context, async {
+                let title = self.translate_text(&source.title, language).await?;
+                let content = self.translate_paragraphs(&source.content, language).await?;
+                Ok::<_, ServiceError>((title, content))
+            })
+            .await?;
+
+        let translation = self
+            .essay_repo
+            .upsert_translation(&source, language.to_string(), title, content)
+            .await?;
+
+        Ok(EssayResponse::from(translation))
+    }
+
+    /// 문단 단위로 번역하여 원문의 문단 구조를 유지.
+    /// 모델이 한 문단을 여러 문단으로 나눠 번역하면 그 문단 구분도 그대로 둔다
+    async fn translate_paragraphs(
+        &self,
+        content: &str,
+        language: &str,
+    ) -> Result<String, ServiceError> {
+        let mut translated = Vec::new();
+
+        for paragraph in content.split(PARAGRAPH_SEPARATOR) {
+            if paragraph.trim().is_empty() {
+                translated.push(paragraph.to_string());
+            } else {
+                translated.push(self.translate_text(paragraph, language).await?);
+            }
+        }
+
+        Ok(translated.join(PARAGRAPH_SEPARATOR))
+    }
+
+    async fn translate_text(&self, text: &str, language: &str) -> Result<String, ServiceError> {
+        let prompt = format!(
+            "다음 글을 언어 코드 '{}'에 해당하는 언어로 번역하세요.\n\
+             번역문만 출력하고, 설명이나 따옴표를 덧붙이지 마세요.\n\
+             줄바꿈과 마크다운 서식은 원문 그대로 유지하세요.\n\n{}",
+            language, text
+        );
+
+        let output = self
+            .text_generator
+            .complete(&prompt, &GenerationOptions::default())
+            .await?;
+        Ok(output.trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,268 @@
+use super::*;
+use crate::{
+    entities::essay,
+    models::essay_dto::{CreateEssayRequest, UpdateEssayRequest},
+    services::EssayService,
+    test_support::{setup_test_db, setup_test_db_with_project},
+    test_utils::MockGeminiClient,
+};
+use sea_orm::*;
+
+async fn setup_test_db_with_essay(content: &str) -> (Arc<DatabaseConnection>, i32, EssayResponse) {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+
+    let essay = EssayService::new(db.clone())
+        .create_essay(
+            user_id,
+            CreateEssayRequest {
+                project_id,
+                title: "원본 제목".to_string(),
+                content: content.to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    (db, user_id, essay)
+}
+
+fn translation_service(db: Arc<DatabaseConnection>) -> TranslationService {
+    TranslationService::new(
+        db,
+        Arc::new(MockGeminiClient::new()) as Arc<dyn TextGenerator>,
+    )
+}
+
+#[tokio::test]
+async fn test_translate_essay_creates_linked_copy() {
+    let (db, user_id, source) = setup_test_db_with_essay("첫 문단").await;
+    let service = translation_service(db);
+
+    let translated = service
+        .translate_essay(user_id, source.id, "en")
+        .await
+        .unwrap();
+
+    assert_ne!(translated.id, source.id);
+    assert_eq!(translated.project_id, source.project_id);
+    assert_eq!(translated.source_essay_id, Some(source.id));
+    assert_eq!(translated.language.as_deref(), Some("en"));
+    assert!(!translated.is_translation_stale);
+    assert!(translated.title.contains("원본 제목"));
+    assert!(translated.content.contains("첫 문단"));
+}
+
+#[tokio::test]
+async fn test_translate_essay_preserves_paragraphs() {
+    let content =
+        "첫 번째 문단입니다.\n같은 문단의 둘째 줄.\n\n두 번째 문단입니다.\n\n세 번째 문단입니다.";
+    let (db, user_id, source) = setup_test_db_with_essay(content).await;
+    let service = translation_service(db);
+
+    let translated = service
+        .translate_essay(user_id, source.id, "en")
+        .await
+        .unwrap();
+
+    let paragraphs: Vec<&str> = translated.content.split("\n\n").collect();
+    assert_eq!(paragraphs.len(), 3);
+    assert!(paragraphs[0].contains("첫 번째 문단입니다."));
+    assert!(paragraphs[0].contains("같은 문단의 둘째 줄."));
+    assert!(paragraphs[1].contains("두 번째 문단입니다."));
+    assert!(paragraphs[2].contains("세 번째 문단입니다."));
+}
+
+/// 모든 입력을 두 문단으로 나눠 번역하는 생성기
+struct SplittingGenerator;
+
+#[async_trait::async_trait]
+impl TextGenerator for SplittingGenerator {
+    async fn generate(
+        &self,
+        prompt: &str,
+        _context: Vec<String>,
+        options: &GenerationOptions,
+    ) -> Result<String, crate::clients::ClientError> {
+        self.complete(prompt, options).await
+    }
+
+    async fn complete(
+        &self,
+        _prompt: &str,
+        _options: &GenerationOptions,
+    ) -> Result<String, crate::clients::ClientError> {
+        Ok("First half.\n\nSecond half.".to_string())
+    }
+}
+
+#[tokio::test]
+async fn test_translate_essay_keeps_paragraph_breaks_added_by_model() {
+    let (db, user_id, source) =
+        setup_test_db_with_essay("첫 번째 문단.\n\n\n\n두 번째 문단.").await;
+    let service = TranslationService::new(db, Arc::new(SplittingGenerator));
+
+    let translated = service
+        .translate_essay(user_id, source.id, "en")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        translated.content,
+        "First half.\n\nSecond half.\n\n\n\nFirst half.\n\nSecond half."
+    );
+}
+
+#[tokio::test]
+async fn test_translate_essay_again_refreshes_existing_translation() {
+    let (db, user_id, source) = setup_test_db_with_essay("원본 내용").await;
+    let service = translation_service(db.clone());
+
+    let first = service
+        .translate_essay(user_id, source.id, "ja")
+        .await
+        .unwrap();
+
+    EssayService::new(db.clone())
+        .update_essay(
+            user_id,
+            source.id,
+            UpdateEssayRequest {
+                title: "원본 제목".to_string(),
+                content: "수정된 원본 내용".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let second = service
+        .translate_essay(user_id, source.id, "ja")
+        .await
+        .unwrap();
+
+    assert_eq!(second.id, first.id);
+    assert!(second.content.contains("수정된 원본 내용"));
+    assert!(!second.is_translation_stale);
+}
+
+#[tokio::test]
+async fn test_concurrent_translations_share_one_copy() {
+    let (db, user_id, source) = setup_test_db_with_essay("원본 내용").await;
+    let service = translation_service(db.clone());
+
+    let (first, second) = tokio::join!(
+        service.translate_essay(user_id, source.id, "fr"),
+        service.translate_essay(user_id, source.id, "fr"),
+    );
+
+    assert_eq!(first.unwrap().id, second.unwrap().id);
+    let copies = essay::Entity::find()
+        .filter(essay::Column::SourceEssayId.eq(source.id))
+        .filter(essay::Column::Language.eq("fr"))
+        .count(db.as_ref())
+        .await
+        .unwrap();
+    assert_eq!(copies, 1);
+}
+
+#[tokio::test]
+async fn test_update_source_marks_translation_stale() {
+    let (db, user_id, source) = setup_test_db_with_essay("원본 내용").await;
+    let service = translation_service(db.clone());
+    let essay_service = EssayService::new(db.clone());
+
+    let translated = service
+        .translate_essay(user_id, source.id, "en")
+        .await
+        .unwrap();
+
+    essay_service
+        .update_essay(
+            user_id,
+            source.id,
+            UpdateEssayRequest {
+                title: "원본 제목".to_string(),
+                content: "수정된 원본 내용".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let fetched = essay_service
+        .get_essay(user_id, translated.id)
+        .await
+        .unwrap();
+    assert!(fetched.is_translation_stale);
+}
+
+#[tokio::test]
+async fn test_update_source_without_changes_keeps_translation_fresh() {
+    let (db, user_id, source) = setup_test_db_with_essay("원본 내용").await;
+    let service = translation_service(db.clone());
+    let essay_service = EssayService::new(db.clone());
+
+    let translated = service
+        .translate_essay(user_id, source.id, "en")
+        .await
+        .unwrap();
+
+    essay_service
+        .update_essay(
+            user_id,
+            source.id,
+            UpdateEssayRequest {
+                title: source.title.clone(),
+                content: source.content.clone(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let fetched = essay_service
+        .get_essay(user_id, translated.id)
+        .await
+        .unwrap();
+    assert!(!fetched.is_translation_stale);
+}
+
+#[tokio::test]
+async fn test_translate_essay_unauthorized() {
+    let (db, user_id, source) = setup_test_db_with_essay("원본 내용").await;
+    let service = translation_service(db);
+
+    let result = service
+        .translate_essay(user_id + 999, source.id, "en")
+        .await;
+    assert!(matches!(result, Err(ServiceError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_translate_essay_not_found() {
+    let (db, user_id) = setup_test_db().await;
+    let service = translation_service(db);
+
+    let result = service.translate_essay(user_id, 999999, "en").await;
+    assert!(matches!(result, Err(ServiceError::EssayNotFound)));
+}
+
+#[tokio::test]
+async fn test_translate_essay_enforces_daily_token_quota() {
+    let (db, user_id, source) = setup_test_db_with_essay("원본 내용").await;
+    let service = translation_service(db).with_quota_config(QuotaConfig {
+        daily_tokens: 1,
+        ..QuotaConfig::unlimited()
+    });
+
+    service
+        .translate_essay(user_id, source.id, "en")
+        .await
+        .unwrap();
+
+    let result = service.translate_essay(user_id, source.id, "ja").await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::QuotaExceeded {
+            quota: "daily_tokens",
+            ..
+        })
+    ));
+}
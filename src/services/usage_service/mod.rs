@@ -0,0 +1,59 @@
+use chrono::{Duration, Utc};
+use sea_orm::DatabaseConnection;
+use std::{future::Future, sync::Arc};
+use tracing::warn;
+
+use crate::{
+    clients::{usage, UsageContext},
+    errors::ServiceError,
+    models::usage_dto::DailyUsageResponse,
+    repositories::UsageRepository,
+};
+
+#[derive(Clone)]
+pub struct UsageService {
+    usage_repo: UsageRepository,
+}
+
+impl UsageService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            usage_repo: UsageRepository::new(db),
+        }
+    }
+
+    /// `future` 안의 AI 프로바이더 호출을 `context`의 사용량으로 기록.
+    /// 기록 실패가 본 요청을 실패시키지 않도록 에러는 로그만 남긴다
+    pub async fn track<F: Future>(&self, context: UsageContext, future: F) -> F::Output {
+        let (output, records) = usage::track(context, future).await;
+
+        if let Err(e) = self.usage_repo.insert_records(&records).await {
+            warn!(
+                "Failed to record {} AI usage record(s) for user {}: {}",
+                records.len(),
+                context.user_id,
+                e
+            );
+        }
+
+        output
+    }
+
+    /// 오늘을 포함한 최근 `days`일의 일별 사용량
+    pub async fn daily_usage(
+        &self,
+        user_id: i32,
+        days: u32,
+    ) -> Result<Vec<DailyUsageResponse>, ServiceError> {
+        let today = Utc::now().date_naive();
+        let since = (today - Duration::days(i64::from(days.max(1)) - 1))
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is a valid time");
+
+        let rows = self.usage_repo.find_daily_by_user(user_id, since).await?;
+        Ok(rows.into_iter().map(DailyUsageResponse::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests;
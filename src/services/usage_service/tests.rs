@@ -0,0 +1,120 @@
+use super::*;
+use crate::{
+    clients::{
+        usage::{report, TokenUsage},
+        UsageFeature, UsageOperation,
+    },
+    test_support::setup_test_db_with_project,
+};
+
+fn generation(prompt_tokens: i32, candidate_tokens: i32) -> TokenUsage {
+    TokenUsage {
+        prompt_tokens,
+        candidate_tokens,
+        total_tokens: prompt_tokens + candidate_tokens,
+    }
+}
+
+#[tokio::test]
+async fn test_track_records_usage_and_returns_output() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = UsageService::new(db);
+    let context = UsageContext {
+        user_id,
+        project_id: Some(project_id),
+        feature: UsageFeature::Assist,
+    };
+
+    let output = service
+        .track(context, async {
+            report(
+                UsageOperation::Embed,
+                "embed-model",
+                TokenUsage::default(),
+                3,
+            );
+            report(UsageOperation::Generate, "chat-model", generation(10, 4), 1);
+            "suggestion"
+        })
+        .await;
+
+    assert_eq!(output, "suggestion");
+
+    let usage = service.daily_usage(user_id, 1).await.unwrap();
+    assert_eq!(usage.len(), 2);
+
+    let embed = usage.iter().find(|u| u.operation == "embed").unwrap();
+    assert_eq!(embed.model, "embed-model");
+    assert_eq!(embed.calls, 1);
+    assert_eq!(embed.items, 3);
+
+    let generate = usage.iter().find(|u| u.operation == "generate").unwrap();
+    assert_eq!(generate.model, "chat-model");
+    assert_eq!(generate.prompt_tokens, 10);
+    assert_eq!(generate.candidate_tokens, 4);
+    assert_eq!(generate.total_tokens, 14);
+    assert_eq!(generate.date, Utc::now().date_naive());
+}
+
+#[tokio::test]
+async fn test_daily_usage_aggregates_calls_per_model() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let service = UsageService::new(db);
+    let context = UsageContext {
+        user_id,
+        project_id: Some(project_id),
+        feature: UsageFeature::Assist,
+    };
+
+    for _ in 0..3 {
+        service
+            .track(context, async {
+                report(UsageOperation::Generate, "chat-model", generation(5, 5), 1);
+            })
+            .await;
+    }
+    service
+        .track(context, async {
+            report(UsageOperation::Generate, "other-model", generation(1, 1), 1);
+        })
+        .await;
+
+    let usage = service.daily_usage(user_id, 30).await.unwrap();
+    assert_eq!(usage.len(), 2);
+
+    let chat = usage.iter().find(|u| u.model == "chat-model").unwrap();
+    assert_eq!(chat.calls, 3);
+    assert_eq!(chat.total_tokens, 30);
+}
+
+#[tokio::test]
+async fn test_daily_usage_is_scoped_to_user() {
+    let (db, user_id, project_id) = setup_test_db_with_project().await;
+    let (_, other_user_id, _) = setup_test_db_with_project().await;
+    let service = UsageService::new(db);
+
+    service
+        .track(
+            UsageContext {
+                user_id,
+                project_id: Some(project_id),
+                feature: UsageFeature::Assist,
+            },
+            async {
+                report(
+                    UsageOperation::Embed,
+                    "embed-model",
+                    TokenUsage::default(),
+                    1,
+                );
+            },
+        )
+        .await;
+
+    assert_eq!(service.daily_usage(user_id, 30).await.unwrap().len(), 1);
+    assert!(service
+        .daily_usage(other_user_id, 30)
+        .await
+        .unwrap()
+        .is_empty());
+}
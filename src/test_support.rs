@@ -0,0 +1,53 @@
+//! 서비스 테스트가 공유하는 DB 준비 코드
+
+use chrono::Utc;
+use rand::Rng;
+use sea_orm::*;
+use std::sync::Arc;
+
+use crate::{
+    db, entities::user, models::project_dto::CreateProjectRequest, services::ProjectService,
+};
+
+/// 테스트 DB에 연결하고 다른 테스트와 겹치지 않는 사용자를 만든다
+pub async fn setup_test_db() -> (Arc<DatabaseConnection>, i32) {
+    dotenv::dotenv().ok();
+    let database_url = std::env::var("DATABASE_URL_TEST")
+        .expect("DATABASE_URL_TEST must be set. Run: just setup-test-db");
+    let db = Arc::new(db::create_connection(&database_url).await.unwrap());
+
+    let now = Utc::now().naive_utc();
+    let timestamp = now.and_utc().timestamp_micros();
+    let random: u32 = rand::thread_rng().gen();
+    let unique_id = format!("{}_{}", timestamp, random);
+
+    let new_user = user::ActiveModel {
+        id: NotSet,
+        username: Set(format!("test_user_{}", unique_id)),
+        email: Set(format!("test_{}@example.com", unique_id)),
+        password_hash: Set(Some("test_hash".to_string())),
+        created_at: Set(now),
+        updated_at: Set(now),
+    };
+    let user_id = new_user.insert(db.as_ref()).await.unwrap().id;
+
+    (db, user_id)
+}
+
+/// [`setup_test_db`]의 사용자에게 프로젝트를 하나 만들어 (db, user_id, project_id)를 반환
+pub async fn setup_test_db_with_project() -> (Arc<DatabaseConnection>, i32, i32) {
+    let (db, user_id) = setup_test_db().await;
+
+    let project = ProjectService::new(db.clone())
+        .create_project(
+            user_id,
+            CreateProjectRequest {
+                name: format!("Test Project {}", user_id),
+                description: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    (db, user_id, project.id)
+}
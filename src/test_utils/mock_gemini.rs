@@ -1,4 +1,7 @@
-use crate::clients::{ClientError, Embedder, TextGenerator};
+use crate::clients::{
+    usage::{self, TokenUsage, UsageOperation},
+    ClientError, Embedder, EmbeddingPurpose, GenerationOptions, TextGenerator,
+};
 
 #[derive(Clone)]
 pub struct MockGeminiClient {
@@ -13,6 +16,32 @@ impl MockGeminiClient {
     }
 }
 
+impl MockGeminiClient {
+    /// 텍스트 길이로 결정되는 고정 벡터
+    fn vector_for(&self, text: &str) -> Vec<f32> {
+        let hash = text.len() as f32;
+        (0..self.embedding_dimension)
+            .map(|i| (hash + i as f32) / 1000.0)
+            .collect()
+    }
+
+    /// 공백 기준 단어 수를 토큰 수로 간주
+    fn report_generation(prompt: &str, output: &str) {
+        let prompt_tokens = prompt.split_whitespace().count() as i32;
+        let candidate_tokens = output.split_whitespace().count() as i32;
+        usage::report(
+            UsageOperation::Generate,
+            "mock-generation",
+            TokenUsage {
+                prompt_tokens,
+                candidate_tokens,
+                total_tokens: prompt_tokens + candidate_tokens,
+            },
+            1,
+        );
+    }
+}
+
 impl Default for MockGeminiClient {
     fn default() -> Self {
         Self::new()
@@ -21,18 +50,37 @@ impl Default for MockGeminiClient {
 
 #[async_trait::async_trait]
 impl Embedder for MockGeminiClient {
-    async fn embed(&self, text: &str) -> Result<Vec<f32>, ClientError> {
-        let hash = text.len() as f32;
-        let vector: Vec<f32> = (0..self.embedding_dimension)
-            .map(|i| (hash + i as f32) / 1000.0)
-            .collect();
+    async fn embed(&self, text: &str, _purpose: EmbeddingPurpose) -> Result<Vec<f32>, ClientError> {
+        usage::report(
+            UsageOperation::Embed,
+            "mock-embedding",
+            TokenUsage::default(),
+            1,
+        );
+        Ok(self.vector_for(text))
+    }
 
-        Ok(vector)
+    async fn embed_batch(
+        &self,
+        texts: &[&str],
+        _purpose: EmbeddingPurpose,
+    ) -> Result<Vec<Vec<f32>>, ClientError> {
+        usage::report(
+            UsageOperation::Embed,
+            "mock-embedding",
+            TokenUsage::default(),
+            texts.len(),
+        );
+        Ok(texts.iter().map(|text| self.vector_for(text)).collect())
     }
 
     fn dimension(&self) -> usize {
         self.embedding_dimension
     }
+
+    fn model_id(&self) -> &str {
+        "mock-embedding"
+    }
 }
 
 #[async_trait::async_trait]
@@ -41,6 +89,7 @@ impl TextGenerator for MockGeminiClient {
         &self,
         prompt: &str,
         context: Vec<String>,
+        _options: &GenerationOptions,
     ) -> Result<String, ClientError> {
         let mut result = format!("AI 제안 (prompt: {})\n\n", prompt);
 
@@ -53,6 +102,17 @@ impl TextGenerator for MockGeminiClient {
 
         result.push_str("\n생성된 글쓰기 제안입니다.");
 
+        Self::report_generation(prompt, &result);
         Ok(result)
     }
+
+    async fn complete(
+        &self,
+        prompt: &str,
+        _options: &GenerationOptions,
+    ) -> Result<String, ClientError> {
+        let output = format!("AI 응답: {}", prompt.replace('\n', " "));
+        Self::report_generation(prompt, &output);
+        Ok(output)
+    }
 }
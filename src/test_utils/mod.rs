@@ -2,4 +2,4 @@ pub mod mock_gemini;
 pub mod mock_qdrant;
 
 pub use mock_gemini::MockGeminiClient;
-pub use mock_qdrant::MockQdrantRepository;
+pub use mock_qdrant::{mock_qdrant_repository, MockQdrantRepository};
@@ -0,0 +1,11 @@
+use sha2::{Digest, Sha256};
+
+/// 임베딩 재사용 여부를 판단하기 위한 콘텐츠 해시 (SHA-256, hex).
+/// 공백만 다른 내용은 같은 임베딩을 쓰도록 연속 공백과 앞뒤 공백을 정규화한 뒤 해시한다
+pub fn content_hash(content: &str) -> String {
+    let normalized = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    format!("{:x}", Sha256::digest(normalized.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests;
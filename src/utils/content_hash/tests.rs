@@ -0,0 +1,23 @@
+use super::*;
+
+#[test]
+fn test_content_hash_is_sha256_hex() {
+    let hash = content_hash("hello");
+    assert_eq!(
+        hash,
+        "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+    );
+}
+
+#[test]
+fn test_content_hash_ignores_whitespace_differences() {
+    assert_eq!(
+        content_hash("오늘 배운 Rust"),
+        content_hash("  오늘   배운\nRust \n")
+    );
+}
+
+#[test]
+fn test_content_hash_differs_for_different_content() {
+    assert_ne!(content_hash("오늘 배운 Rust"), content_hash("오늘 배운 Go"));
+}
@@ -0,0 +1,54 @@
+use std::str::FromStr;
+use thiserror::Error;
+
+/// 값이 있는데 파싱할 수 없는 환경 변수
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("{key} has an invalid value '{value}'")]
+pub struct InvalidEnvVar {
+    pub key: &'static str,
+    pub value: String,
+}
+
+/// 환경 변수를 파싱하고, 없거나 비어 있으면 기본값 사용. 값이 있는데 파싱할 수 없으면 설정 오류
+pub fn env_or<T: FromStr>(key: &'static str, default: T) -> Result<T, InvalidEnvVar> {
+    Ok(env_opt(key)?.unwrap_or(default))
+}
+
+/// 환경 변수가 있고 비어 있지 않으면 파싱. 값이 있는데 파싱할 수 없으면 설정 오류
+pub fn env_opt<T: FromStr>(key: &'static str) -> Result<Option<T>, InvalidEnvVar> {
+    let Some(value) = std::env::var(key).ok().filter(|v| !v.trim().is_empty()) else {
+        return Ok(None);
+    };
+
+    match value.trim().parse() {
+        Ok(parsed) => Ok(Some(parsed)),
+        Err(_) => Err(InvalidEnvVar { key, value }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_or_treats_empty_value_as_unset() {
+        std::env::set_var("INKLINGS_TEST_ENV_EMPTY", "  ");
+        assert_eq!(env_or("INKLINGS_TEST_ENV_EMPTY", 7u64), Ok(7));
+        assert_eq!(env_opt::<u64>("INKLINGS_TEST_ENV_EMPTY"), Ok(None));
+    }
+
+    #[test]
+    fn test_env_or_reports_invalid_value() {
+        std::env::set_var("INKLINGS_TEST_ENV_INVALID", "many");
+        assert_eq!(
+            env_or("INKLINGS_TEST_ENV_INVALID", 7u64),
+            Err(InvalidEnvVar {
+                key: "INKLINGS_TEST_ENV_INVALID",
+                value: "many".to_string(),
+            })
+        );
+
+        std::env::set_var("INKLINGS_TEST_ENV_VALID", " 12 ");
+        assert_eq!(env_or("INKLINGS_TEST_ENV_VALID", 7u64), Ok(12));
+    }
+}
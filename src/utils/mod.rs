@@ -1 +1,3 @@
+pub mod content_hash;
+pub mod env;
 pub mod jwt;
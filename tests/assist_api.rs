@@ -5,7 +5,7 @@ use http_body_util::BodyExt;
 use inklings_server::clients::{Embedder, TextGenerator};
 use inklings_server::models::assist_dto::{AssistRequest, AssistResponse};
 use inklings_server::models::memo_dto::CreateMemoRequest;
-use inklings_server::test_utils::{MockGeminiClient, MockQdrantRepository};
+use inklings_server::test_utils::{mock_qdrant_repository, MockGeminiClient, MockQdrantRepository};
 use inklings_server::{db, entities, handlers, services};
 use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, NotSet, Set};
 use std::sync::Arc;
@@ -25,7 +25,7 @@ async fn setup() -> (
     let db = Arc::new(db::create_connection(&database_url).await.unwrap());
 
     // Mock 클라이언트 생성
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let gemini_client = Arc::new(MockGeminiClient::new());
 
     // 전체 라우터 생성
@@ -34,7 +34,8 @@ async fn setup() -> (
         qdrant_repo.clone(),
         gemini_client.clone() as Arc<dyn Embedder>,
         gemini_client.clone() as Arc<dyn TextGenerator>,
-    );
+    )
+    .unwrap();
     (app, db, qdrant_repo, gemini_client)
 }
 
@@ -99,7 +100,7 @@ async fn test_assist_success() {
     let memo_service =
         services::MemoService::new(db.clone(), qdrant_repo, embedder as Arc<dyn Embedder>);
 
-    memo_service
+    let memo = memo_service
         .create_memo(
             user.id,
             CreateMemoRequest {
@@ -109,12 +110,19 @@ async fn test_assist_success() {
         )
         .await
         .unwrap();
+    memo_service
+        .process_index_jobs_for(&[memo.id])
+        .await
+        .unwrap();
 
     // Assist 요청
     let req_body = AssistRequest {
         project_id: project.id,
         prompt: "Tell me about async programming".to_string(),
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let token = generate_test_token(user.id);
@@ -151,6 +159,9 @@ async fn test_assist_no_memos() {
         project_id: project.id,
         prompt: "Tell me something".to_string(),
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let token = generate_test_token(user.id);
@@ -191,6 +202,9 @@ async fn test_assist_prompt_min_length() {
         project_id: project.id,
         prompt: "a".to_string(), // 1자
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let token = generate_test_token(user.id);
@@ -221,6 +235,9 @@ async fn test_assist_prompt_max_length() {
         project_id: project.id,
         prompt: "a".repeat(10000), // 10000자
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let token = generate_test_token(user.id);
@@ -251,6 +268,9 @@ async fn test_assist_prompt_empty() {
         project_id: project.id,
         prompt: "".to_string(), // 0자
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let token = generate_test_token(user.id);
@@ -281,6 +301,9 @@ async fn test_assist_prompt_too_long() {
         project_id: project.id,
         prompt: "a".repeat(10001), // 10001자
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let token = generate_test_token(user.id);
@@ -315,6 +338,9 @@ async fn test_assist_limit_min() {
         project_id: project.id,
         prompt: "Test prompt".to_string(),
         limit: 1, // 최소값
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let token = generate_test_token(user.id);
@@ -345,6 +371,9 @@ async fn test_assist_limit_max() {
         project_id: project.id,
         prompt: "Test prompt".to_string(),
         limit: 20, // 최대값
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let token = generate_test_token(user.id);
@@ -375,6 +404,9 @@ async fn test_assist_limit_zero() {
         project_id: project.id,
         prompt: "Test prompt".to_string(),
         limit: 0, // 최소값 미만
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let token = generate_test_token(user.id);
@@ -405,6 +437,9 @@ async fn test_assist_limit_too_large() {
         project_id: project.id,
         prompt: "Test prompt".to_string(),
         limit: 21, // 최대값 초과
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let token = generate_test_token(user.id);
@@ -442,6 +477,9 @@ async fn test_assist_unauthorized() {
         project_id: project.id,
         prompt: "Test prompt".to_string(),
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let token = generate_test_token(user2.id); // user2의 토큰
@@ -472,6 +510,9 @@ async fn test_assist_no_auth() {
         project_id: project.id,
         prompt: "Test prompt".to_string(),
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     // 토큰 없이 요청
@@ -503,6 +544,9 @@ async fn test_assist_project_not_found() {
         project_id: 999999, // 존재하지 않는 ID
         prompt: "Test prompt".to_string(),
         limit: 5,
+        temperature: None,
+        max_output_tokens: None,
+        response_language: None,
     };
 
     let token = generate_test_token(user.id);
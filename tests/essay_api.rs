@@ -11,8 +11,9 @@ use inklings_server::{
     handlers,
     models::essay_dto::{CreateEssayRequest, EssayResponse, UpdateEssayRequest},
     models::project_dto::CreateProjectRequest,
+    models::Page,
     services::{EssayService, ProjectService},
-    test_utils::{MockGeminiClient, MockQdrantRepository},
+    test_utils::{mock_qdrant_repository, MockGeminiClient},
 };
 use rand::Rng;
 use sea_orm::{ActiveModelTrait, DatabaseConnection, NotSet, Set};
@@ -27,7 +28,7 @@ async fn setup() -> (Router, Arc<DatabaseConnection>) {
 
     let db = Arc::new(db::create_connection(&database_url).await.unwrap());
 
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let gemini_client = Arc::new(MockGeminiClient::new());
 
     let app = handlers::create_router(
@@ -35,7 +36,8 @@ async fn setup() -> (Router, Arc<DatabaseConnection>) {
         qdrant_repo,
         gemini_client.clone() as Arc<dyn inklings_server::clients::Embedder>,
         gemini_client as Arc<dyn inklings_server::clients::TextGenerator>,
-    );
+    )
+    .unwrap();
     (app, db)
 }
 
@@ -129,7 +131,7 @@ async fn test_list_essays_api() {
         .await
         .unwrap();
 
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let essay_service = Arc::new(EssayService::new(db.clone()));
 
@@ -175,10 +177,11 @@ async fn test_list_essays_api() {
     assert_eq!(response.status(), StatusCode::OK);
 
     let body = response.into_body().collect().await.unwrap().to_bytes();
-    let essays: Vec<EssayResponse> = serde_json::from_slice(&body).unwrap();
+    let essays: Page<EssayResponse> = serde_json::from_slice(&body).unwrap();
 
-    assert_eq!(essays.len(), 2);
-    assert!(essays.iter().all(|e| e.project_id == project.id));
+    assert_eq!(essays.items.len(), 2);
+    assert!(essays.items.iter().all(|e| e.project_id == project.id));
+    assert!(essays.next_cursor.is_none());
 }
 
 #[tokio::test]
@@ -199,7 +202,7 @@ async fn test_get_essay_unauthorized_api() {
         .await
         .unwrap();
 
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let essay_service = Arc::new(EssayService::new(db.clone()));
 
@@ -250,7 +253,7 @@ async fn test_update_essay_api() {
         .await
         .unwrap();
 
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let essay_service = Arc::new(EssayService::new(db.clone()));
 
@@ -313,7 +316,7 @@ async fn test_delete_essay_api() {
         .await
         .unwrap();
 
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let essay_service = Arc::new(EssayService::new(db.clone()));
 
@@ -392,7 +395,7 @@ async fn test_essay_project_isolation_api() {
         .await
         .unwrap();
 
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let essay_service = Arc::new(EssayService::new(db.clone()));
 
@@ -452,8 +455,110 @@ async fn test_essay_project_isolation_api() {
     assert_eq!(response.status(), StatusCode::OK);
 
     let body = response.into_body().collect().await.unwrap().to_bytes();
-    let essays: Vec<EssayResponse> = serde_json::from_slice(&body).unwrap();
+    let essays: Page<EssayResponse> = serde_json::from_slice(&body).unwrap();
 
-    assert_eq!(essays.len(), 2);
-    assert!(essays.iter().all(|e| e.title.contains("Project 1")));
+    assert_eq!(essays.items.len(), 2);
+    assert!(essays.items.iter().all(|e| e.title.contains("Project 1")));
+}
+
+#[tokio::test]
+async fn test_translate_essay_api() {
+    let (app, db) = setup().await;
+    let user = create_test_user(&db).await;
+
+    let project_service = ProjectService::new(db.clone());
+    let project = project_service
+        .create_project(
+            user.id,
+            CreateProjectRequest {
+                name: "Translation Project".to_string(),
+                description: Some("Test project for translation".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+    let essay_service = EssayService::new(db.clone());
+    let source = essay_service
+        .create_essay(
+            user.id,
+            CreateEssayRequest {
+                project_id: project.id,
+                title: "번역할 에세이".to_string(),
+                content: "첫 번째 문단\n\n두 번째 문단".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let token = generate_test_token(user.id);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri(format!("/api/essays/{}/translate?lang=en", source.id))
+                .header(http::header::COOKIE, format!("access_token={}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let translated: EssayResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(translated.project_id, project.id);
+    assert_eq!(translated.source_essay_id, Some(source.id));
+    assert_eq!(translated.language.as_deref(), Some("en"));
+    assert_eq!(translated.content.split("\n\n").count(), 2);
+}
+
+#[tokio::test]
+async fn test_translate_essay_invalid_language_api() {
+    let (app, db) = setup().await;
+    let user = create_test_user(&db).await;
+
+    let project_service = ProjectService::new(db.clone());
+    let project = project_service
+        .create_project(
+            user.id,
+            CreateProjectRequest {
+                name: "Translation Project".to_string(),
+                description: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    let essay_service = EssayService::new(db.clone());
+    let source = essay_service
+        .create_essay(
+            user.id,
+            CreateEssayRequest {
+                project_id: project.id,
+                title: "번역할 에세이".to_string(),
+                content: "내용".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let token = generate_test_token(user.id);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri(format!("/api/essays/{}/translate?lang=english", source.id))
+                .header(http::header::COOKIE, format!("access_token={}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
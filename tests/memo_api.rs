@@ -11,8 +11,9 @@ use inklings_server::{
     handlers,
     models::memo_dto::{CreateMemoRequest, MemoResponse},
     models::project_dto::CreateProjectRequest,
+    models::Page,
     services,
-    test_utils::{MockGeminiClient, MockQdrantRepository},
+    test_utils::{mock_qdrant_repository, MockGeminiClient},
 };
 use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, Set};
 use std::sync::Arc;
@@ -26,7 +27,7 @@ async fn setup() -> (Router, Arc<DatabaseConnection>) {
 
     let db = Arc::new(db::create_connection(&database_url).await.unwrap());
 
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let gemini_client = Arc::new(MockGeminiClient::new());
 
     let app = handlers::create_router(
@@ -34,7 +35,8 @@ async fn setup() -> (Router, Arc<DatabaseConnection>) {
         qdrant_repo,
         gemini_client.clone() as Arc<dyn Embedder>,
         gemini_client as Arc<dyn TextGenerator>,
-    );
+    )
+    .unwrap();
     (app, db)
 }
 
@@ -132,7 +134,7 @@ async fn test_list_memos_api() {
         .await
         .unwrap();
 
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let memo_service = Arc::new(services::memo_service::MemoService::new(
         db.clone(),
@@ -174,6 +176,7 @@ async fn test_list_memos_api() {
     let token = generate_test_token(user1.id);
 
     let response = app
+        .clone()
         .oneshot(
             Request::builder()
                 .method(http::Method::GET)
@@ -188,10 +191,24 @@ async fn test_list_memos_api() {
     assert_eq!(response.status(), StatusCode::OK);
 
     let body = response.into_body().collect().await.unwrap().to_bytes();
-    let memos: Vec<MemoResponse> = serde_json::from_slice(&body).unwrap();
+    let memos: Page<MemoResponse> = serde_json::from_slice(&body).unwrap();
 
-    assert_eq!(memos.len(), 2);
-    assert!(memos.iter().all(|m| m.project_id != 0));
+    assert_eq!(memos.items.len(), 2);
+    assert!(memos.items.iter().all(|m| m.project_id == project1.id));
+    assert!(memos.next_cursor.is_none());
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method(http::Method::GET)
+                .uri("/api/memos?limit=1&cursor=not-a-cursor")
+                .header(http::header::COOKIE, format!("access_token={}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
 
 #[tokio::test]
@@ -212,7 +229,7 @@ async fn test_get_memo_unauthorized_api() {
         .await
         .unwrap();
 
-    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let qdrant_repo = Arc::new(mock_qdrant_repository());
     let embedder = Arc::new(MockGeminiClient::new());
     let memo_service = Arc::new(services::memo_service::MemoService::new(
         db.clone(),